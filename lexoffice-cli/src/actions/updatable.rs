@@ -2,7 +2,7 @@ use edit::edit_with_builder as edit;
 use lexoffice::request::HasId;
 use lexoffice::request::RequestWithState;
 use lexoffice::request::{ById, Endpoint, ResultInfo, Updatable};
-use lexoffice::{Error, Result};
+use lexoffice::Result;
 use serde::{de::DeserializeOwned, Serialize};
 use serde_any::{from_str, to_string_pretty, Format};
 use structopt::StructOpt;
@@ -13,6 +13,17 @@ pub struct UpdatableOpt {
     id: String,
 }
 
+fn edit_object<T>(object: &T) -> Result<T>
+where
+    T: Serialize + DeserializeOwned,
+{
+    let new_str = edit(
+        to_string_pretty(object, Format::Yaml).unwrap(),
+        edit::Builder::new().suffix(".yaml"),
+    )?;
+    Ok(from_str(&new_str, Format::Yaml).unwrap())
+}
+
 impl UpdatableOpt {
     pub async fn exec<T, U>(
         &self,
@@ -23,16 +34,17 @@ impl UpdatableOpt {
         T: Serialize + DeserializeOwned + Send + HasId + Clone,
         U: Clone,
     {
-        let get = request.clone();
-        let object: T = get.by_id_str(&self.id).await?;
-        let id = object.id().ok_or(Error::NoUuid)?;
-
-        let new_str = edit(
-            to_string_pretty(&object, Format::Yaml).unwrap(),
-            edit::Builder::new().suffix(".yaml"),
-        )?;
-        let new_obj: T = from_str(&new_str, Format::Yaml).unwrap();
-
-        request.update_with_id(id, new_obj).await
+        let mut first = true;
+        request
+            .update_with_str(&self.id, |object| {
+                if !first {
+                    eprintln!("The object was changed in the meantime.");
+                    eprintln!("Reopening the current version in the editor.");
+                }
+                first = false;
+                *object = edit_object(object)?;
+                Ok(())
+            })
+            .await
     }
 }
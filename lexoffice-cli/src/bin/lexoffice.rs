@@ -17,6 +17,7 @@ struct Opt {
 
 #[derive(Debug, StructOpt)]
 enum SubOpt {
+    Article(ArticleOpt),
     Contact(ContactOpt),
     Countries(CountryOpt),
     CreditNote(CreditNoteOpt),
@@ -25,9 +26,12 @@ enum SubOpt {
     File(FileOpt),
     Invoice(InvoiceOpt),
     OrderConfirmation(OrderConfirmationOpt),
+    Payment(PaymentOpt),
+    PaymentCondition(PaymentConditionOpt),
     Profile(ProfileOpt),
     PostingCategory(PostingCategoryOpt),
     Quotation(QuotationOpt),
+    RecurringTemplate(RecurringTemplateOpt),
     Voucherlist(VoucherlistOpt),
     Voucher(VoucherOpt),
 }
@@ -78,6 +82,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     };
     let client = Client::new(api_key?);
     match &opt.sub_opt {
+        SubOpt::Article(x) => opt.out(x.exec(client).await?).await,
         SubOpt::Contact(x) => opt.out(x.exec(client).await?).await,
         SubOpt::Countries(x) => opt.out(x.exec(client).await?).await,
         SubOpt::CreditNote(x) => opt.out(x.exec(client).await?).await,
@@ -85,7 +90,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         SubOpt::EventSubscription(x) => opt.out(x.exec(client).await?).await,
         SubOpt::Invoice(x) => opt.out(x.exec(client).await?).await,
         SubOpt::OrderConfirmation(x) => opt.out(x.exec(client).await?).await,
+        SubOpt::Payment(x) => opt.out(x.exec(client).await?).await,
+        SubOpt::PaymentCondition(x) => opt.out(x.exec(client).await?).await,
         SubOpt::Quotation(x) => opt.out(x.exec(client).await?).await,
+        SubOpt::RecurringTemplate(RecurringTemplateOpt::Preview(x)) => {
+            opt.out(x.exec(client).await?).await
+        }
+        SubOpt::RecurringTemplate(x) => opt.out(x.exec(client).await?).await,
         SubOpt::Profile(x) => opt.out(x.exec(client).await?).await,
         SubOpt::PostingCategory(x) => opt.out(x.exec(client).await?).await,
         SubOpt::File(x) => Ok(x.exec(client).await?),
@@ -0,0 +1,88 @@
+use crate::actions::*;
+use crate::ReturnType;
+use lexoffice::model::articles::*;
+use lexoffice::model::Article;
+use lexoffice::types::Amount;
+use lexoffice::Client;
+use lexoffice::Result;
+use structopt::StructOpt;
+
+const TYPE_VALUES: &[&str] = &["PRODUCT", "SERVICE"];
+
+/// article endpoint
+#[derive(Debug, StructOpt)]
+pub enum ArticleOpt {
+    /// retrieves a paginated list of all articles
+    List {
+        #[structopt(flatten)]
+        page: PaginatedOpt,
+        /// filters by article number
+        #[structopt(short, long)]
+        article_number: Option<String>,
+        /// filters by Global Trade Item Number
+        #[structopt(short, long)]
+        gtin: Option<String>,
+        /// filters by article type
+        #[structopt(short, long, possible_values = TYPE_VALUES)]
+        type_: Option<Type>,
+    },
+    /// creates new article and opens it in an editor
+    New(StorableOpt),
+    /// modifies an article
+    Edit(UpdatableOpt),
+    /// queries a specific article by its id
+    Get(ByIdOpt),
+    /// deletes an article
+    Delete(DeletableOpt),
+}
+
+fn default() -> Article {
+    Article::builder()
+        .title("".to_string())
+        ._type(Type::Product)
+        .unit_name("Stück".to_string())
+        .price(
+            Price::builder()
+                .net_price(Amount::default())
+                .leading_price(LeadingPrice::Net)
+                .tax_rate(19.0)
+                .build(),
+        )
+        .build()
+}
+
+impl ArticleOpt {
+    pub async fn exec(&self, client: Client) -> Result<ReturnType<Article>> {
+        let request = client.request::<Article>();
+        let result = match self {
+            Self::List {
+                page,
+                article_number,
+                gtin,
+                type_,
+            } => {
+                let mut request = request;
+                if let Some(article_number) = article_number {
+                    request = request.article_number(article_number);
+                }
+                if let Some(gtin) = gtin {
+                    request = request.gtin(gtin);
+                }
+                if let Some(type_) = type_ {
+                    request = request.type_(type_);
+                }
+                ReturnType::Paged(page.exec(request).await?)
+            }
+            Self::New(x) => {
+                ReturnType::ResultInfo(x.exec(request, default()).await?)
+            }
+            Self::Edit(x) => ReturnType::ResultInfo(x.exec(request).await?),
+            Self::Get(x) => ReturnType::Obj(x.exec(request).await?),
+            Self::Delete(x) => {
+                x.exec(request).await?;
+                ReturnType::Empty
+            }
+        };
+        Ok(result)
+    }
+}
@@ -10,7 +10,25 @@ use structopt::StructOpt;
 #[derive(Debug, StructOpt)]
 pub enum ContactOpt {
     /// retrieves a paginated list of all contacts
-    List(PaginatedOpt),
+    List {
+        #[structopt(flatten)]
+        page: PaginatedOpt,
+        /// filters by email address
+        #[structopt(short, long)]
+        email: Option<String>,
+        /// filters by company or person name
+        #[structopt(short, long)]
+        name: Option<String>,
+        /// filters by customer or vendor number
+        #[structopt(short = "N", long)]
+        number: Option<i64>,
+        /// only lists customers
+        #[structopt(short, long)]
+        customer: bool,
+        /// only lists vendors
+        #[structopt(short, long)]
+        vendor: bool,
+    },
     /// creates new contact and opens it in an editor
     New(StorableOpt),
     /// modifies a contact
@@ -33,7 +51,32 @@ impl ContactOpt {
     pub async fn exec(&self, client: Client) -> Result<ReturnType<Contact>> {
         let request = client.request::<Contact>();
         let result = match self {
-            Self::List(x) => ReturnType::Paged(x.exec(request).await?),
+            Self::List {
+                page,
+                email,
+                name,
+                number,
+                customer,
+                vendor,
+            } => {
+                let mut request = request;
+                if let Some(email) = email {
+                    request = request.email(email);
+                }
+                if let Some(name) = name {
+                    request = request.name(name);
+                }
+                if let Some(number) = number {
+                    request = request.number(*number);
+                }
+                if *customer {
+                    request = request.customer(true);
+                }
+                if *vendor {
+                    request = request.vendor(true);
+                }
+                ReturnType::Paged(page.exec(request).await?)
+            }
             Self::Get(x) => ReturnType::Obj(x.exec(request).await?),
             Self::New(x) => {
                 ReturnType::ResultInfo(x.exec(request, default()).await?)
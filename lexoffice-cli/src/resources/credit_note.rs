@@ -1,25 +1,108 @@
 use crate::actions::*;
 use crate::ReturnType;
 use lexoffice::model::CreditNote;
+use lexoffice::request::{Cancellation, Request};
+use lexoffice::types::Amount;
 use lexoffice::Client;
 use lexoffice::Result;
 use structopt::StructOpt;
+use uuid::Uuid;
 
 /// credit note endpoint
 #[derive(Debug, StructOpt)]
 pub enum CreditNoteOpt {
-    //New(StorableOpt),
-    //Updatable(UpdatableOpt),
+    /// creates new credit note and opens it in an editor
+    New {
+        #[structopt(flatten)]
+        storable: StorableOpt,
+        /// creates the credit note in status open instead of draft
+        #[structopt(short, long)]
+        finalize: bool,
+        /// uuid of the invoice this credit note refunds
+        #[structopt(short, long)]
+        pursue: Option<Uuid>,
+    },
+    /// creates a finalized credit note that refunds an invoice
+    Cancel {
+        /// uuid of the invoice
+        invoice_id: Uuid,
+        /// refunds only the line item at this position (starting at 0)
+        #[structopt(short, long = "line-item", conflicts_with = "amount")]
+        line_items: Vec<usize>,
+        /// refunds only this amount, net or gross like the invoice
+        #[structopt(short, long)]
+        amount: Option<Amount>,
+        /// tax rate of the refunded amount, defaults to 19
+        #[structopt(short, long, requires = "amount")]
+        tax_rate: Option<f64>,
+    },
     /// queries a specific credit note by its id
     Get(ByIdOpt),
 }
 
+fn default() -> CreditNote {
+    sales_voucher_builder!(credit_notes::CreditNote)
+        .voucher_date(chrono::Utc::now())
+        .build()
+}
+
+async fn store(
+    storable: &StorableOpt,
+    request: Request<CreditNote>,
+    finalize: bool,
+    pursue: Option<Uuid>,
+    credit_note: CreditNote,
+) -> Result<ReturnType<CreditNote>> {
+    let result = match (pursue, finalize) {
+        (Some(id), true) => {
+            storable
+                .exec(request.pursue(id).finalize(), credit_note)
+                .await?
+        }
+        (Some(id), false) => {
+            storable.exec(request.pursue(id), credit_note).await?
+        }
+        (None, true) => storable.exec(request.finalize(), credit_note).await?,
+        (None, false) => storable.exec(request, credit_note).await?,
+    };
+    Ok(ReturnType::ResultInfo(result))
+}
+
 impl CreditNoteOpt {
     pub async fn exec(&self, client: Client) -> Result<ReturnType<CreditNote>> {
         let request = client.request::<CreditNote>();
         let result = match self {
-            //Self::New(x) => x.exec(request),
-            //Self::Updatable(x) => x.exec(request),
+            Self::New {
+                storable,
+                finalize,
+                pursue,
+            } => {
+                store(storable, request, *finalize, *pursue, default()).await?
+            }
+            Self::Cancel {
+                invoice_id,
+                line_items,
+                amount,
+                tax_rate,
+            } => {
+                let cancellation = match amount {
+                    Some(amount) => Some(Cancellation::Amount {
+                        amount: *amount,
+                        tax_rate_percentage: tax_rate.unwrap_or(19.0),
+                    }),
+                    None if !line_items.is_empty() => {
+                        Some(Cancellation::LineItems(line_items.clone()))
+                    }
+                    None => None,
+                };
+                let result = match cancellation {
+                    Some(x) => {
+                        request.cancel_invoice_partially(*invoice_id, x).await?
+                    }
+                    None => request.cancel_invoice(*invoice_id).await?,
+                };
+                ReturnType::ResultInfo(result)
+            }
             Self::Get(x) => ReturnType::Obj(x.exec(request).await?),
         };
         Ok(result)
@@ -1,23 +1,95 @@
 use crate::actions::*;
 use crate::ReturnType;
+use lexoffice::model::invoices::*;
 use lexoffice::model::Invoice;
+use lexoffice::request::Request;
 use lexoffice::Client;
 use lexoffice::Result;
 use structopt::StructOpt;
+use uuid::Uuid;
 
 /// invoice endpoint
 #[derive(Debug, StructOpt)]
 pub enum InvoiceOpt {
+    /// creates new invoice and opens it in an editor
+    New {
+        #[structopt(flatten)]
+        storable: StorableOpt,
+        /// creates the invoice in status open instead of draft
+        #[structopt(short, long)]
+        finalize: bool,
+        /// uuid of the sales voucher this invoice pursues
+        #[structopt(short, long)]
+        pursue: Option<Uuid>,
+    },
+    /// opens an existing invoice in an editor and saves it as new invoice
+    Edit {
+        #[structopt(flatten)]
+        by_id: ByIdOpt,
+        #[structopt(flatten)]
+        storable: StorableOpt,
+        /// creates the invoice in status open instead of draft
+        #[structopt(short, long)]
+        finalize: bool,
+        /// uuid of the sales voucher this invoice pursues
+        #[structopt(short, long)]
+        pursue: Option<Uuid>,
+    },
     /// queries a specific invoice by its id
     Get(ByIdOpt),
 }
 
+fn default() -> Invoice {
+    sales_voucher_builder!(invoices::Invoice)
+        .shipping_conditions(
+            ShippingConditions::builder()
+                .shipping_type(ShippingType::None)
+                .build(),
+        )
+        .build()
+}
+
+async fn store(
+    storable: &StorableOpt,
+    request: Request<Invoice>,
+    finalize: bool,
+    pursue: Option<Uuid>,
+    invoice: Invoice,
+) -> Result<ReturnType<Invoice>> {
+    let result = match (pursue, finalize) {
+        (Some(id), true) => {
+            storable
+                .exec(request.pursue(id).finalize(), invoice)
+                .await?
+        }
+        (Some(id), false) => storable.exec(request.pursue(id), invoice).await?,
+        (None, true) => storable.exec(request.finalize(), invoice).await?,
+        (None, false) => storable.exec(request, invoice).await?,
+    };
+    Ok(ReturnType::ResultInfo(result))
+}
+
 impl InvoiceOpt {
     pub async fn exec(&self, client: Client) -> Result<ReturnType<Invoice>> {
         let request = client.request::<Invoice>();
         let result = match self {
-            //Self::New(x) => x.exec(request),
-            //Self::Updatable(x) => x.exec(request),
+            Self::New {
+                storable,
+                finalize,
+                pursue,
+            } => {
+                store(storable, request, *finalize, *pursue, default()).await?
+            }
+            Self::Edit {
+                by_id,
+                storable,
+                finalize,
+                pursue,
+            } => {
+                let invoice =
+                    as_new!(Invoice, by_id.exec(request.clone()).await?);
+                store(storable, request, *finalize, *pursue, invoice).await?
+            }
             Self::Get(x) => ReturnType::Obj(x.exec(request).await?),
         };
         Ok(result)
@@ -1,3 +1,57 @@
+/// Starts a builder of the sales voucher `$model` from the model module
+/// `$module` with an empty german address, a single custom line item and
+/// net prices in EUR.
+macro_rules! sales_voucher_builder {
+    ($module:ident::$model:ident) => {{
+        use lexoffice::model::$module::*;
+        use lexoffice::types::{Amount, Currency};
+
+        lexoffice::model::$model::builder()
+            .address(
+                Address::builder()
+                    .name(String::new())
+                    .country_code("DE".to_string())
+                    .build(),
+            )
+            .line_items(vec![LineItems::builder()
+                ._type(Type::Custom)
+                .name(String::new())
+                .quantity(1.0)
+                .unit_name("Stück".to_string())
+                .unit_price(
+                    UnitPrice::builder()
+                        .currency(Currency::EUR)
+                        .net_amount(Amount::default())
+                        .tax_rate_percentage(19.0)
+                        .build(),
+                )
+                .build()])
+            .total_price(TotalPrice::builder().currency(Currency::EUR).build())
+            .tax_conditions(
+                TaxConditions::builder().tax_type(TaxType::Net).build(),
+            )
+    }};
+}
+
+/// Strips the fields lexoffice sets on creation from the sales voucher
+/// `$voucher` of type `$model`, so that it can be saved as a new one.
+macro_rules! as_new {
+    ($model:ident, $voucher:expr) => {
+        $model {
+            id: None,
+            organization_id: None,
+            created_date: None,
+            updated_date: None,
+            version: 0,
+            archived: None,
+            voucher_status: None,
+            voucher_number: None,
+            ..$voucher
+        }
+    };
+}
+
+mod article;
 mod contact;
 mod countries;
 mod credit_note;
@@ -7,12 +61,15 @@ mod file;
 mod invoice;
 mod order_confirmation;
 mod payment;
+mod payment_condition;
 mod posting_categories;
 mod profile;
 mod quotation;
+mod recurring_template;
 mod voucher;
 mod voucherlist;
 
+pub use article::ArticleOpt;
 pub use contact::ContactOpt;
 pub use countries::CountryOpt;
 pub use credit_note::CreditNoteOpt;
@@ -22,8 +79,10 @@ pub use file::FileOpt;
 pub use invoice::InvoiceOpt;
 pub use order_confirmation::OrderConfirmationOpt;
 pub use payment::PaymentOpt;
+pub use payment_condition::PaymentConditionOpt;
 pub use posting_categories::PostingCategoryOpt;
 pub use profile::ProfileOpt;
 pub use quotation::QuotationOpt;
+pub use recurring_template::RecurringTemplateOpt;
 pub use voucher::VoucherOpt;
 pub use voucherlist::VoucherlistOpt;
@@ -1,17 +1,55 @@
 use crate::actions::*;
 use crate::ReturnType;
 use lexoffice::model::OrderConfirmation;
+use lexoffice::request::Request;
 use lexoffice::Client;
 use lexoffice::Result;
 use structopt::StructOpt;
+use uuid::Uuid;
 
 /// order confirmation endpoint
 #[derive(Debug, StructOpt)]
 pub enum OrderConfirmationOpt {
+    /// creates new order confirmation and opens it in an editor
+    New {
+        #[structopt(flatten)]
+        storable: StorableOpt,
+        /// uuid of the quotation this order confirmation pursues
+        #[structopt(short, long)]
+        pursue: Option<Uuid>,
+    },
+    /// opens an existing order confirmation in an editor and saves it as new
+    /// order confirmation
+    Edit {
+        #[structopt(flatten)]
+        by_id: ByIdOpt,
+        #[structopt(flatten)]
+        storable: StorableOpt,
+        /// uuid of the quotation this order confirmation pursues
+        #[structopt(short, long)]
+        pursue: Option<Uuid>,
+    },
     /// queries a specific order confirmation by its id
     Get(ByIdOpt),
 }
 
+async fn store(
+    storable: &StorableOpt,
+    request: Request<OrderConfirmation>,
+    pursue: Option<Uuid>,
+    order_confirmation: OrderConfirmation,
+) -> Result<ReturnType<OrderConfirmation>> {
+    let result = match pursue {
+        Some(id) => {
+            storable
+                .exec(request.pursue(id), order_confirmation)
+                .await?
+        }
+        None => storable.exec(request, order_confirmation).await?,
+    };
+    Ok(ReturnType::ResultInfo(result))
+}
+
 impl OrderConfirmationOpt {
     pub async fn exec(
         &self,
@@ -19,8 +57,21 @@ impl OrderConfirmationOpt {
     ) -> Result<ReturnType<OrderConfirmation>> {
         let request = client.request::<OrderConfirmation>();
         let result = match self {
-            //Self::New(x) => x.exec(request),
-            //Self::Updatable(x) => x.exec(request),
+            Self::New { storable, pursue } => {
+                let order_confirmation = OrderConfirmation::builder().build();
+                store(storable, request, *pursue, order_confirmation).await?
+            }
+            Self::Edit {
+                by_id,
+                storable,
+                pursue,
+            } => {
+                let order_confirmation = as_new!(
+                    OrderConfirmation,
+                    by_id.exec(request.clone()).await?
+                );
+                store(storable, request, *pursue, order_confirmation).await?
+            }
             Self::Get(x) => ReturnType::Obj(x.exec(request).await?),
         };
         Ok(result)
@@ -5,10 +5,10 @@ use lexoffice::Client;
 use lexoffice::Result;
 use structopt::StructOpt;
 
-/// invoice endpoint
+/// payments endpoint
 #[derive(Debug, StructOpt)]
 pub enum PaymentOpt {
-    /// queries a specific invoice by its id
+    /// queries the payment status of a voucher by its id
     Get(ByIdOpt),
 }
 
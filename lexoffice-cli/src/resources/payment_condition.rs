@@ -0,0 +1,30 @@
+use crate::ReturnType;
+use lexoffice::model::PaymentCondition;
+use lexoffice::Client;
+use lexoffice::Result;
+use structopt::StructOpt;
+
+/// payment conditions endpoint
+#[derive(Debug, StructOpt)]
+pub enum PaymentConditionOpt {
+    /// retrieves a list of all payment conditions
+    List,
+    /// retrieves the default payment condition of the organization
+    Default,
+}
+
+impl PaymentConditionOpt {
+    pub async fn exec(
+        &self,
+        client: Client,
+    ) -> Result<ReturnType<Vec<PaymentCondition>>> {
+        let request = client.request::<PaymentCondition>();
+        let result = match self {
+            Self::List => request.get().await?,
+            Self::Default => {
+                request.organization_default().await?.into_iter().collect()
+            }
+        };
+        Ok(ReturnType::Obj(result))
+    }
+}
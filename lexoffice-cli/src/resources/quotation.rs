@@ -1,6 +1,7 @@
 use crate::actions::*;
 use crate::ReturnType;
 use lexoffice::model::Quotation;
+use lexoffice::request::Request;
 use lexoffice::Client;
 use lexoffice::Result;
 use structopt::StructOpt;
@@ -10,19 +11,67 @@ use structopt::StructOpt;
 pub enum QuotationOpt {
     /// retrieves a paginated list of all quotations
     List(PaginatedOpt),
-    //New(StorableOpt),
-    //Updatable(UpdatableOpt),
+    /// creates new quotation and opens it in an editor
+    New {
+        #[structopt(flatten)]
+        storable: StorableOpt,
+        /// creates the quotation in status open instead of draft
+        #[structopt(short, long)]
+        finalize: bool,
+    },
+    /// opens an existing quotation in an editor and saves it as new quotation
+    Edit {
+        #[structopt(flatten)]
+        by_id: ByIdOpt,
+        #[structopt(flatten)]
+        storable: StorableOpt,
+        /// creates the quotation in status open instead of draft
+        #[structopt(short, long)]
+        finalize: bool,
+    },
     /// queries a specific quotation by its id
     Get(ByIdOpt),
 }
 
+fn default() -> Quotation {
+    let now = chrono::Utc::now();
+    sales_voucher_builder!(quotations::Quotation)
+        .voucher_date(now)
+        .expiration_date(now + chrono::Duration::days(30))
+        .build()
+}
+
+async fn store(
+    storable: &StorableOpt,
+    request: Request<Quotation>,
+    finalize: bool,
+    quotation: Quotation,
+) -> Result<ReturnType<Quotation>> {
+    let result = if finalize {
+        storable.exec(request.finalize(), quotation).await?
+    } else {
+        storable.exec(request, quotation).await?
+    };
+    Ok(ReturnType::ResultInfo(result))
+}
+
 impl QuotationOpt {
     pub async fn exec(&self, client: Client) -> Result<ReturnType<Quotation>> {
         let request = client.request::<Quotation>();
         let result = match self {
             Self::List(x) => ReturnType::Paged(x.exec(request).await?),
-            //Self::New(x) => x.exec(request),
-            //Self::Updatable(x) => x.exec(request),
+            Self::New { storable, finalize } => {
+                store(storable, request, *finalize, default()).await?
+            }
+            Self::Edit {
+                by_id,
+                storable,
+                finalize,
+            } => {
+                let quotation =
+                    as_new!(Quotation, by_id.exec(request.clone()).await?);
+                store(storable, request, *finalize, quotation).await?
+            }
             Self::Get(x) => ReturnType::Obj(x.exec(request).await?),
         };
         Ok(result)
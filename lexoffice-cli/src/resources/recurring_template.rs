@@ -0,0 +1,111 @@
+use crate::actions::*;
+use crate::ReturnType;
+use lexoffice::model::RecurringTemplate;
+use lexoffice::types::{Amount, Currency, Date};
+use lexoffice::Client;
+use lexoffice::Result;
+use serde::Serialize;
+use structopt::StructOpt;
+use tokio_stream::StreamExt;
+use uuid::Uuid;
+
+/// recurring template endpoint
+#[derive(Debug, StructOpt)]
+pub enum RecurringTemplateOpt {
+    /// retrieves a paginated list of all recurring templates
+    List(PaginatedOpt),
+    /// queries a specific recurring template by its id
+    Get(ByIdOpt),
+    /// lists the upcoming executions of recurring templates
+    Preview(PreviewOpt),
+}
+
+impl RecurringTemplateOpt {
+    pub async fn exec(
+        &self,
+        client: Client,
+    ) -> Result<ReturnType<RecurringTemplate>> {
+        let request = client.request::<RecurringTemplate>();
+        let result = match self {
+            Self::List(x) => ReturnType::Paged(x.exec(request).await?),
+            Self::Get(x) => ReturnType::Obj(x.exec(request).await?),
+            Self::Preview(_) => unreachable!("handled by PreviewOpt::exec()"),
+        };
+        Ok(result)
+    }
+}
+
+/// An upcoming execution of a recurring template
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Execution {
+    date: Date,
+    id: Option<Uuid>,
+    title: Option<String>,
+    contact_name: Option<String>,
+    total_net_amount: Option<Amount>,
+    total_gross_amount: Option<Amount>,
+    currency: Option<Currency>,
+}
+
+#[derive(Debug, StructOpt)]
+pub struct PreviewOpt {
+    /// uuid of a recurring template, all templates are previewed if omitted
+    id: Option<Uuid>,
+    /// maximum number of executions per template, defaults to 3 without
+    /// --until
+    #[structopt(short = "n", long)]
+    count: Option<usize>,
+    /// only lists executions up to this date, e.g. 2021-04-30
+    #[structopt(short, long)]
+    until: Option<Date>,
+}
+
+impl PreviewOpt {
+    pub async fn exec(
+        &self,
+        client: Client,
+    ) -> Result<ReturnType<Vec<Execution>>> {
+        let request = client.request::<RecurringTemplate>();
+        let templates = match self.id {
+            Some(id) => vec![request.by_id(id).await?],
+            None => request.stream().collect::<Result<Vec<_>>>().await?,
+        };
+        let today = chrono::Utc::now().naive_utc().date();
+
+        let mut executions = vec![];
+        for template in templates {
+            let settings = match &template.recurring_template_settings {
+                Some(settings) => settings,
+                None => continue,
+            };
+            let total_price = template.total_price.as_ref();
+            let mut dates = match self.until {
+                Some(until) => settings.upcoming_executions_until(today, until),
+                None => settings
+                    .upcoming_executions_from(today, self.count.unwrap_or(3)),
+            };
+            if let Some(count) = self.count {
+                dates.truncate(count);
+            }
+            for date in dates {
+                executions.push(Execution {
+                    date,
+                    id: template.id,
+                    title: template.title.clone(),
+                    contact_name: template
+                        .address
+                        .as_ref()
+                        .and_then(|x| x.name.clone()),
+                    total_net_amount: total_price
+                        .and_then(|x| x.total_net_amount),
+                    total_gross_amount: total_price
+                        .and_then(|x| x.total_gross_amount),
+                    currency: total_price.and_then(|x| x.currency.clone()),
+                });
+            }
+        }
+        executions.sort_by_key(|x| x.date);
+        Ok(ReturnType::Obj(executions))
+    }
+}
@@ -1,23 +1,115 @@
 use crate::actions::*;
 use crate::ReturnType;
-use lexoffice::model::Voucher;
+use lexoffice::model::vouchers::*;
+use lexoffice::model::{PostingCategory, Voucher};
+use lexoffice::types::{Amount, Date};
 use lexoffice::Client;
 use lexoffice::Result;
 use structopt::StructOpt;
+use uuid::Uuid;
 
 /// voucher endpoint
 #[derive(Debug, StructOpt)]
 pub enum VoucherOpt {
+    /// creates new voucher and opens it in an editor
+    New(StorableOpt),
+    /// modifies a voucher
+    Edit(UpdatableOpt),
+    /// attaches a file to a voucher
+    Attach {
+        /// uuid of the voucher
+        id: Uuid,
+        /// path of the file
+        file: String,
+    },
+    /// creates a purchase invoice and attaches its receipt
+    Receipt {
+        /// path of the receipt
+        file: String,
+        /// voucher number of the purchase invoice
+        #[structopt(short = "n", long)]
+        voucher_number: String,
+        /// date of the purchase invoice, e.g. 2021-04-01
+        #[structopt(short = "d", long)]
+        voucher_date: Date,
+        /// name or uuid of the posting category
+        #[structopt(short, long)]
+        category: String,
+        /// gross amount of the purchase invoice
+        #[structopt(short, long)]
+        amount: Amount,
+        /// tax rate of the purchase invoice
+        #[structopt(short, long, default_value = "19")]
+        tax_rate: f64,
+    },
     /// queries a specific voucher by its id
     Get(ByIdOpt),
 }
 
+fn default() -> Voucher {
+    Voucher::builder()
+        ._type(Type::Purchaseinvoice)
+        .voucher_number(String::new())
+        .voucher_date(chrono::Utc::now().naive_utc().date())
+        .total_gross_amount(Amount::default())
+        .total_tax_amount(Amount::default())
+        .tax_type(TaxType::Gross)
+        .use_collective_contact(true)
+        .voucher_items(vec![VoucherItems::builder()
+            .amount(Amount::default())
+            .tax_amount(Amount::default())
+            .tax_rate_percent(19.0)
+            .category_id(Uuid::nil())
+            .build()])
+        .build()
+}
+
 impl VoucherOpt {
     pub async fn exec(&self, client: Client) -> Result<ReturnType<Voucher>> {
         let request = client.request::<Voucher>();
         let result = match self {
-            //Self::New(x) => x.exec(request),
-            //Self::Updatable(x) => x.exec(request),
+            Self::New(x) => {
+                ReturnType::ResultInfo(x.exec(request, default()).await?)
+            }
+            Self::Edit(x) => ReturnType::ResultInfo(x.exec(request).await?),
+            Self::Attach { id, file } => {
+                println!("{}", request.attach_path(*id, file).await?);
+                ReturnType::Empty
+            }
+            Self::Receipt {
+                file,
+                voucher_number,
+                voucher_date,
+                category,
+                amount,
+                tax_rate,
+            } => {
+                let categories =
+                    client.request::<PostingCategory>().get().await?;
+                let category = categories
+                    .iter()
+                    .find(|x| {
+                        x.name.as_ref() == Some(category)
+                            || x.id.map(|x| x.to_string()).as_ref()
+                                == Some(category)
+                    })
+                    .ok_or_else(|| {
+                        std::io::Error::new(
+                            std::io::ErrorKind::NotFound,
+                            format!("Unknown posting category: {}", category),
+                        )
+                    })?;
+                let voucher = Voucher::purchase_invoice(
+                    voucher_number,
+                    *voucher_date,
+                    category,
+                    *amount,
+                    *tax_rate,
+                )?;
+                ReturnType::ResultInfo(
+                    request.save_receipt(voucher, file).await?,
+                )
+            }
             Self::Get(x) => ReturnType::Obj(x.exec(request).await?),
         };
         Ok(result)
@@ -7,4 +7,19 @@ const client = lexoffice.client(fs.readFileSync("/home/tox/.lexoffice", "utf-8")
 	let request = client.contact();
 
 	let page = await request.page(0);
+
+	let invoices = await client.voucherlist()
+		.type("invoice")
+		.status("open")
+		.page(0);
+
+	let profile = await client.profile().get();
+
+	let fileId = await client.file()
+		.upload(fs.readFileSync("receipt.pdf"), "application/pdf");
+	let receipt = await client.file().download(fileId);
+
+	for await (const contact of client.contact().stream(250)) {
+		console.log(contact.id);
+	}
 })();
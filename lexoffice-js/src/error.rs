@@ -0,0 +1,271 @@
+use js_sys::{Array, Object, Reflect};
+use lexoffice::error::{Detail, Issue, LexOfficeError};
+use lexoffice::Error;
+use std::fmt::Display;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_ERROR: &'static str = r#"
+export type ErrorKind =
+    | "lexoffice"
+    | "versionConflict"
+    | "network"
+    | "decode"
+    | "uuid"
+    | "parse"
+    | "usage"
+    | "io"
+    | "apiKey"
+    | "webhook"
+    | "other";
+
+export interface Issue {
+    i18nKey: string;
+    type: string;
+    source?: string;
+    additionalData?: string;
+    args?: string;
+}
+
+export interface Detail {
+    violation?: string;
+    field?: string;
+    message?: string;
+}
+
+/** All promises of this package are rejected with this error. */
+export declare class LexofficeError extends Error {
+    name: "LexofficeError";
+    kind: ErrorKind;
+    /** The HTTP status code, if the request reached the server */
+    status?: number;
+    traceId?: string;
+    url?: string;
+    /** The issues of a legacy LexOffice error message */
+    issues: Issue[];
+    /** The violations of a detailed LexOffice error message */
+    details: Detail[];
+    /** Only set for errors of kind `versionConflict` */
+    currentVersion?: number;
+}
+"#;
+
+fn set<V: Into<JsValue>>(object: &Object, key: &str, value: V) {
+    // Reflect::set only fails for frozen objects or non-objects
+    Reflect::set(object, &JsValue::from_str(key), &value.into()).unwrap_throw();
+}
+
+fn set_opt<V: Into<JsValue>>(object: &Object, key: &str, value: Option<V>) {
+    if let Some(value) = value {
+        set(object, key, value);
+    }
+}
+
+#[wasm_bindgen(inline_js = "export class LexofficeError extends Error {
+    constructor(message, kind) {
+        super(message);
+        this.name = 'LexofficeError';
+        this.kind = kind;
+        this.issues = [];
+        this.details = [];
+    }
+}")]
+extern "C" {
+    #[wasm_bindgen(extends = js_sys::Error, reexport)]
+    pub type LexofficeError;
+
+    #[wasm_bindgen(constructor)]
+    fn new(message: &str, kind: &str) -> LexofficeError;
+}
+
+fn new_error(kind: &str, message: &str) -> LexofficeError {
+    LexofficeError::new(message, kind)
+}
+
+fn issue_object(issue: &Issue) -> Object {
+    let object = Object::new();
+    set(&object, "i18nKey", issue.i18n_key());
+    set(&object, "type", issue.type_());
+    set_opt(&object, "source", issue.source());
+    set_opt(&object, "additionalData", issue.additional_data());
+    set_opt(&object, "args", issue.args());
+    object
+}
+
+fn detail_object(detail: &Detail) -> Object {
+    let object = Object::new();
+    set_opt(&object, "violation", detail.violation());
+    set_opt(&object, "field", detail.field());
+    set_opt(&object, "message", detail.message());
+    object
+}
+
+fn set_lexoffice_fields(error: &LexofficeError, err: &LexOfficeError) {
+    set(error, "status", err.status().as_u16());
+    set_opt(error, "traceId", err.trace_id());
+    set_opt(error, "url", err.url().map(|x| x.as_str()));
+    let issues = err.issues().iter().map(issue_object).collect::<Array>();
+    set(error, "issues", issues);
+    let details = err.details().iter().map(detail_object).collect::<Array>();
+    set(error, "details", details);
+}
+
+/// Converts an error of the lexoffice crate into a `LexofficeError`
+pub(crate) fn from_lexoffice(err: Error) -> JsValue {
+    let message = err.to_string();
+    let error = match &err {
+        Error::LexOffice(x) => {
+            let error = new_error("lexoffice", &message);
+            set_lexoffice_fields(&error, x);
+            error
+        }
+        Error::VersionConflict(x) => {
+            let error = new_error("versionConflict", &message);
+            set_lexoffice_fields(&error, x.error());
+            // u64 would become a BigInt, versions are plain numbers in JS
+            let version = x.current_version().map(|x| x as f64);
+            set_opt(&error, "currentVersion", version);
+            error
+        }
+        Error::Reqwest(x) if x.is_decode() => new_error("decode", &message),
+        Error::Reqwest(x) => {
+            let error = new_error("network", &message);
+            set_opt(&error, "status", x.status().map(|x| x.as_u16()));
+            set_opt(&error, "url", x.url().map(|x| x.as_str()));
+            error
+        }
+        Error::Json(_) => new_error("decode", &message),
+        Error::Uuid(_) | Error::NoUuid => new_error("uuid", &message),
+        Error::Io(_) => new_error("io", &message),
+        Error::Env(_) | Error::FailedToLoadApiKey => {
+            new_error("apiKey", &message)
+        }
+        Error::InvalidCancellation(_) => new_error("usage", &message),
+        Error::InvalidSignature | Error::InvalidPublicKey => {
+            new_error("webhook", &message)
+        }
+        _ => new_error("other", &message),
+    };
+    error.into()
+}
+
+/// Creates a `LexofficeError` for arguments that couldn't be parsed
+pub(crate) fn parse_error<E: Display>(err: E) -> JsValue {
+    new_error("parse", &err.to_string()).into()
+}
+
+/// Creates a `LexofficeError` for requests that were used incorrectly
+pub(crate) fn usage_error(message: &str) -> JsValue {
+    new_error("usage", message).into()
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use super::*;
+    use lexoffice::error::VersionConflict;
+    use reqwest::{StatusCode, Url};
+    use wasm_bindgen_test::*;
+
+    fn get(value: &JsValue, key: &str) -> JsValue {
+        Reflect::get(value, &JsValue::from_str(key)).unwrap()
+    }
+
+    fn lexoffice_error(status: u16, body: &str) -> LexOfficeError {
+        LexOfficeError::new(
+            StatusCode::from_u16(status).unwrap(),
+            serde_json::from_str(body).unwrap(),
+            Url::parse("https://api.lexoffice.io/v1/invoices").ok(),
+        )
+    }
+
+    #[wasm_bindgen_test]
+    fn errors_are_lexoffice_errors() {
+        let error = from_lexoffice(Error::NoUuid);
+
+        assert!(error.is_instance_of::<js_sys::Error>());
+        assert!(error.is_instance_of::<LexofficeError>());
+        assert_eq!(get(&error, "name"), "LexofficeError");
+        assert_eq!(get(&error, "kind"), "uuid");
+        assert_eq!(get(&error, "message"), Error::NoUuid.to_string());
+        assert_eq!(Array::from(&get(&error, "issues")).length(), 0);
+        assert_eq!(Array::from(&get(&error, "details")).length(), 0);
+        assert!(get(&error, "status").is_undefined());
+    }
+
+    #[wasm_bindgen_test]
+    fn legacy_issues_are_mapped() {
+        let err = lexoffice_error(
+            400,
+            r#"{"IssueList":[{"i18nKey":"missing_entity","source":"company and person","type":"validation_failure"}]}"#,
+        );
+        let error = from_lexoffice(err.into());
+
+        assert_eq!(get(&error, "kind"), "lexoffice");
+        assert_eq!(get(&error, "status"), 400);
+        assert_eq!(get(&error, "url"), "https://api.lexoffice.io/v1/invoices");
+        assert!(get(&error, "traceId").is_undefined());
+        let issues = Array::from(&get(&error, "issues"));
+        assert_eq!(issues.length(), 1);
+        let issue = issues.get(0);
+        assert_eq!(get(&issue, "i18nKey"), "missing_entity");
+        assert_eq!(get(&issue, "type"), "validation_failure");
+        assert_eq!(get(&issue, "source"), "company and person");
+        assert!(get(&issue, "args").is_undefined());
+        assert_eq!(Array::from(&get(&error, "details")).length(), 0);
+    }
+
+    #[wasm_bindgen_test]
+    fn detailed_messages_are_mapped() {
+        let err = lexoffice_error(
+            406,
+            r#"{
+                "timestamp": "2017-05-11T17:12:31.233+02:00",
+                "status": 406,
+                "error": "Not Acceptable",
+                "path": "/v1/invoices",
+                "traceId": "90d78d0777be",
+                "message": "Validation failed for request.",
+                "details": [{
+                    "violation": "NOTNULL",
+                    "field": "lineItems[0].unitPrice.taxRatePercentage",
+                    "message": "darf nicht leer sein"
+                }]
+            }"#,
+        );
+        let error = from_lexoffice(err.into());
+
+        assert_eq!(get(&error, "kind"), "lexoffice");
+        assert_eq!(get(&error, "status"), 406);
+        assert_eq!(get(&error, "traceId"), "90d78d0777be");
+        assert_eq!(Array::from(&get(&error, "issues")).length(), 0);
+        let details = Array::from(&get(&error, "details"));
+        assert_eq!(details.length(), 1);
+        let detail = details.get(0);
+        assert_eq!(get(&detail, "violation"), "NOTNULL");
+        assert_eq!(
+            get(&detail, "field"),
+            "lineItems[0].unitPrice.taxRatePercentage"
+        );
+        assert_eq!(get(&detail, "message"), "darf nicht leer sein");
+    }
+
+    #[wasm_bindgen_test]
+    fn version_conflicts_carry_the_current_version() {
+        let err = lexoffice_error(409, r#"{"message":"conflict"}"#);
+        let error = from_lexoffice(VersionConflict::new(Some(3), err).into());
+
+        assert_eq!(get(&error, "kind"), "versionConflict");
+        assert_eq!(get(&error, "status"), 409);
+        assert_eq!(get(&error, "currentVersion"), 3);
+    }
+
+    #[wasm_bindgen_test]
+    fn usage_and_parse_errors_have_their_kind() {
+        let error = from_lexoffice(Error::InvalidCancellation(
+            "position 3 does not exist".to_string(),
+        ));
+        assert_eq!(get(&error, "kind"), "usage");
+        assert_eq!(get(&usage_error("no id"), "kind"), "usage");
+        assert_eq!(get(&parse_error("not a uuid"), "kind"), "parse");
+    }
+}
@@ -0,0 +1,95 @@
+use crate::error::usage_error;
+use crate::to_js_err;
+use crate::{FileRequest, IdPromise};
+use js_sys::{Array, ArrayBuffer, Object, Promise, Reflect, Uint8Array};
+use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::{future_to_promise, JsFuture};
+
+// Only the parts of `Blob` that are needed here, this avoids depending on
+// web-sys.
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "Blob")]
+    pub type Blob;
+
+    #[wasm_bindgen(constructor)]
+    fn new(parts: &Array, options: &JsValue) -> Blob;
+
+    #[wasm_bindgen(method, getter, js_name = type)]
+    fn type_(this: &Blob) -> String;
+
+    #[wasm_bindgen(method, js_name = arrayBuffer)]
+    fn array_buffer(this: &Blob) -> Promise;
+
+    /// The data of a file that is uploaded to lexoffice
+    #[wasm_bindgen(typescript_type = "Blob | ArrayBuffer | Uint8Array")]
+    pub type FileData;
+
+    #[wasm_bindgen(typescript_type = "Promise<Blob>")]
+    pub type BlobPromise;
+}
+
+/// Reads the content of `data`. The content type of a `Blob` is used unless
+/// `content_type` is given.
+async fn read(
+    data: &JsValue,
+    content_type: Option<String>,
+) -> Result<(String, Vec<u8>), JsValue> {
+    let (mime, bytes) = if let Some(blob) = data.dyn_ref::<Blob>() {
+        let buffer = JsFuture::from(blob.array_buffer()).await?;
+        let mime = content_type.unwrap_or_else(|| blob.type_());
+        (mime, Uint8Array::new(&buffer).to_vec())
+    } else if let Some(array) = data.dyn_ref::<Uint8Array>() {
+        (content_type.unwrap_or_default(), array.to_vec())
+    } else if data.is_instance_of::<ArrayBuffer>() {
+        (
+            content_type.unwrap_or_default(),
+            Uint8Array::new(data).to_vec(),
+        )
+    } else {
+        return Err(usage_error("expected a Blob, ArrayBuffer or Uint8Array"));
+    };
+    if mime.is_empty() {
+        return Err(usage_error("the content type of the file is unknown"));
+    }
+    Ok((mime, bytes))
+}
+
+#[wasm_bindgen]
+impl FileRequest {
+    /// Uploads a file to lexoffice and returns its id. `contentType` is
+    /// mandatory unless `data` is a `Blob` with a type.
+    pub fn upload(
+        self,
+        data: FileData,
+        content_type: Option<String>,
+    ) -> IdPromise {
+        let request = self.inner;
+        future_to_promise(async move {
+            let (mime, bytes) = read(&data, content_type).await?;
+            let result = request.upload_bytes_str(&mime, bytes).await;
+            let result = to_js_err(result)?;
+            Ok(JsValue::from_str(&result.to_string()))
+        })
+        .unchecked_into()
+    }
+
+    /// Downloads the file identified by `id` into a `Blob` that has the
+    /// content type sent by lexoffice.
+    pub fn download(self, id: String) -> BlobPromise {
+        let request = self.inner;
+        future_to_promise(async move {
+            let id = to_js_err(uuid::Uuid::from_str(&id))?;
+            let file = to_js_err(request.download(id).await)?;
+            let parts = Array::of1(&Uint8Array::from(file.bytes.as_ref()));
+            let options = Object::new();
+            // Setting properties of a new object can't fail
+            let mime = file.mime.to_string().into();
+            Reflect::set(&options, &"type".into(), &mime).unwrap_throw();
+            Ok(Blob::new(&parts, &options).into())
+        })
+        .unchecked_into()
+    }
+}
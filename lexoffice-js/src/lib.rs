@@ -1,8 +1,22 @@
+mod error;
+mod files;
+mod model;
+mod stream;
 mod utils;
+mod voucherlist;
 
+use gloo_utils::format::JsValueSerdeExt;
 use lexoffice::model::*;
 use lexoffice::request::Request;
+use serde::Serialize;
+use std::future::Future;
+use std::str::FromStr;
 use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::future_to_promise;
+
+pub use files::{Blob, FileData};
+pub use voucherlist::VoucherlistRequest;
 
 // When the `wee_alloc` feature is enabled, use `wee_alloc` as the global
 // allocator.
@@ -10,6 +24,75 @@ use wasm_bindgen::prelude::*;
 #[global_allocator]
 static ALLOC: wee_alloc::WeeAlloc = wee_alloc::WeeAlloc::INIT;
 
+#[wasm_bindgen(typescript_custom_section)]
+const TS_REQUEST: &'static str = r#"
+/** Returned by lexoffice when an object was created or changed */
+export interface ResultInfo {
+    id: string;
+    resourceUri: string;
+    createdDate: string;
+    updatedDate: string;
+    version: number;
+}
+
+export type ArticleGetPromise = Promise<articles.Article>;
+export type ArticlePagePromise = Promise<Page<articles.Article>>;
+export type ArticleStream = AsyncIterableIterator<articles.Article>;
+export type ContactGetPromise = Promise<contacts.Contact>;
+export type ContactPagePromise = Promise<Page<contacts.Contact>>;
+export type ContactStream = AsyncIterableIterator<contacts.Contact>;
+export type CountryGetPromise = Promise<countries.Country[]>;
+export type CreditNoteGetPromise = Promise<credit_notes.CreditNote>;
+export type DeliveryNoteGetPromise = Promise<delivery_notes.DeliveryNote>;
+export type DeliveryNotePagePromise =
+    Promise<Page<delivery_notes.DeliveryNote>>;
+export type DeliveryNoteStream =
+    AsyncIterableIterator<delivery_notes.DeliveryNote>;
+export type DownPaymentInvoiceGetPromise =
+    Promise<down_payment_invoices.DownPaymentInvoice>;
+export type DunningGetPromise = Promise<dunnings.Dunning>;
+export type DunningPagePromise = Promise<Page<dunnings.Dunning>>;
+export type DunningStream = AsyncIterableIterator<dunnings.Dunning>;
+export type EventSubscriptionGetPromise =
+    Promise<event_subscriptions.EventSubscription>;
+export type EventSubscriptionPagePromise =
+    Promise<Page<event_subscriptions.EventSubscription>>;
+export type EventSubscriptionStream =
+    AsyncIterableIterator<event_subscriptions.EventSubscription>;
+export type InvoiceGetPromise = Promise<invoices.Invoice>;
+export type OrderConfirmationGetPromise =
+    Promise<order_confirmations.OrderConfirmation>;
+export type PaymentGetPromise = Promise<payments.Payment>;
+export type PaymentConditionGetPromise =
+    Promise<payment_conditions.PaymentCondition[]>;
+export type PostingCategoryGetPromise =
+    Promise<posting_categories.PostingCategory[]>;
+export type ProfileGetPromise = Promise<profile.Profile>;
+export type QuotationGetPromise = Promise<quotations.Quotation>;
+export type QuotationPagePromise = Promise<Page<quotations.Quotation>>;
+export type QuotationStream = AsyncIterableIterator<quotations.Quotation>;
+export type RecurringTemplateGetPromise =
+    Promise<recurring_templates.RecurringTemplate>;
+export type RecurringTemplatePagePromise =
+    Promise<Page<recurring_templates.RecurringTemplate>>;
+export type RecurringTemplateStream =
+    AsyncIterableIterator<recurring_templates.RecurringTemplate>;
+export type VoucherGetPromise = Promise<vouchers.Voucher>;
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "Promise<ResultInfo>")]
+    pub type ResultInfoPromise;
+
+    /// Resolves to the id of a file
+    #[wasm_bindgen(typescript_type = "Promise<string>")]
+    pub type IdPromise;
+
+    #[wasm_bindgen(typescript_type = "Promise<void>")]
+    pub type VoidPromise;
+}
+
 #[wasm_bindgen]
 pub struct Client {
     client: lexoffice::Client,
@@ -17,21 +100,33 @@ pub struct Client {
 
 fn to_js_err<T, E>(result: Result<T, E>) -> Result<T, JsValue>
 where
-    E: std::fmt::Debug,
+    E: Into<lexoffice::Error>,
 {
-    match result {
-        Ok(x) => Ok(x),
-        Err(err) => {
-            let err_str = format!("{:?}", err);
-            // TODO: use proper Error object
-            Err(JsValue::from_str(&err_str))
-        }
-    }
+    result.map_err(|err| error::from_lexoffice(err.into()))
+}
+
+/// Runs `future` and returns a promise that resolves to its serialized
+/// result. `P` is the typed promise that is exposed to TypeScript.
+fn to_promise<P, F, T, E>(future: F) -> P
+where
+    P: JsCast,
+    F: Future<Output = Result<T, E>> + 'static,
+    T: Serialize,
+    E: Into<lexoffice::Error>,
+{
+    future_to_promise(async move {
+        let result = to_js_err(future.await)?;
+        to_js_err(JsValue::from_serde(&result))
+    })
+    .unchecked_into()
 }
 
 #[macro_export]
 macro_rules! request {
-    ( $x:ident, $y:ident $(, $z:ident)* ) => {
+    ( $x:ident, $y:ident ) => {
+        request!($x, $y, $y);
+    };
+    ( $x:ident, $y:ident, $js:ident ) => {
         paste::item! {
             #[wasm_bindgen]
             pub struct [<$x Request>] {
@@ -42,13 +137,14 @@ macro_rules! request {
                     Self{ inner }
                 }
             }
-            impl Into<Request<$x>> for [<$x Request>] {
-                fn into(self) -> Request<$x> {
-                    self.inner
+            impl From<[<$x Request>]> for Request<$x> {
+                fn from(request: [<$x Request>]) -> Self {
+                    request.inner
                 }
             }
             #[wasm_bindgen]
             impl Client {
+                #[wasm_bindgen(js_name = $js)]
                 pub fn $y(&self) -> [<$x Request>] {
                     self.client.request::<$x>().into()
                 }
@@ -57,45 +153,282 @@ macro_rules! request {
     };
 }
 
+// The typed promises and iterators are declared by the arms, their
+// TypeScript types are defined in `TS_REQUEST`.
 #[macro_export]
 macro_rules! request_impl {
     (by_id for $x:ident) => {
         paste::item! {
+            #[wasm_bindgen]
+            extern "C" {
+                #[wasm_bindgen(typescript_type = [<$x GetPromise>])]
+                pub type [<$x GetPromise>];
+            }
             #[wasm_bindgen]
             impl [<$x Request>] {
-                pub async fn update(self, obj: JsValue) -> Result<JsValue, JsValue> {
-                    let obj = to_js_err(obj.into_serde::<$x>())?;
-                    let result = self.inner.update(obj).await;
-                    let result = to_js_err(result)?;
-                    to_js_err(JsValue::from_serde(&result))
+                pub fn get(self, id: String) -> [<$x GetPromise>] {
+                    let request = self.inner;
+                    to_promise(async move { request.by_id_str(&id).await })
                 }
             }
         }
     };
     (paginated for $x:ident) => {
+        paste::item! {
+            #[wasm_bindgen]
+            extern "C" {
+                #[wasm_bindgen(typescript_type = [<$x PagePromise>])]
+                pub type [<$x PagePromise>];
+
+                /// An async iterator over all items of the endpoint. Use it
+                /// with `for await (const item of request.stream()) { ... }`.
+                #[wasm_bindgen(typescript_type = [<$x Stream>])]
+                pub type [<$x Stream>];
+            }
+            #[wasm_bindgen]
+            impl [<$x Request>] {
+                pub fn page(
+                    self,
+                    page: usize,
+                    size: Option<usize>,
+                ) -> [<$x PagePromise>] {
+                    let request = self.inner;
+                    to_promise(async move {
+                        if let Some(size) = size {
+                            request.page_size(page, size).await
+                        } else {
+                            request.page(page).await
+                        }
+                    })
+                }
+
+                pub fn stream(
+                    self,
+                    page_size: Option<usize>,
+                    prefetch: Option<usize>,
+                ) -> [<$x Stream>] {
+                    let mut stream = self.inner.stream();
+                    if let Some(page_size) = page_size {
+                        stream = stream.page_size(page_size);
+                    }
+                    if let Some(prefetch) = prefetch {
+                        stream = stream.prefetch(prefetch);
+                    }
+                    stream::page_iterator(stream)
+                }
+            }
+        }
+    };
+    (storable for $x:ident) => {
+        paste::item! {
+            #[wasm_bindgen]
+            impl [<$x Request>] {
+                pub fn save(self, obj: JsValue) -> ResultInfoPromise {
+                    let request = self.inner;
+                    to_promise(async move {
+                        let obj = obj.into_serde::<$x>()?;
+                        request.save(obj).await
+                    })
+                }
+            }
+        }
+    };
+    (updatable for $x:ident) => {
+        paste::item! {
+            #[wasm_bindgen]
+            impl [<$x Request>] {
+                pub fn update(self, obj: JsValue) -> ResultInfoPromise {
+                    let request = self.inner;
+                    to_promise(async move {
+                        let obj = obj.into_serde::<$x>()?;
+                        request.update(obj).await
+                    })
+                }
+            }
+        }
+    };
+    (deletable for $x:ident) => {
+        paste::item! {
+            #[wasm_bindgen]
+            impl [<$x Request>] {
+                pub fn delete(self, id: String) -> VoidPromise {
+                    let request = self.inner;
+                    to_promise(async move { request.delete_str(&id).await })
+                }
+            }
+        }
+    };
+    (renderable for $x:ident) => {
         paste::item! {
             #[wasm_bindgen]
             impl [<$x Request>] {
-                pub async fn page(self, page: usize, size: Option<usize>) -> Result<JsValue, JsValue> {
-                    let result = if let Some(size) = size {
-                        self.inner.page_size(page, size).await
-                    } else {
-                        self.inner.page(page).await
-                    };
-                    let result = to_js_err(result)?;
-                    to_js_err(JsValue::from_serde(&result))
+                pub fn document(self, id: String) -> IdPromise {
+                    let request = self.inner;
+                    to_promise(async move { request.document_str(&id).await })
+                }
+            }
+        }
+    };
+    (get for $x:ident) => {
+        paste::item! {
+            #[wasm_bindgen]
+            extern "C" {
+                #[wasm_bindgen(typescript_type = [<$x GetPromise>])]
+                pub type [<$x GetPromise>];
+            }
+            #[wasm_bindgen]
+            impl [<$x Request>] {
+                pub fn get(self) -> [<$x GetPromise>] {
+                    let request = self.inner;
+                    to_promise(async move { request.get().await })
                 }
             }
         }
     };
 }
 
+request!(Article, article);
+request_impl!(by_id for Article);
+request_impl!(paginated for Article);
+request_impl!(storable for Article);
+request_impl!(updatable for Article);
+request_impl!(deletable for Article);
+
+#[wasm_bindgen]
+impl ArticleRequest {
+    #[wasm_bindgen(js_name = articleNumber)]
+    pub fn article_number(self, article_number: &str) -> Self {
+        self.inner.article_number(article_number).into()
+    }
+    pub fn gtin(self, gtin: &str) -> Self {
+        self.inner.gtin(gtin).into()
+    }
+    #[wasm_bindgen(js_name = type)]
+    pub fn type_(self, type_: &str) -> Result<ArticleRequest, JsValue> {
+        let type_ =
+            articles::Type::from_str(type_).map_err(error::parse_error)?;
+        Ok(self.inner.type_(&type_).into())
+    }
+}
+
 request!(Contact, contact);
 request_impl!(by_id for Contact);
 request_impl!(paginated for Contact);
+request_impl!(storable for Contact);
+request_impl!(updatable for Contact);
+
+#[wasm_bindgen]
+impl ContactRequest {
+    pub fn email(self, email: &str) -> Self {
+        self.inner.email(email).into()
+    }
+    pub fn name(self, name: &str) -> Self {
+        self.inner.name(name).into()
+    }
+    // u32 instead of i64, which would be a BigInt in JavaScript
+    pub fn number(self, number: u32) -> Self {
+        self.inner.number(number.into()).into()
+    }
+    pub fn customer(self, customer: bool) -> Self {
+        self.inner.customer(customer).into()
+    }
+    pub fn vendor(self, vendor: bool) -> Self {
+        self.inner.vendor(vendor).into()
+    }
+}
+
+request!(Country, country);
+request_impl!(get for Country);
+
+request!(CreditNote, credit_note, creditNote);
+request_impl!(by_id for CreditNote);
+request_impl!(renderable for CreditNote);
+request_impl!(storable for CreditNote);
+
+request!(DeliveryNote, delivery_note, deliveryNote);
+request_impl!(by_id for DeliveryNote);
+request_impl!(renderable for DeliveryNote);
+request_impl!(paginated for DeliveryNote);
+request_impl!(storable for DeliveryNote);
+request_impl!(updatable for DeliveryNote);
+
+request!(DownPaymentInvoice, down_payment_invoice, downPaymentInvoice);
+request_impl!(by_id for DownPaymentInvoice);
+request_impl!(renderable for DownPaymentInvoice);
+
+request!(Dunning, dunning);
+request_impl!(by_id for Dunning);
+request_impl!(renderable for Dunning);
+request_impl!(paginated for Dunning);
+request_impl!(storable for Dunning);
+request_impl!(updatable for Dunning);
+
+request!(EventSubscription, event_subscription, eventSubscription);
+request_impl!(by_id for EventSubscription);
+request_impl!(paginated for EventSubscription);
+request_impl!(storable for EventSubscription);
+request_impl!(updatable for EventSubscription);
+request_impl!(deletable for EventSubscription);
+
+request!(File, file);
+
+request!(Invoice, invoice);
+request_impl!(by_id for Invoice);
+request_impl!(renderable for Invoice);
+request_impl!(storable for Invoice);
+
+request!(OrderConfirmation, order_confirmation, orderConfirmation);
+request_impl!(by_id for OrderConfirmation);
+request_impl!(renderable for OrderConfirmation);
+request_impl!(storable for OrderConfirmation);
+
+request!(Payment, payment);
+request_impl!(by_id for Payment);
+
+request!(PaymentCondition, payment_condition, paymentCondition);
+request_impl!(get for PaymentCondition);
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(
+        typescript_type = "Promise<payment_conditions.PaymentCondition | null>"
+    )]
+    pub type PaymentConditionDefaultPromise;
+}
+
+#[wasm_bindgen]
+impl PaymentConditionRequest {
+    #[wasm_bindgen(js_name = organizationDefault)]
+    pub fn organization_default(self) -> PaymentConditionDefaultPromise {
+        let request = self.inner;
+        to_promise(async move { request.organization_default().await })
+    }
+}
+
+request!(PostingCategory, posting_category, postingCategory);
+request_impl!(get for PostingCategory);
+
+request!(Profile, profile);
+request_impl!(get for Profile);
+
+request!(Quotation, quotation);
+request_impl!(by_id for Quotation);
+request_impl!(renderable for Quotation);
+request_impl!(paginated for Quotation);
+request_impl!(storable for Quotation);
+
+request!(RecurringTemplate, recurring_template, recurringTemplate);
+request_impl!(by_id for RecurringTemplate);
+request_impl!(paginated for RecurringTemplate);
+
+request!(Voucher, voucher);
+request_impl!(by_id for Voucher);
+request_impl!(storable for Voucher);
+request_impl!(updatable for Voucher);
 
 #[wasm_bindgen]
 pub fn client(api_key: &str) -> Client {
+    utils::set_panic_hook();
     let client = lexoffice::Client::new(api_key);
     Client { client }
 }
@@ -0,0 +1,4853 @@
+//! TypeScript definitions of the model. This file was semi-automaticly
+//! generated from The official lexoffice documentation
+//!
+//! See <https://developers.lexoffice.io/docs/> for more information
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_MODEL: &'static str = r##"
+export interface Page<T> {
+    content: T[];
+    first: boolean;
+    last: boolean;
+    totalPages: number;
+    totalElements: number;
+    numberOfElements: number;
+    size: number;
+    number: number;
+    sort: unknown;
+}
+
+/**
+ * This endpoint provides read and write access to articles (products and services). Articles can be referenced by the line items of sales vouchers. It is also possible to use filters on the articles collection.
+ */
+export namespace articles {
+    export type LeadingPrice =
+        | "GROSS"
+        | "NET";
+
+    export type Type =
+        | "PRODUCT"
+        | "SERVICE";
+
+    /**
+     * An article describes a product or a service that can be sold.
+     */
+    export interface Article {
+        /**
+         * Unique id of the article generated on creation by lexoffice.
+         * *Read-only.*
+         */
+        readonly id?: string | null;
+        /**
+         * Unique id of the organization the article belongs to.
+         * *Read-only.*
+         */
+        readonly organizationId?: string | null;
+        /**
+         * The instant of time when the article was created by lexoffice in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2023-04-24T08:20:22.528+02:00*).
+         * *Read-only.*
+         */
+        readonly createdDate?: string | null;
+        /**
+         * The instant of time when the article was updated by lexoffice in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2023-04-24T08:20:22.528+02:00*).
+         * *Read-only.*
+         */
+        readonly updatedDate?: string | null;
+        /**
+         * Archived flag of the article.
+         * *Read-only.*
+         */
+        readonly archived?: boolean | null;
+        /**
+         * Title of the article.
+         */
+        title?: string;
+        /**
+         * Description of the article.
+         */
+        description?: string;
+        /**
+         * The type of the article. Possible values are **PRODUCT** and **SERVICE**.
+         */
+        type?: Type;
+        /**
+         * The article number as given by the user.
+         */
+        articleNumber?: string;
+        /**
+         * Global Trade Item Number (GTIN) of the article. If given, the value will be validated to match one of the formats GTIN-8, GTIN-12, GTIN-13, or GTIN-14.
+         */
+        gtin?: string;
+        /**
+         * Internal note for the article.
+         */
+        note?: string;
+        /**
+         * Unit name of the article. If the provided unit name is not known in lexoffice it will be created on the fly.
+         */
+        unitName?: string;
+        /**
+         * Price of the article. For details see below.
+         */
+        price?: Price;
+        /**
+         * Version *(revision)* number which will be increased on each change to handle [optimistic locking](#optimistic-locking).
+         * *Read-only.*
+         */
+        version?: number;
+    }
+
+    export interface Price {
+        /**
+         * The net price of the article. The value can contain up to 4 decimals.
+         */
+        netPrice?: number;
+        /**
+         * The gross price of the article. The value can contain up to 4 decimals.
+         */
+        grossPrice?: number;
+        /**
+         * The price that is leading for calculations of the other price. Possible values are **NET** and **GROSS**.
+         */
+        leadingPrice?: LeadingPrice;
+        /**
+         * The tax rate of the article. See [the "Supported tax rates" FAQ](#faq-valid-tax-rates) for more information and a list of possible values.
+         */
+        taxRate?: number;
+    }
+}
+
+/**
+ * This endpoint provides read access to contacts (e.g. customers, vendors). A contact can hold addresses, contact information (e.g. phone numbers, email addresses) and contact persons for company related contacts. It is also possible to use filters on the contacts collection.
+ */
+export namespace contacts {
+    /**
+     * ```json
+     * {
+     *     "id": "be9475f4-ef80-442b-8ab9-3ab8b1a2aeb9",
+     *     "organizationId": "aa93e8a8-2aa3-470b-b914-caad8a255dd8",
+     *     "version": 1,
+     *     "roles": {
+     *         "customer": {
+     *             "number": 10307
+     *         },
+     *         "vendor": {
+     *             "number": 70303
+     *         }
+     *     },
+     *     "company": {
+     *         "name": "Testfirma",
+     *         "taxNumber": "12345/12345",
+     *         "vatRegistrationId": "DE123456789",
+     *         "allowTaxFreeInvoices": true,
+     *         "contactPersons": [
+     *             {
+     *                 "salutation": "Herr",
+     *                 "firstName": "Max",
+     *                 "lastName": "Mustermann",
+     *                 "primary": true,
+     *                 "emailAddress": "contactpersonmail@lexoffice.de",
+     *                 "phoneNumber": "08000/11111"
+     *             }
+     *         ]
+     *     },
+     *     "addresses": {
+     *         "billing": [
+     *             {
+     *                 "supplement": "Rechnungsadressenzusatz",
+     *                 "street": "Hauptstr. 5",
+     *                 "zip": "12345",
+     *                 "city": "Musterort",
+     *                 "countryCode": "DE"
+     *             }
+     *         ],
+     *         "shipping": [
+     *             {
+     *                 "supplement": "Lieferadressenzusatz",
+     *                 "street": "Schulstr. 13",
+     *                 "zip": "76543",
+     *                 "city": "MUsterstadt",
+     *                 "countryCode": "DE"
+     *             }
+     *         ]
+     *     },
+     *     "xRechnung": {
+     *         "buyerReference": "04011000-1234512345-35",
+     *         "vendorNumberAtCustomer": "70123456"
+     *     },
+     *     "emailAddresses": {
+     *         "business": [
+     *             "business@lexoffice.de"
+     *         ],
+     *         "office": [
+     *             "office@lexoffice.de"
+     *         ],
+     *         "private": [
+     *             "private@lexoffice.de"
+     *         ],
+     *         "other": [
+     *             "other@lexoffice.de"
+     *         ]
+     *     },
+     *     "phoneNumbers": {
+     *         "business": [
+     *             "08000/1231"
+     *         ],
+     *         "office": [
+     *             "08000/1232"
+     *         ],
+     *         "mobile": [
+     *             "08000/1233"
+     *         ],
+     *         "private": [
+     *             "08000/1234"
+     *         ],
+     *         "fax": [
+     *             "08000/1235"
+     *         ],
+     *         "other": [
+     *             "08000/1236"
+     *         ]
+     *     },
+     *     "note": "Notizen",
+     *     "archived": false
+     * }
+     *
+     * ```
+     */
+    export interface Contact {
+        /**
+         * Unique id of the contact generated on creation by lexoffice.
+         */
+        readonly id?: string | null;
+        /**
+         * Unique id of the organization the contact belongs to.
+         */
+        organizationId?: string;
+        /**
+         * Version *(revision)* number which will be increased on each change to handle [optimistic locking](https://developers.lexoffice.io/docs/#optimistic-locking).
+         * *Read-only.*
+         */
+        version?: number;
+        /**
+         * Defines contact roles and supports further contact information. For object details see below.
+         */
+        roles: Roles;
+        /**
+         * Company related information. For details see below.
+         */
+        company?: Company;
+        /**
+         * Individual person related information. For details see below.
+         */
+        person?: Person;
+        /**
+         * Addresses (e.g. billing and shipping address(es)) for the contact. Contains a list for each address type. For details see below.
+         */
+        addresses?: Addresses;
+        /**
+         * XRechnung related properties of the contact
+         */
+        xRechnung?: XRechnung;
+        /**
+         * Email addresses for the contact. Contains a list for each EMail type. For details see below.
+         */
+        emailAddresses?: EmailAddresses;
+        /**
+         * Phone numbers for the contact. Contains a list for each PhoneNumber type. For details see below.
+         */
+        phoneNumbers?: PhoneNumbers;
+        /**
+         * A note to the contact. This is just an additional information.
+         */
+        note?: string;
+        /**
+         * Archived flag of the contact.
+         * *Read-only.*
+         */
+        readonly archived?: boolean | null;
+    }
+
+    /**
+     * Contains a customer and/or a vendor object. The presence of a role in the JSON implies that the contact will have this role. For example, if the customer object is present, the contact has the role customer.
+     * Please note that each contact must have at least one role.
+     */
+    export interface Roles {
+        /**
+         * May be present. If present the created contact has the role customer.
+         */
+        customer?: Customer;
+        /**
+         * May be present. If present the created contact has the role vendor.
+         */
+        vendor?: Vendor;
+    }
+
+    export interface Customer {
+        /**
+         * Unique customer number within the current organization. This number is created by lexoffice for contacts with role Customer. It cannot be set during creation and cannot be changed.
+         * *Read-only.*
+         */
+        readonly number?: number | null;
+    }
+
+    export interface Vendor {
+        /**
+         * Unique vendor number within the current organization. This number is created by lexoffice for contacts with role Vendor. It cannot be set during creation and cannot be changed.
+         * *Read-only.*
+         */
+        readonly number?: number | null;
+    }
+
+    /**
+     * Use this object to provide information for a contact of type company.
+     */
+    export interface Company {
+        /**
+         * Possible values are **true** or **false**.
+         */
+        allowTaxFreeInvoices?: boolean;
+        /**
+         * Company name
+         */
+        name: string;
+        /**
+         * Tax number for this company --> *"Steuernummer"*.
+         */
+        taxNumber?: string;
+        /**
+         * Vat registration id for this company. This id has to follow the german rules for the vat registration ids --> *"Umsatzsteuer ID"*.
+         */
+        vatRegistrationId?: string;
+        /**
+         * A list of company contact persons. Each entry is an object of [company contact person](https://developers.lexoffice.io/docs/#company-contact-person-details). Details of nested object please see below.
+         */
+        contactPersons?: CompanyContactPerson[];
+    }
+
+    /**
+     * Please note that it's only possible to create and change contacts with a maximum of one contact person. It's possible to retrieve contacts with more than one contact person, but it's not possible to update such a contact via the REST API.
+     */
+    export interface CompanyContactPerson {
+        /**
+         * Salutation for the contact person with max length of 25 characters.
+         */
+        salutation?: string;
+        /**
+         * First name of the contact person.
+         */
+        firstName?: string;
+        /**
+         * Last name of the contact person.
+         */
+        lastName: string;
+        /**
+         * Flags if contact person is the primary contact person. Primary contact persons are shown on vouchers. Default is **false**.
+         */
+        primary?: boolean;
+        /**
+         * Email address of the contact person.
+         */
+        emailAddress?: string;
+        /**
+         * Phone number of the contact person.
+         */
+        phoneNumber?: string;
+    }
+
+    /**
+     * ```json
+     * {
+     *   "id": "e9066f04-8cc7-4616-93f8-ac9ecc8479c8",
+     *   "organizationId": "aa93e8a8-2aa3-470b-b914-caad8a255dd8",
+     *   "version": 0,
+     *   "roles": {
+     *     "customer": {
+     *       "number": 10308
+     *     }
+     *   },
+     *   "person": {
+     *     "salutation": "Frau",
+     *     "firstName": "Inge",
+     *     "lastName": "Musterfrau"
+     *   },
+     *   "archived": false
+     * }
+     *
+     * ```
+     *
+     * Use this object to provide information for a contact of type private person.
+     */
+    export interface Person {
+        /**
+         * Salutation for the individual person with max length of 25 characters.
+         */
+        salutation?: string;
+        /**
+         * First name of the person.
+         */
+        firstName?: string;
+        /**
+         * Last name of the person.
+         */
+        lastName: string;
+    }
+
+    /**
+     * Use this objects to provide billing and shipping information of a contact.
+     *
+     * Please note that it's only possible to create and change contacts with a maximum of one billing and/or one shipping address. It's possible to retrieve contacts with more than one billing and shipping address, but it's not possible to update such a contact via the REST API.
+     */
+    export interface Addresses {
+        /**
+         * A list of billing addresses. Each entry is an object of [address](https://developers.lexoffice.io/docs/#address-details).
+         */
+        billing?: Address[];
+        /**
+         * A list of shipping addresses. Each entry is an object of [address](https://developers.lexoffice.io/docs/#address-details).
+         */
+        shipping?: Address[];
+    }
+
+    export interface Address {
+        /**
+         * Additional address information.
+         */
+        supplement?: string;
+        /**
+         * Street with Street number.
+         */
+        street?: string;
+        /**
+         * Zip code
+         */
+        zip?: string;
+        /**
+         * City
+         */
+        city?: string;
+        /**
+         * Country code in the format of [ISO 3166 alpha2](https://developers.lexoffice.io/docs/#faq-country-codes) (e.g. DE is used for germany).
+         */
+        countryCode: string;
+    }
+
+    /**
+     * Contacts for German public authorities should be created with both of the following attributes set.
+     * This results in the generation of invoice documents conforming to the German XRechnung standard when creating invoices in lexoffice.
+     *
+     * If a customer's `buyerReference` is set, its `vendorNumberAtCustomer` needs to be set as well.
+     */
+    export interface XRechnung {
+        /**
+         * Customer's *Leitweg-ID* conforming to the German XRechnung system
+         */
+        buyerReference?: string;
+        /**
+         * Your vendor number as used by the customer
+         */
+        vendorNumberAtCustomer?: string;
+    }
+
+    /**
+     * Please note that it's only possible to create and change contacts with a maximum of one entry in each of the below described lists. It's possible to retrieve contacts with more than one entry in the lists, but it's not possible to update such a contact via the REST API.
+     */
+    export interface EmailAddresses {
+        /**
+         * A list of email addresses. Each entry is of type string and contains an email address.
+         */
+        business?: string[];
+        /**
+         * A list of email addresses. Each entry is of type string and contains an email address.
+         */
+        office?: string[];
+        /**
+         * A list of email addresses. Each entry is of type string and contains an email address.
+         */
+        private?: string[];
+        /**
+         * A list of email addresses. Each entry is of type string and contains an email address.
+         */
+        other?: string[];
+    }
+
+    /**
+     * Please note that it's only possible to create and change contacts with a maximum of one entry in each of the below described lists. It's possible to retrieve contacts with more than one entry in the lists, but it's not possible to update such a contact via the REST API.
+     */
+    export interface PhoneNumbers {
+        /**
+         * A list of phone numbers. Each entry is of type string and contains a phone number.
+         */
+        business?: string[];
+        /**
+         * A list of phone numbers. Each entry is of type string and contains a phone number.
+         */
+        office?: string[];
+        /**
+         * A list of phone numbers. Each entry is of type string and contains a phone number.
+         */
+        mobile?: string[];
+        /**
+         * A list of phone numbers. Each entry is of type string and contains a phone number.
+         */
+        private?: string[];
+        /**
+         * A list of phone numbers. Each entry is of type string and contains a phone number.
+         */
+        fax?: string[];
+        /**
+         * A list of phone numbers. Each entry is of type string and contains a phone number.
+         */
+        other?: string[];
+    }
+}
+
+/**
+ * The countries endpoint provides read access to the list of countries known to lexoffice.
+ */
+export namespace countries {
+    export type TaxClassification =
+        | "de"
+        | "intraCommunity"
+        | "thirdPartyCountry";
+
+    export interface Country {
+        /**
+         * The country's code. See [our FAQ](https://developers.lexoffice.io/docs/#faq-country-codes) for specification.
+         */
+        countryCode: string;
+        /**
+         * Country name (English)
+         */
+        countryNameEN: string;
+        /**
+         * Country name (German translation)
+         */
+        countryNameDE: string;
+        /**
+         * Tax classification. Possible values are **de** (*Germany*), **intraCommunity** (eligible for *Innergemeinschaftliche Lieferung*), and **thirdPartyCountry** (other). See [below](https://developers.lexoffice.io/docs/#countries-endpoint-country-tax-classification)
+         */
+        taxClassification: TaxClassification;
+    }
+}
+
+/**
+ * This endpoint provides read and write access to credit notes and also the possibility to render the document as a PDF in order to download it. Credit notes can be created as a draft or finalized in open mode.
+ *
+ * With a credit note the partial or full amount of an invoice can be refunded to a customer.
+ *
+ * A credit note may be related to an invoice but can also be standalone without any reference to an invoice. If related to an invoice, the credit note's status will immediately switch to paidoff on finalization and the open payment amount of the related invoice is either reduced or completely paid. There can only be one credit note related to an invoice. An unrelated and finalized credit note will remain in status open until the lexoffice user assigns the payment in lexoffice. Please note, that the printed document does not show the related invoice resp. the invoice number. However, to show the invoice number, it can simply be included in the header text (*introduction*).
+ *
+ * It is possible to create credit notes with value-added tax such as of type net (*Netto*), gross (*Brutto*) or different types of vat-free. For tax-exempt organizations vat-free (*Steuerfrei*) credit notes can be created exclusively. All other vat-free tax types are only usable in combination with a referenced contact in lexoffice. For recipients within the EU these are intra-community supply (*Innergemeinschaftliche Lieferung gem. §13b UStG*), constructional services (*Bauleistungen gem. §13b UStG*) and external services (*Fremdleistungen innerhalb der EU gem. §13b UStG*). For credit notes to third countries, the tax types third party country service (*Dienstleistungen an Drittländer*) and third party country delivery (*Ausfuhrlieferungen an Drittländer*) are possible.
+ */
+export namespace credit_notes {
+    export type TaxType =
+        | "constructionService13b"
+        | "externalService13b"
+        | "gross"
+        | "intraCommunitySupply"
+        | "net"
+        | "thirdPartyCountryDelivery"
+        | "thirdPartyCountryService"
+        | "vatfree";
+
+    export type Type =
+        | "custom"
+        | "material"
+        | "service"
+        | "text";
+
+    export type TaxSubType =
+        | "distanceSales"
+        | "electronicServices";
+
+    export type VoucherStatus =
+        | "draft"
+        | "open"
+        | "paidoff"
+        | "voided";
+
+    /**
+     * ```json
+     * {
+     *    "id":"e9066f04-8cc7-4616-93f8-ac9ecc8479c8",
+     *    "organizationId":"aa93e8a8-2aa3-470b-b914-caad8a255dd8",
+     *    "createdDate":"2019-06-17T18:32:07.480+02:00",
+     *    "updatedDate":"2019-06-17T18:32:07.551+02:00",
+     *    "version":1,
+     *    "language":"de",
+     *    "archived":false,
+     *    "voucherStatus":"draft",
+     *    "voucherNumber":"GS0007",
+     *    "voucherDate":"2017-02-22T00:00:00.000+01:00",
+     *    "address":{
+     *       "name":"Bike & Ride GmbH & Co. KG",
+     *       "supplement":"Gebäude 10",
+     *       "street":"Musterstraße 42",
+     *       "city":"Freiburg",
+     *       "zip":"79112",
+     *       "countryCode":"DE"
+     *    },
+     *    "lineItems":[
+     *       {
+     *          "type":"custom",
+     *          "name":"Abus Kabelschloss Primo 590 ",
+     *          "description":"· 9,5 mm starkes, smoke-mattes Spiralkabel mit integrierter Halterlösung zur Befestigung am Sattelklemmbolzen · bewährter Qualitäts-Schließzylinder mit praktischem Wendeschlüssel · KabelØ: 9,5 mm, Länge: 150 cm",
+     *          "quantity":2,
+     *          "unitName":"Stück",
+     *          "unitPrice":{
+     *             "currency":"EUR",
+     *             "netAmount":13.4,
+     *             "grossAmount":15.946,
+     *             "taxRatePercentage":19
+     *          },
+     *          "lineItemAmount":26.8
+     *       },
+     *       {
+     *          "type":"custom",
+     *          "name":"Energieriegel Testpaket",
+     *          "quantity":1,
+     *          "unitName":"Stück",
+     *          "unitPrice":{
+     *             "currency":"EUR",
+     *             "netAmount":5,
+     *             "grossAmount":5,
+     *             "taxRatePercentage":0
+     *          },
+     *          "lineItemAmount":5
+     *       }
+     *    ],
+     *    "totalPrice":{
+     *       "currency":"EUR",
+     *       "totalNetAmount":31.8,
+     *       "totalGrossAmount":36.89,
+     *       "totalTaxAmount":5.09
+     *    },
+     *    "taxAmounts":[
+     *       {
+     *          "taxRatePercentage":0,
+     *          "taxAmount":0,
+     *          "netAmount":5
+     *       },
+     *       {
+     *          "taxRatePercentage":19,
+     *          "taxAmount":5.09,
+     *          "netAmount":26.8
+     *       }
+     *    ],
+     *    "taxConditions":{
+     *       "taxType":"net"
+     *    },
+     *    "relatedVouchers":[],
+     *    "title":"Rechnungskorrektur",
+     *    "introduction":"Rechnungskorrektur zur Rechnung RE-00020",
+     *    "remark":"Folgende Lieferungen/Leistungen schreiben wir Ihnen gut.",
+     *    "files":{
+     *       "documentFileId":"a79fea19-a892-4ea9-89ad-e879946329a3"
+     *    }
+     * }
+     *
+     * ```
+     */
+    export interface CreditNote {
+        /**
+         * Unique id generated on creation by lexoffice.
+         * *Read-only.*
+         */
+        readonly id?: string | null;
+        /**
+         * Unique id of the organization the credit note belongs to.
+         * *Read-only.*
+         */
+        readonly organizationId?: string | null;
+        /**
+         * The instant of time when the credit note was created by lexoffice in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).
+         * *Read-only.*
+         */
+        readonly createdDate?: string | null;
+        /**
+         * The instant of time when the credit note was updated by lexoffice in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).
+         * *Read-only.*
+         */
+        readonly updatedDate?: string | null;
+        /**
+         * Version *(revision)* number which will be increased on each change to handle [optimistic locking](https://developers.lexoffice.io/docs/#optimistic-locking).
+         * *Read-only.*
+         */
+        version?: number;
+        /**
+         * Specifies the language of the credit note which affects the print document but also set translated default text modules when no values are send (e.g. for introduction). Values accepted in ISO 639-1 code. Possible values are German **de** (default) and English **en**.
+         */
+        language?: string;
+        /**
+         * Specifies if the credit note is only available in the archive in lexoffice.
+         * *Read-only.*
+         */
+        readonly archived?: boolean | null;
+        /**
+         * Specifies the status of the credit note. Possible values are **draft** (is editable), **open** (finalized and no longer editable but not yet paid off), **paidoff** (has been fully paid back to the customer), **voided** (cancelled)
+         * *Read-only.*
+         */
+        readonly voucherStatus?: VoucherStatus | null;
+        /**
+         * The specific number a credit note is aware of. This consecutive number is set by lexoffice on creation.
+         * *Read-only.*
+         */
+        readonly voucherNumber?: string | null;
+        /**
+         * The date of credit note in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).
+         */
+        voucherDate: string;
+        /**
+         * The address of the credit note recipient. For details see below.
+         */
+        address: Address;
+        /**
+         * The items of the credit note. For details see below.
+         */
+        lineItems: LineItems[];
+        /**
+         * The total price of the credit note. For details see below.
+         */
+        totalPrice: TotalPrice;
+        /**
+         * The tax amounts for each tax rate. Please note: As done with every read-only element or object all submitted content (POST) will be ignored. For details see below.
+         * *Read-only.*
+         */
+        readonly taxAmounts?: TaxAmounts[] | null;
+        /**
+         * The tax conditions of the credit note. For details see below.
+         */
+        taxConditions: TaxConditions;
+        /**
+         * The related vouchers of the invoice. *Read-only.*
+         */
+        readonly relatedVouchers?: RelatedVouchers[] | null;
+        /**
+         * (Optional) A title text. The organization's default is used if no value was sent.
+         */
+        title?: string;
+        /**
+         * (Optional) An introductory text / header. The organization's default is used if no value was send. **We recommended to include the invoice number in the header when the credit note is related to an invoice.**
+         */
+        introduction?: string;
+        /**
+         * (Optional) A closing text note. The organization's default is used if no value was send.
+         */
+        remark?: string;
+        /**
+         * The document id for the PDF version of the credit note. For details see below.
+         * *Read-only.*
+         */
+        readonly files?: Files | null;
+    }
+
+    /**
+     * There are two main options to address the recipient of a credit note. First, using an existing lexoffice contact or second, creating a new address.
+     *
+     * For **referencing an existing contact** it is only necessary to provide the UUID of that contact. Usually the billing address is used (for delivery notes, the shipping address will be preferred). Additionally, the referenced address can also be modified for this specific credit note. This can be done by setting all required address fields and this deviated address will not be stored back to the lexoffice contacts.
+     *
+     * The referenced contact needs to have the role customer. For more information please refer to the [contacts endpoint](https://developers.lexoffice.io/docs/#contacts-endpoint).
+     *
+     * Otherwise, a **new address** for the credit note recipient can be created. That type of address is called a "one-time address". A one-time address will not create a new contact in lexoffice. For instance, this could be useful when it is not needed to create a contact in lexoffice for each new credit note.
+     *
+     * Please get in touch with us if you are not sure which option fits your use case best.
+     */
+    export interface Address {
+        /**
+         * If the credit note recipient is (optionally) registered as a contact in lexoffice, this field specifies the related id of the contact.
+         */
+        contactId?: string;
+        /**
+         * The name of the credit note recipient. To use an existing contact of an individual person, provide the name in the format {firstname} {lastname}.
+         */
+        name?: string;
+        /**
+         * (Optional) An address supplement.
+         */
+        supplement?: string;
+        /**
+         * The street (street and street number) of the address.
+         */
+        street?: string;
+        /**
+         * The city of the address.
+         */
+        city?: string;
+        /**
+         * The zip code of the address.
+         */
+        zip?: string;
+        /**
+         * The [ISO 3166 alpha2 country code](https://developers.lexoffice.io/docs/#faq-country-codes) of the address.
+         */
+        countryCode?: string;
+        /**
+         * The contact person selected while editing the voucher. The primary contact person will be used when creating vouchers via the API with a referenced `contactId`.
+         * *Read-only.*
+         */
+        readonly contactPerson?: string | null;
+    }
+
+    export interface LineItems {
+        /**
+         * The field specifies the related id of the product/service.
+         * *Read-only.*
+         */
+        readonly id?: string | null;
+        /**
+         * The type of the item. Possible values are **service** (the line item is related to a supply of services), **material** (the line item is related to a physical product), **custom** (an item without reference in lexoffice and has no id) or **text** (contains only a name and/or a description for informative purposes).
+         */
+        type: Type;
+        /**
+         * The name of the item.
+         */
+        name: string;
+        /**
+         * The description of the item.
+         */
+        description?: string;
+        /**
+         * The amount of the purchased item. The value can contain up to 4 decimals.
+         */
+        quantity?: number;
+        /**
+         * The unit name of the purchased item. If the provided unit name is not known in lexoffice it will be created on the fly.
+         */
+        unitName?: string;
+        /**
+         * The unit price of the purchased item. For details see below.
+         */
+        unitPrice?: UnitPrice;
+        /**
+         * The total price of this line item. Depending by the selected *taxType* in *taxConditions*, the amount must be given either as net or gross. The value can contain up to 2 decimals.
+         * *Read-only.*
+         */
+        readonly lineItemAmount?: number | null;
+    }
+
+    export interface UnitPrice {
+        /**
+         * The currency of the price. Currently only **EUR** is supported.
+         */
+        currency: string;
+        /**
+         * The net price of the unit price. The value can contain up to 4 decimals.
+         */
+        netAmount?: number;
+        /**
+         * The gross price of the unit price. The value can contain up to 4 decimals.
+         */
+        grossAmount?: number;
+        /**
+         * The tax rate of the unit price. See [the "Supported tax rates" FAQ](https://developers.lexoffice.io/docs/#faq-valid-tax-rates) for more information and a list of possible values.. For vat-free sales vouchers the tax rate percentage must be **0**.
+         */
+        taxRatePercentage: number;
+    }
+
+    export interface TotalPrice {
+        /**
+         * The currency of the total price. Currently only **EUR** is supported.
+         */
+        currency: string;
+        /**
+         * The total net price over all line items. The value can contain up to 2 decimals.
+         * *Read-only.*
+         */
+        readonly totalNetAmount?: number | null;
+        /**
+         * The total gross price over all line items. The value can contain up to 2 decimals.
+         * *Read-only.*
+         */
+        readonly totalGrossAmount?: number | null;
+        /**
+         * The total tax amount over all line items. The value can contain up to 2 decimals.
+         * *Read-only.*
+         */
+        readonly totalTaxAmount?: number | null;
+        /**
+         * (Optional) A total discount as absolute value. The value can contain up to 2 decimals.
+         */
+        totalDiscountAbsolute?: number;
+        /**
+         * (Optional) A total discount relative to the gross amount or net amount dependent on the given tax conditions. A contact-specific default will be set if available and no total discount was send. The value can contain up to 2 decimals.
+         */
+        totalDiscountPercentage?: number;
+    }
+
+    export interface TaxAmounts {
+        /**
+         * Tax rate as percentage value. See [the "Supported tax rates" FAQ](https://developers.lexoffice.io/docs/#faq-valid-tax-rates) for more information and a list of possible values..
+         */
+        taxRatePercentage?: number;
+        /**
+         * The total tax amount for this tax rate. The value can contain up to 2 decimals.
+         */
+        taxAmount?: number;
+        /**
+         * The total net amount for this tax rate. The value can contain up to 2 decimals.
+         */
+        netAmount?: number;
+    }
+
+    /**
+     * ```json
+     * "taxConditions": {
+     *     "taxType": "constructionService13b",
+     *     "taxTypeNote": "Steuerschuldnerschaft des Leistungsempfängers (Reverse Charge)"
+     * }
+     *
+     * ```
+     */
+    export interface TaxConditions {
+        /**
+         * The tax type for the credit note. Possible values are **net**, **gross**, **vatfree** (*Steuerfrei*), **intraCommunitySupply** (*Innergemeinschaftliche Lieferung gem. §13b UStG*), **constructionService13b** (*Bauleistungen gem. §13b UStG*), **externalService13b** (*Fremdleistungen innerhalb der EU gem. §13b UStG*), **thirdPartyCountryService** (*Dienstleistungen an Drittländer*), and **thirdPartyCountryDelivery** (*Ausfuhrlieferungen an Drittländer*)
+         */
+        taxType?: TaxType;
+        /**
+         * A tax subtype. Only required for dedicated cases. For vouchers referencing a B2C customer in the EU, and with a `taxType` of `net` or `gross`, the taxSubType may be set to **distanceSales**, or **electronicServices**. Passing a null value results in a standard voucher.
+         * If the organization's `distanceSalesPrinciple` ([profile endpoint](https://developers.lexoffice.io/docs/#profile-endpoint)) is set to `DESTINATION` *and* this attribute is set to **distanceSales** or **electronicServices**, the voucher needs to reference the destination country's tax rates.
+         */
+        taxSubType?: TaxSubType;
+        /**
+         * When *taxType* is set to a vat-free tax type then a note regarding the conditions can be set. When omitted lexoffice sets the organization's default.
+         */
+        taxTypeNote?: string;
+    }
+
+    /**
+     * The *relatedVouchers* property documents all existing voucher relations for the current sales voucher. If no related vouchers exist, an empty list will be returned.
+     */
+    export interface RelatedVouchers {
+        /**
+         * The related sales voucher's unique id.
+         */
+        readonly id?: string | null;
+        /**
+         * The specific number of the related sales voucher.
+         * *Read-only.*
+         */
+        readonly voucherNumber?: string | null;
+        /**
+         * Voucher type of the related sales voucher.
+         */
+        voucherType?: string;
+    }
+
+    export interface Files {
+        /**
+         * The id of the credit note PDF. The PDF will be created when the credit note turns from **draft** into status **open** or **paidoff**. To download the credit note PDF file please use the files endpoint.
+         */
+        documentFileId?: string;
+    }
+}
+
+/**
+ * This endpoint provides read and write access to delivery notes and also the possibility to render the document as a PDF in order to download it. Delivery notes are always created in draft mode and do not need to be finalized.
+ *
+ * When creating delivery notes to existing invoices, it is recommended to use the pursue action to create a reference between the documents. Please note, that the printed document does not show the related invoice resp. the invoice number. However, to show the invoice number, it can simply be included in the header text (*introduction*).
+ *
+ * Delivery notes contain neither payment conditions nor prices, reductions and tax amounts.
+ */
+export namespace delivery_notes {
+    export type TaxType =
+        | "constructionService13b"
+        | "externalService13b"
+        | "gross"
+        | "intraCommunitySupply"
+        | "net"
+        | "thirdPartyCountryDelivery"
+        | "thirdPartyCountryService"
+        | "vatfree";
+
+    export type Type =
+        | "custom"
+        | "material"
+        | "service"
+        | "text";
+
+    export type TaxSubType =
+        | "distanceSales"
+        | "electronicServices";
+
+    export type VoucherStatus =
+        | "draft";
+
+    /**
+     * ```json
+     * {
+     *    "id":"e9066f04-8cc7-4616-93f8-ac9ecc8479c8",
+     *    "organizationId":"aa93e8a8-2aa3-470b-b914-caad8a255dd8",
+     *    "createdDate":"2019-06-17T18:32:07.480+02:00",
+     *    "updatedDate":"2019-06-17T18:32:07.551+02:00",
+     *    "version":1,
+     *    "language":"de",
+     *    "archived":false,
+     *    "voucherStatus":"draft",
+     *    "voucherNumber":"LS0007",
+     *    "voucherDate":"2017-02-22T00:00:00.000+01:00",
+     *    "address":{
+     *       "name":"Bike & Ride GmbH & Co. KG",
+     *       "supplement":"Gebäude 10",
+     *       "street":"Musterstraße 42",
+     *       "city":"Freiburg",
+     *       "zip":"79112",
+     *       "countryCode":"DE"
+     *    },
+     *    "lineItems":[
+     *       {
+     *          "type":"custom",
+     *          "name":"Abus Kabelschloss Primo 590 ",
+     *          "description":"· 9,5 mm starkes, smoke-mattes Spiralkabel mit integrierter Halterlösung zur Befestigung am Sattelklemmbolzen · bewährter Qualitäts-Schließzylinder mit praktischem Wendeschlüssel · KabelØ: 9,5 mm, Länge: 150 cm",
+     *          "quantity":2,
+     *          "unitName":"Stück",
+     *          "unitPrice":{
+     *             "currency":"EUR",
+     *             "netAmount":13.4,
+     *             "grossAmount":15.946,
+     *             "taxRatePercentage":19
+     *          }
+     *       },
+     *       {
+     *          "type":"custom",
+     *          "name":"Energieriegel Testpaket",
+     *          "quantity":1,
+     *          "unitName":"Stück",
+     *          "unitPrice":{
+     *             "currency":"EUR",
+     *             "netAmount":5,
+     *             "grossAmount":5,
+     *             "taxRatePercentage":0
+     *          }
+     *       }
+     *    ],
+     *    "taxConditions":{
+     *       "taxType":"net"
+     *    },
+     *    "relatedVouchers":[],
+     *    "title":"Lieferschein",
+     *    "introduction":"Lieferschein zur Rechnung RE-00020",
+     *    "remark":"Folgende Lieferungen/Leistungen schreiben wir Ihnen gut.",
+     *    "files":{
+     *       "documentFileId":"a79fea19-a892-4ea9-89ad-e879946329a3"
+     *    }
+     * }
+     *
+     * ```
+     */
+    export interface DeliveryNote {
+        /**
+         * Unique id generated on creation by lexoffice.
+         * *Read-only.*
+         */
+        readonly id?: string | null;
+        /**
+         * Unique id of the organization the delivery note belongs to.
+         * *Read-only.*
+         */
+        readonly organizationId?: string | null;
+        /**
+         * The instant of time when the delivery note was created by lexoffice in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).
+         * *Read-only.*
+         */
+        readonly createdDate?: string | null;
+        /**
+         * The instant of time when the delivery note was updated by lexoffice in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).
+         * *Read-only.*
+         */
+        readonly updatedDate?: string | null;
+        /**
+         * Version *(revision)* number which will be increased on each change to handle [optimistic locking](https://developers.lexoffice.io/docs/#optimistic-locking).
+         * *Read-only.*
+         */
+        version?: number;
+        /**
+         * Specifies the language of the delivery note which affects the print document but also set translated default text modules when no values are send (e.g. for introduction). Values accepted in ISO 639-1 code. Possible values are German **de** (default) and English **en**.
+         */
+        language?: string;
+        /**
+         * Specifies if the delivery note is only available in the archive in lexoffice.
+         * *Read-only.*
+         */
+        readonly archived?: boolean | null;
+        /**
+         * Specifies the status of the order confirmation. The only possible status is **draft** (is editable).
+         * *Read-only.*
+         */
+        readonly voucherStatus?: VoucherStatus | null;
+        /**
+         * The specific number a delivery note is aware of. This consecutive number is set by lexoffice on creation.
+         * *Read-only.*
+         */
+        readonly voucherNumber?: string | null;
+        /**
+         * The date of delivery note in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).
+         */
+        voucherDate: string;
+        /**
+         * The address of the delivery note recipient. For details see below.
+         */
+        address: Address;
+        /**
+         * The items of the delivery note. For details see below.
+         */
+        lineItems: LineItems[];
+        /**
+         * The tax conditions of the delivery note. For details see below.
+         */
+        taxConditions: TaxConditions;
+        /**
+         * The related vouchers of the invoice. *Read-only.*
+         */
+        readonly relatedVouchers?: RelatedVouchers[] | null;
+        /**
+         * (Optional) A title text. The organization's default is used if no value was sent.
+         */
+        title?: string;
+        /**
+         * (Optional) An introductory text / header. The organization's default is used if no value was send. **We recommend to include the invoice number in the header when the delivery note is related to an invoice.**
+         */
+        introduction?: string;
+        /**
+         * (Optional) A closing text note. The organization's default is used if no value was send.
+         */
+        remark?: string;
+        /**
+         * (Optional) Describes the terms for delivery. The organization's (or contact-specific) default is used if no value was send.
+         */
+        deliveryTerms?: string;
+        /**
+         * The document id for the PDF version of the delivery note. For details see below.
+         * *Read-only.*
+         */
+        readonly files?: Files | null;
+    }
+
+    /**
+     * There are two main options to address the recipient of a delivery note. First, using an existing lexoffice contact or second, creating a new address.
+     *
+     * For **referencing an existing contact** it is only necessary to provide the UUID of that contact. Usually the billing address is used (for delivery notes, the shipping address will be preferred). Additionally, the referenced address can also be modified for this specific delivery note. This can be done by setting all required address fields and this deviated address will not be stored back to the lexoffice contacts.
+     *
+     * The referenced contact needs to have the role customer. For more information please refer to the [contacts endpoint](https://developers.lexoffice.io/docs/#contacts-endpoint).
+     *
+     * Otherwise, a **new address** for the delivery note recipient can be created. That type of address is called a "one-time address". A one-time address will not create a new contact in lexoffice. For instance, this could be useful when it is not needed to create a contact in lexoffice for each new delivery note.
+     *
+     * Please get in touch with us if you are not sure which option fits your use case best.
+     */
+    export interface Address {
+        /**
+         * If the delivery note recipient is (optionally) registered as a contact in lexoffice, this field specifies the related id of the contact.
+         */
+        contactId?: string;
+        /**
+         * The name of the delivery note recipient. To use an existing contact of an individual person, provide the name in the format {firstname} {lastname}.
+         */
+        name?: string;
+        /**
+         * (Optional) An address supplement.
+         */
+        supplement?: string;
+        /**
+         * The street (street and street number) of the address.
+         */
+        street?: string;
+        /**
+         * The city of the address.
+         */
+        city?: string;
+        /**
+         * The zip code of the address.
+         */
+        zip?: string;
+        /**
+         * The [ISO 3166 alpha2 country code](https://developers.lexoffice.io/docs/#faq-country-codes) of the address.
+         */
+        countryCode?: string;
+        /**
+         * The contact person selected while editing the voucher. The primary contact person will be used when creating vouchers via the API with a referenced `contactId`.
+         * *Read-only.*
+         */
+        readonly contactPerson?: string | null;
+    }
+
+    export interface LineItems {
+        /**
+         * The field specifies the related id of the product/service.
+         * *Read-only.*
+         */
+        readonly id?: string | null;
+        /**
+         * The type of the item. Possible values are **service** (the line item is related to a supply of services), **material** (the line item is related to a physical product), **custom** (an item without reference in lexoffice and has no id) or **text** (contains only a name and/or a description for informative purposes).
+         */
+        type: Type;
+        /**
+         * The name of the item.
+         */
+        name: string;
+        /**
+         * The description of the item.
+         */
+        description?: string;
+        /**
+         * The amount of the purchased item. The value can contain up to 4 decimals.
+         */
+        quantity?: number;
+        /**
+         * The unit name of the purchased item. If the provided unit name is not known in lexoffice it will be created on the fly.
+         */
+        unitName?: string;
+        /**
+         * The unit price of the purchased item. For details see below.
+         */
+        unitPrice?: UnitPrice;
+        /**
+         * The total price of this line item. Depending by the selected *taxType* in *taxConditions*, the amount must be given either as net or gross. The value can contain up to 2 decimals.
+         * *Read-only.*
+         */
+        readonly lineItemAmount?: number | null;
+    }
+
+    export interface UnitPrice {
+        /**
+         * The currency of the price. Currently only **EUR** is supported.
+         */
+        currency: string;
+        /**
+         * The net price of the unit price. The value can contain up to 4 decimals.
+         */
+        netAmount?: number;
+        /**
+         * The gross price of the unit price. The value can contain up to 4 decimals.
+         */
+        grossAmount?: number;
+        /**
+         * The tax rate of the unit price. See [the "Supported tax rates" FAQ](https://developers.lexoffice.io/docs/#faq-valid-tax-rates) for more information and a list of possible values.. For vat-free sales vouchers the tax rate percentage must be **0**.
+         */
+        taxRatePercentage: number;
+    }
+
+    /**
+     * ```json
+     * "taxConditions": {
+     *     "taxType": "constructionService13b",
+     *     "taxTypeNote": "Steuerschuldnerschaft des Leistungsempfängers (Reverse Charge)"
+     * }
+     *
+     * ```
+     */
+    export interface TaxConditions {
+        /**
+         * The tax type for the delivery note. Possible values are **net**, **gross**, **vatfree** (*Steuerfrei*), **intraCommunitySupply** (*Innergemeinschaftliche Lieferung gem. §13b UStG*), **constructionService13b** (*Bauleistungen gem. §13b UStG*), **externalService13b** (*Fremdleistungen innerhalb der EU gem. §13b UStG*), **thirdPartyCountryService** (*Dienstleistungen an Drittländer*), and **thirdPartyCountryDelivery** (*Ausfuhrlieferungen an Drittländer*)
+         */
+        taxType?: TaxType;
+        /**
+         * A tax subtype. Only required for dedicated cases. For vouchers referencing a B2C customer in the EU, and with a `taxType` of `net` or `gross`, the taxSubType may be set to **distanceSales**, or **electronicServices**. Passing a null value results in a standard voucher.
+         * If the organization's `distanceSalesPrinciple` ([profile endpoint](https://developers.lexoffice.io/docs/#profile-endpoint)) is set to `DESTINATION` *and* this attribute is set to **distanceSales** or **electronicServices**, the voucher needs to reference the destination country's tax rates.
+         */
+        taxSubType?: TaxSubType;
+        /**
+         * When *taxType* is set to a vat-free tax type then a note regarding the conditions can be set. When omitted lexoffice sets the organization's default.
+         */
+        taxTypeNote?: string;
+    }
+
+    /**
+     * The *relatedVouchers* property documents all existing voucher relations for the current sales voucher. If no related vouchers exist, an empty list will be returned.
+     */
+    export interface RelatedVouchers {
+        /**
+         * The related sales voucher's unique id.
+         */
+        readonly id?: string | null;
+        /**
+         * The specific number of the related sales voucher.
+         * *Read-only.*
+         */
+        readonly voucherNumber?: string | null;
+        /**
+         * Voucher type of the related sales voucher.
+         */
+        voucherType?: string;
+    }
+
+    export interface Files {
+        /**
+         * The id of the order confirmation PDF. To download the order confirmation PDF file please use the files endpoint.
+         */
+        documentFileId?: string;
+    }
+}
+
+/**
+ * This endpoint provides read-only access to down payment invoices.
+ */
+export namespace down_payment_invoices {
+    export type ShippingConditions = invoices.ShippingConditions;
+    export type TaxType =
+        | "constructionService13b"
+        | "externalService13b"
+        | "gross"
+        | "intraCommunitySupply"
+        | "net"
+        | "thirdPartyCountryDelivery"
+        | "thirdPartyCountryService"
+        | "vatfree";
+
+    export type Type =
+        | "custom"
+        | "material"
+        | "service"
+        | "text";
+
+    export type TaxSubType =
+        | "distanceSales"
+        | "electronicServices";
+
+    export type VoucherStatus =
+        | "draft"
+        | "open"
+        | "paid"
+        | "voided";
+
+    /**
+     * Most properties of down payment invoices are identical with the ones of regular invoices.
+     *
+     * ```json
+     * {
+     *   "id": "0333f0c7-2b89-4889-b64e-68b3ca3f167a",
+     *   "organizationId": "aa93e8a8-2aa3-470b-b914-caad8a255dd8",
+     *   "createdDate": "2021-01-20T10:26:40.956+01:00",
+     *   "updatedDate": "2021-01-21T13:34:13.228+01:00",
+     *   "version": 3,
+     *   "language": "de",
+     *   "archived": false,
+     *   "voucherStatus": "open",
+     *   "voucherNumber": "RE1129",
+     *   "voucherDate": "2021-01-20T10:26:26.565+01:00",
+     *   "dueDate": "2021-02-19T00:00:00.000+01:00",
+     *   "address": {
+     *     "name": "Bike & Ride GmbH & Co. KG",
+     *     "supplement": "Gebäude 10",
+     *     "street": "Musterstraße 42",
+     *     "city": "Freiburg",
+     *     "zip": "79112",
+     *     "countryCode": "DE"
+     *   },
+     *   "lineItems": [
+     *     {
+     *       "type": "custom",
+     *       "name": "Pauschaler Abschlag",
+     *       "quantity": 1,
+     *       "unitPrice": {
+     *         "currency": "EUR",
+     *         "netAmount": 559.66,
+     *         "grossAmount": 666,
+     *         "taxRatePercentage": 19
+     *       },
+     *       "lineItemAmount": 666.00
+     *     }
+     *   ],
+     *   "totalPrice": {
+     *     "currency": "EUR",
+     *     "totalNetAmount": 559.66,
+     *     "totalGrossAmount": 666.00,
+     *     "totalTaxAmount": 106.34
+     *   },
+     *   "taxAmounts": [
+     *     {
+     *       "taxRatePercentage": 19,
+     *       "taxAmount": 106.34,
+     *       "netAmount": 559.66
+     *     }
+     *   ],
+     *   "taxConditions": {
+     *     "taxType": "gross"
+     *   },
+     *   "paymentConditions": {
+     *     "paymentTermLabel": "10 Tage - 3 %, 30 Tage netto",
+     *     "paymentTermLabelTemplate": "{discountRange} Tage -{discount}, {paymentRange} Tage netto",
+     *     "paymentTermDuration": 30,
+     *     "paymentDiscountConditions": {
+     *       "discountPercentage": 3,
+     *       "discountRange": 10
+     *     }
+     *   },
+     *   "shippingConditions": {
+     *     "shippingType": "none"
+     *   },
+     *   "closingInvoiceId": null,
+     *   "relatedVouchers": [],
+     *   "introduction": "Wie vereinbart, erlauben wir uns folgenden pauschalen Abschlag in Rechnung zu stellen.",
+     *   "remark": "Vielen Dank für die gute Zusammenarbeit.",
+     *   "files": {
+     *     "documentFileId": "aa0388c5-20b5-49d7-96ce-0c08ac0482f4"
+     *   },
+     *   "title": "1. Abschlagsrechnung"
+     * }
+     *
+     * ```
+     *
+     * All attributes are read-only, as down payment invoices cannot be created or modified by means of the API.
+     */
+    export interface DownPaymentInvoice {
+        /**
+         * Unique id generated on creation by lexoffice.
+         */
+        readonly id?: string | null;
+        /**
+         * Unique id of the organization the down payment invoice belongs to.
+         */
+        organizationId?: string;
+        /**
+         * The instant of time when the down payment invoice was created by lexoffice in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).
+         * *Read-only.*
+         */
+        readonly createdDate?: string | null;
+        /**
+         * The instant of time when the down payment invoice was updated by lexoffice in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).
+         * *Read-only.*
+         */
+        readonly updatedDate?: string | null;
+        /**
+         * Version *(revision)* number which will be increased on each change to handle [optimistic locking](https://developers.lexoffice.io/docs/#optimistic-locking).
+         * *Read-only.*
+         */
+        version?: number;
+        /**
+         * Specifies the language of the down payment invoice which affects the print document but also set translated default text modules when no values are send (e.g. for introduction). Values accepted in ISO 639-1 code. Possible values are German **de** (default) and English **en**.
+         */
+        language?: string;
+        /**
+         * Specifies if the down payment invoice is only available in the archive in lexoffice.
+         */
+        archived?: boolean;
+        /**
+         * Specifies the status of the down payment invoice. Possible values are **draft** (is editable), **open** (finalized and no longer editable but yet unpaid or only partially paid), **paid** (has been fully paid), **voided** (cancelled)
+         */
+        voucherStatus?: VoucherStatus;
+        /**
+         * The specific number a down payment invoice is aware of. This consecutive number is set by lexoffice on creation.
+         */
+        voucherNumber?: string;
+        /**
+         * The date of the down payment invoice in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).
+         */
+        voucherDate?: string;
+        /**
+         * Sets the date on which the down payment invoice is payable before becoming overdue in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).
+         */
+        dueDate?: string;
+        /**
+         * The address of the down payment invoice recipient. For details see below.
+         */
+        address?: Address;
+        /**
+         * The items of the down payment invoice. For down payment invoices, this list contains exactly one item. For details see below.
+         */
+        lineItems?: LineItems[];
+        /**
+         * The total price of the down payment invoice. For details see below.
+         */
+        totalPrice?: TotalPrice;
+        /**
+         * The tax amounts for each tax rate. Please note: As done with every read-only element or object all submitted content (POST) will be ignored. For details see below.
+         */
+        readonly taxAmounts?: TaxAmounts[] | null;
+        /**
+         * The tax conditions of the down payment invoice. For details see below.
+         */
+        taxConditions?: TaxConditions;
+        /**
+         * The payment conditions of the down payment invoice. The organization's (or contact-specific) default is used if no value was send. For details see below.
+         */
+        paymentConditions?: PaymentConditions;
+        /**
+         * The shipping conditions of the invoice. For details see below.
+         */
+        shippingConditions?: ShippingConditions;
+        /**
+         * Id of the closing invoice that references this down payment invoice, if one exists. Null otherwise.
+         */
+        closingInvoiceId?: string;
+        /**
+         * The related vouchers of the invoice. *Read-only.*
+         */
+        readonly relatedVouchers?: RelatedVouchers[] | null;
+        /**
+         * (Optional) A title text. The organization's default is used if no value was sent.
+         */
+        title?: string;
+        /**
+         * (Optional) An introductory text / header. The organization's default is used if no value was send.
+         */
+        introduction?: string;
+        /**
+         * (Optional) A closing text note. The organization's default is used if no value was send.
+         */
+        remark?: string;
+        /**
+         * The document id for the PDF version of the down payment invoice. For details see below.
+         */
+        files?: Files;
+    }
+
+    /**
+     * There are two main options to address the recipient of a down payment invoice. First, using an existing lexoffice contact or second, creating a new address.
+     *
+     * For **referencing an existing contact** it is only necessary to provide the UUID of that contact. Usually the billing address is used (for delivery notes, the shipping address will be preferred). Additionally, the referenced address can also be modified for this specific down payment invoice. This can be done by setting all required address fields and this deviated address will not be stored back to the lexoffice contacts.
+     *
+     * The referenced contact needs to have the role customer. For more information please refer to the [contacts endpoint](https://developers.lexoffice.io/docs/#contacts-endpoint).
+     *
+     * Otherwise, a **new address** for the down payment invoice recipient can be created. That type of address is called a "one-time address". A one-time address will not create a new contact in lexoffice. For instance, this could be useful when it is not needed to create a contact in lexoffice for each new down payment invoice.
+     *
+     * Please get in touch with us if you are not sure which option fits your use case best.
+     */
+    export interface Address {
+        /**
+         * If the down payment invoice recipient is (optionally) registered as a contact in lexoffice, this field specifies the related id of the contact.
+         */
+        contactId?: string;
+        /**
+         * The name of the down payment invoice recipient. To use an existing contact of an individual person, provide the name in the format {firstname} {lastname}.
+         */
+        name?: string;
+        /**
+         * (Optional) An address supplement.
+         */
+        supplement?: string;
+        /**
+         * The street (street and street number) of the address.
+         */
+        street?: string;
+        /**
+         * The city of the address.
+         */
+        city?: string;
+        /**
+         * The zip code of the address.
+         */
+        zip?: string;
+        /**
+         * The [ISO 3166 alpha2 country code](https://developers.lexoffice.io/docs/#faq-country-codes) of the address.
+         */
+        countryCode?: string;
+        /**
+         * The contact person selected while editing the voucher. The primary contact person will be used when creating vouchers via the API with a referenced `contactId`.
+         * *Read-only.*
+         */
+        readonly contactPerson?: string | null;
+    }
+
+    export interface LineItems {
+        /**
+         * The field specifies the related id of the product/service.
+         * *Read-only.*
+         */
+        readonly id?: string | null;
+        /**
+         * The type of the item. Possible values are **service** (the line item is related to a supply of services), **material** (the line item is related to a physical product), **custom** (an item without reference in lexoffice and has no id) or **text** (contains only a name and/or a description for informative purposes).
+         */
+        type?: Type;
+        /**
+         * The name of the item.
+         */
+        name?: string;
+        /**
+         * The description of the item.
+         */
+        description?: string;
+        /**
+         * The amount of the purchased item. The value can contain up to 4 decimals.
+         */
+        quantity?: number;
+        /**
+         * The unit name of the purchased item. If the provided unit name is not known in lexoffice it will be created on the fly.
+         */
+        unitName?: string;
+        /**
+         * The unit price of the purchased item. For details see below.
+         */
+        unitPrice?: UnitPrice;
+        /**
+         * The offered discount for the item. The value can contain up to 2 decimals.
+         */
+        discountPercentage?: number;
+        /**
+         * The total price of this line item. Depending by the selected *taxType* in *taxConditions*, the amount must be given either as net or gross. The value can contain up to 2 decimals.
+         * *Read-only.*
+         */
+        readonly lineItemAmount?: number | null;
+    }
+
+    export interface UnitPrice {
+        /**
+         * The currency of the price. Currently only **EUR** is supported.
+         */
+        currency?: string;
+        /**
+         * The net price of the unit price. The value can contain up to 4 decimals.
+         */
+        netAmount?: number;
+        /**
+         * The gross price of the unit price. The value can contain up to 4 decimals.
+         */
+        grossAmount?: number;
+        /**
+         * The tax rate of the unit price. See [the "Supported tax rates" FAQ](https://developers.lexoffice.io/docs/#faq-valid-tax-rates) for more information and a list of possible values.. For vat-free sales vouchers the tax rate percentage must be **0**.
+         */
+        taxRatePercentage?: number;
+    }
+
+    export interface TotalPrice {
+        /**
+         * The currency of the total price. Currently only **EUR** is supported.
+         */
+        currency?: string;
+        /**
+         * The total net price over all line items. The value can contain up to 2 decimals.
+         * *Read-only.*
+         */
+        readonly totalNetAmount?: number | null;
+        /**
+         * The total gross price over all line items. The value can contain up to 2 decimals.
+         * *Read-only.*
+         */
+        readonly totalGrossAmount?: number | null;
+        /**
+         * The total tax amount over all line items. The value can contain up to 2 decimals.
+         * *Read-only.*
+         */
+        readonly totalTaxAmount?: number | null;
+        /**
+         * (Optional) A total discount as absolute value. The value can contain up to 2 decimals.
+         */
+        totalDiscountAbsolute?: number;
+        /**
+         * (Optional) A total discount relative to the gross amount or net amount dependent on the given tax conditions. A contact-specific default will be set if available and no total discount was send. The value can contain up to 2 decimals.
+         */
+        totalDiscountPercentage?: number;
+    }
+
+    export interface TaxAmounts {
+        /**
+         * Tax rate as percentage value. See [the "Supported tax rates" FAQ](https://developers.lexoffice.io/docs/#faq-valid-tax-rates) for more information and a list of possible values..
+         */
+        taxRatePercentage?: number;
+        /**
+         * The total tax amount for this tax rate. The value can contain up to 2 decimals.
+         */
+        taxAmount?: number;
+        /**
+         * The total net amount for this tax rate. The value can contain up to 2 decimals.
+         */
+        netAmount?: number;
+    }
+
+    /**
+     * ```json
+     * "taxConditions": {
+     *     "taxType": "constructionService13b",
+     *     "taxTypeNote": "Steuerschuldnerschaft des Leistungsempfängers (Reverse Charge)"
+     * }
+     *
+     * ```
+     */
+    export interface TaxConditions {
+        /**
+         * The tax type for the down payment invoice. Possible values are **net**, **gross**, **vatfree** (*Steuerfrei*), **intraCommunitySupply** (*Innergemeinschaftliche Lieferung gem. §13b UStG*), **constructionService13b** (*Bauleistungen gem. §13b UStG*), **externalService13b** (*Fremdleistungen innerhalb der EU gem. §13b UStG*), **thirdPartyCountryService** (*Dienstleistungen an Drittländer*), and **thirdPartyCountryDelivery** (*Ausfuhrlieferungen an Drittländer*)
+         */
+        taxType?: TaxType;
+        /**
+         * A tax subtype. Only required for dedicated cases. For vouchers referencing a B2C customer in the EU, and with a `taxType` of `net` or `gross`, the taxSubType may be set to **distanceSales**, or **electronicServices**. Passing a null value results in a standard voucher.
+         * If the organization's `distanceSalesPrinciple` ([profile endpoint](https://developers.lexoffice.io/docs/#profile-endpoint)) is set to `DESTINATION` *and* this attribute is set to **distanceSales** or **electronicServices**, the voucher needs to reference the destination country's tax rates.
+         */
+        taxSubType?: TaxSubType;
+        /**
+         * When *taxType* is set to a vat-free tax type then a note regarding the conditions can be set. When omitted lexoffice sets the organization's default.
+         */
+        taxTypeNote?: string;
+    }
+
+    /**
+     * The payment conditions are optional and the organization's or contact-specific defaults will be used if ommitted.
+     */
+    export interface PaymentConditions {
+        /**
+         * A textual note regarding the payment conditions.
+         */
+        paymentTermLabel?: string;
+        /**
+         * A textual note regarding the payment conditions. This label template may contain variables such as the discount range. These variables are enclosed in curly braces, e.g., *{discountRange}*.'
+         * *Read-only.*
+         */
+        readonly paymentTermLabelTemplate?: string | null;
+        /**
+         * The time left (in days) until the payment must be conducted.
+         */
+        paymentTermDuration?: number;
+    }
+
+    /**
+     * The *relatedVouchers* property documents all existing voucher relations for the current sales voucher. If no related vouchers exist, an empty list will be returned.
+     */
+    export interface RelatedVouchers {
+        /**
+         * The related sales voucher's unique id.
+         */
+        readonly id?: string | null;
+        /**
+         * The specific number of the related sales voucher.
+         * *Read-only.*
+         */
+        readonly voucherNumber?: string | null;
+        /**
+         * Voucher type of the related sales voucher.
+         */
+        voucherType?: string;
+    }
+
+    export interface Files {
+        /**
+         * The id of the down payment invoice PDF. The PDF will be created when the invoice turns from **draft** into status **open**. To download the invoice PDF file please use the files endpoint.
+         */
+        documentFileId?: string;
+    }
+}
+
+/**
+ * This endpoint provides read and write access to dunnings and also the possibility to render the document as a PDF in order to download it. Dunnings are always created in draft mode and do not need to be finalized.
+ *
+ * A dunning requires an invoice as a reference, making the `precedingSalesVoucherId` a mandatory query parameter. When creating a dunning, the contact ids of the invoice and the dunning must be equal (or both be absent, resulting in a reference to the collective customer). The name attribute in the address field is copied from the referenced invoice and will be ignored in the dunning structure. The tax conditions must match the tax conditions in the referenced invoice.
+ *
+ * Dunning a down payment invoice is possible as well.
+ */
+export namespace dunnings {
+    export type TaxType =
+        | "constructionService13b"
+        | "externalService13b"
+        | "gross"
+        | "intraCommunitySupply"
+        | "net"
+        | "thirdPartyCountryDelivery"
+        | "thirdPartyCountryService"
+        | "vatfree";
+
+    export type Type =
+        | "custom"
+        | "material"
+        | "service"
+        | "text";
+
+    export type TaxSubType =
+        | "distanceSales"
+        | "electronicServices";
+
+    export type VoucherStatus =
+        | "draft";
+
+    /**
+     * ```json
+     * {
+     *    "id":"e9066f04-8cc7-4616-93f8-ac9ecc8479c8",
+     *    "organizationId":"aa93e8a8-2aa3-470b-b914-caad8a255dd8",
+     *    "createdDate":"2021-07-17T18:32:07.480+02:00",
+     *    "updatedDate":"2021-07-17T18:32:07.551+02:00",
+     *    "version":1,
+     *    "language":"de",
+     *    "archived":false,
+     *    "voucherStatus":"draft",
+     *    "voucherDate":"2021-07-17T00:00:00.000+01:00",
+     *    "address":{
+     *       "supplement":"Gebäude 10",
+     *       "street":"Musterstraße 42",
+     *       "city":"Freiburg",
+     *       "zip":"79112",
+     *       "countryCode":"DE"
+     *    },
+     *    "lineItems":[
+     *       {
+     *          "type": "custom",
+     *          "name": "Energieriegel Testpaket",
+     *          "quantity": 1,
+     *          "unitName": "Stück",
+     *          "unitPrice": {
+     *             "currency": "EUR",
+     *             "netAmount": 5,
+     *             "grossAmount": 5.0,
+     *             "taxRatePercentage": 0.0
+     *          },
+     *          "discountPercentage": 0,
+     *          "lineItemAmount": 5.0
+     *       },
+     *       {
+     *          "type": "text",
+     *          "name": "Strukturieren Sie Ihre Belege durch Text-Elemente.",
+     *          "description": "Das hilft beim Verst\u00e4ndnis"
+     *       }
+     *    ],
+     *    "totalPrice": {
+     *        "currency": "EUR",
+     *        "totalNetAmount": 5.0,
+     *        "totalGrossAmount": 5.0,
+     *        "totalTaxAmount": 0.0
+     *    },
+     *    "taxAmounts": [
+     *        {
+     *            "taxRatePercentage": 0.0,
+     *            "taxAmount": 0.0,
+     *            "netAmount": 5.0
+     *        }
+     *    ],
+     *    "taxConditions": {
+     *        "taxType": "net"
+     *    },
+     *    "shippingConditions": {
+     *        "shippingDate": "2021-07-21T15:16:44.051+02:00",
+     *        "shippingType": "delivery"
+     *    },
+     *    "relatedVouchers": [
+     *        {
+     *            "id": "52cd26a2-ea26-11eb-a4f0-2bb179f80c5a",
+     *            "voucherNumber": "RE0357",
+     *            "voucherType": "invoice"
+     *        }
+     *    ],
+     *    "introduction": "Wir bitten Sie, die nachfolgend aufgelisteten Lieferungen/Leistungen unverzüglich zu begleichen.",
+     *    "remark": "Sollten Sie den offenen Betrag bereits beglichen haben, betrachten Sie dieses Schreiben als gegenstandslos.",
+     *    "files": {
+     *        "documentFileId": "4e19354c-ea26-11eb-a31f-af2d58e85357"
+     *    },
+     *    "title": "Mahnung"
+     * }
+     *
+     * ```
+     */
+    export interface Dunning {
+        /**
+         * Unique id generated on creation by lexoffice.
+         * *Read-only.*
+         */
+        readonly id?: string | null;
+        /**
+         * Unique id of the organization the dunning belongs to.
+         * *Read-only.*
+         */
+        readonly organizationId?: string | null;
+        /**
+         * The instant of time when the dunning was created by lexoffice in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).
+         * *Read-only.*
+         */
+        readonly createdDate?: string | null;
+        /**
+         * The instant of time when the dunning was updated by lexoffice in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).
+         * *Read-only.*
+         */
+        readonly updatedDate?: string | null;
+        /**
+         * Version *(revision)* number which will be increased on each change to handle [optimistic locking](https://developers.lexoffice.io/docs/#optimistic-locking).
+         * *Read-only.*
+         */
+        version?: number;
+        /**
+         * Specifies the language of the dunning which affects the print document but also set translated default text modules when no values are send (e.g. for introduction). Values accepted in ISO 639-1 code. Possible values are German **de** (default) and English **en**.
+         */
+        language?: string;
+        /**
+         * Specifies if the dunning is only available in the archive in lexoffice.
+         * *Read-only.*
+         */
+        readonly archived?: boolean | null;
+        /**
+         * Specifies the status of the order confirmation. The only possible status is **draft** (is editable).
+         * *Read-only.*
+         */
+        readonly voucherStatus?: VoucherStatus | null;
+        /**
+         * The date of dunning in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).
+         */
+        voucherDate: string;
+        /**
+         * The address of the dunning recipient. For details see below.
+         */
+        address: Address;
+        /**
+         * The items of the dunning. For details see below.
+         */
+        lineItems: LineItems[];
+        /**
+         * The related vouchers of the invoice. *Read-only.*
+         */
+        readonly relatedVouchers?: RelatedVouchers[] | null;
+        /**
+         * (Optional) A title text. The organization's default is used if no value was sent.
+         */
+        title?: string;
+        /**
+         * (Optional) An introductory text / header. The organization's default is used if no value was send. **We recommended to include the invoice number in the header when the dunning is related to an invoice.**
+         */
+        introduction?: string;
+        /**
+         * (Optional) A closing text note. The organization's default is used if no value was send.
+         */
+        remark?: string;
+        /**
+         * The document id for the PDF version of the dunning. For details see below.
+         * *Read-only.*
+         */
+        readonly files?: Files | null;
+    }
+
+    /**
+     * There are two main options to address the recipient of a dunning. First, using an existing lexoffice contact or second, creating a new address.
+     *
+     * For **referencing an existing contact** it is only necessary to provide the UUID of that contact. Usually the billing address is used (for delivery notes, the shipping address will be preferred). Additionally, the referenced address can also be modified for this specific dunning. This can be done by setting all required address fields and this deviated address will not be stored back to the lexoffice contacts.
+     *
+     * The referenced contact needs to have the role customer. For more information please refer to the [contacts endpoint](https://developers.lexoffice.io/docs/#contacts-endpoint).
+     *
+     * Otherwise, a **new address** for the dunning recipient can be created. That type of address is called a "one-time address". A one-time address will not create a new contact in lexoffice. For instance, this could be useful when it is not needed to create a contact in lexoffice for each new dunning.
+     *
+     * Please get in touch with us if you are not sure which option fits your use case best.
+     */
+    export interface Address {
+        /**
+         * If the dunning recipient is (optionally) registered as a contact in lexoffice, this field specifies the related id of the contact.
+         */
+        contactId?: string;
+        /**
+         * The name of the dunning recipient. To use an existing contact of an individual person, provide the name in the format {firstname} {lastname}.
+         */
+        name?: string;
+        /**
+         * (Optional) An address supplement.
+         */
+        supplement?: string;
+        /**
+         * The street (street and street number) of the address.
+         */
+        street?: string;
+        /**
+         * The city of the address.
+         */
+        city?: string;
+        /**
+         * The zip code of the address.
+         */
+        zip?: string;
+        /**
+         * The [ISO 3166 alpha2 country code](https://developers.lexoffice.io/docs/#faq-country-codes) of the address.
+         */
+        countryCode?: string;
+        /**
+         * The contact person selected while editing the voucher. The primary contact person will be used when creating vouchers via the API with a referenced `contactId`.
+         * *Read-only.*
+         */
+        readonly contactPerson?: string | null;
+    }
+
+    export interface LineItems {
+        /**
+         * The field specifies the related id of the product/service.
+         * *Read-only.*
+         */
+        readonly id?: string | null;
+        /**
+         * The type of the item. Possible values are **service** (the line item is related to a supply of services), **material** (the line item is related to a physical product), **custom** (an item without reference in lexoffice and has no id) or **text** (contains only a name and/or a description for informative purposes).
+         */
+        type: Type;
+        /**
+         * The name of the item.
+         */
+        name: string;
+        /**
+         * The description of the item.
+         */
+        description?: string;
+        /**
+         * The amount of the purchased item. The value can contain up to 4 decimals.
+         */
+        quantity?: number;
+        /**
+         * The unit name of the purchased item. If the provided unit name is not known in lexoffice it will be created on the fly.
+         */
+        unitName?: string;
+        /**
+         * The unit price of the purchased item. For details see below.
+         */
+        unitPrice?: UnitPrice;
+        /**
+         * The total price of this line item. Depending by the selected *taxType* in *taxConditions*, the amount must be given either as net or gross. The value can contain up to 2 decimals.
+         * *Read-only.*
+         */
+        readonly lineItemAmount?: number | null;
+    }
+
+    export interface UnitPrice {
+        /**
+         * The currency of the price. Currently only **EUR** is supported.
+         */
+        currency: string;
+        /**
+         * The net price of the unit price. The value can contain up to 4 decimals.
+         */
+        netAmount?: number;
+        /**
+         * The gross price of the unit price. The value can contain up to 4 decimals.
+         */
+        grossAmount?: number;
+        /**
+         * The tax rate of the unit price. See [the "Supported tax rates" FAQ](https://developers.lexoffice.io/docs/#faq-valid-tax-rates) for more information and a list of possible values.. For vat-free sales vouchers the tax rate percentage must be **0**.
+         */
+        taxRatePercentage: number;
+    }
+
+    /**
+     * ```json
+     * "taxConditions": {
+     *     "taxType": "constructionService13b",
+     *     "taxTypeNote": "Steuerschuldnerschaft des Leistungsempfängers (Reverse Charge)"
+     * }
+     *
+     * ```
+     */
+    export interface TaxConditions {
+        /**
+         * The tax type for the dunning. Possible values are **net**, **gross**, **vatfree** (*Steuerfrei*), **intraCommunitySupply** (*Innergemeinschaftliche Lieferung gem. §13b UStG*), **constructionService13b** (*Bauleistungen gem. §13b UStG*), **externalService13b** (*Fremdleistungen innerhalb der EU gem. §13b UStG*), **thirdPartyCountryService** (*Dienstleistungen an Drittländer*), and **thirdPartyCountryDelivery** (*Ausfuhrlieferungen an Drittländer*)
+         */
+        taxType?: TaxType;
+        /**
+         * A tax subtype. Only required for dedicated cases. For vouchers referencing a B2C customer in the EU, and with a `taxType` of `net` or `gross`, the taxSubType may be set to **distanceSales**, or **electronicServices**. Passing a null value results in a standard voucher.
+         * If the organization's `distanceSalesPrinciple` ([profile endpoint](https://developers.lexoffice.io/docs/#profile-endpoint)) is set to `DESTINATION` *and* this attribute is set to **distanceSales** or **electronicServices**, the voucher needs to reference the destination country's tax rates.
+         */
+        taxSubType?: TaxSubType;
+        /**
+         * When *taxType* is set to a vat-free tax type then a note regarding the conditions can be set. When omitted lexoffice sets the organization's default.
+         */
+        taxTypeNote?: string;
+    }
+
+    /**
+     * The *relatedVouchers* property documents all existing voucher relations for the current sales voucher. If no related vouchers exist, an empty list will be returned.
+     */
+    export interface RelatedVouchers {
+        /**
+         * The related sales voucher's unique id.
+         */
+        readonly id?: string | null;
+        /**
+         * The specific number of the related sales voucher.
+         * *Read-only.*
+         */
+        readonly voucherNumber?: string | null;
+        /**
+         * Voucher type of the related sales voucher.
+         */
+        voucherType?: string;
+    }
+
+    export interface Files {
+        /**
+         * The id of the order confirmation PDF. To download the order confirmation PDF file please use the files endpoint.
+         */
+        documentFileId?: string;
+    }
+}
+
+/**
+ * Using event subscriptions you will be notified about certain events on resources - e.g. you receive a notification every time a contact changes in lexoffice. This will make pull requests superfluous to keep your data synced between lexoffice and your application. The notifications are implemented as webhooks. Subscribing to an event simply requires the *event type* and your *callback url*. With the event-subscriptions endpoint you can manage your subscriptions within lexoffice.
+ */
+export namespace event_subscriptions {
+    /**
+     * The following table lists all types of events you can subscribe to. The property *EventType* is the combined key of a resource and a event name. The *EventType* is handled in lower case.
+     */
+    export type EventType =
+        | "contact.changed"
+        | "contact.created"
+        | "contact.deleted"
+        | "credit-note.changed"
+        | "credit-note.created"
+        | "credit-note.deleted"
+        | "credit-note.status.changed"
+        | "delivery-note.changed"
+        | "delivery-note.created"
+        | "delivery-note.deleted"
+        | "down-payment-invoice.changed"
+        | "down-payment-invoice.created"
+        | "down-payment-invoice.deleted"
+        | "down-payment-invoice.status.changed"
+        | "dunning.changed"
+        | "dunning.created"
+        | "dunning.deleted"
+        | "invoice.changed"
+        | "invoice.created"
+        | "invoice.deleted"
+        | "invoice.status.changed"
+        | "order-confirmation.changed"
+        | "order-confirmation.created"
+        | "order-confirmation.deleted"
+        | "order-confirmation.status.changed"
+        | "payment.changed"
+        | "quotation.changed"
+        | "quotation.created"
+        | "quotation.deleted"
+        | "quotation.status.changed"
+        | "recurring-template.changed"
+        | "recurring-template.created"
+        | "recurring-template.deleted"
+        | "token.revoked"
+        | "voucher.changed"
+        | "voucher.created"
+        | "voucher.deleted"
+        | "voucher.status.changed";
+
+    /**
+     * ```json
+     * {
+     *   "subscriptionId": "4d43ad14-671d-4e0c-fd4b-2fd8cc117eff",
+     *   "organizationId": "aa93e8a8-2aa3-470b-b914-caad8a255dd8",
+     *   "createdDate": "2018-04-11T12:15:00.000+02:00",
+     *   "eventType": "contact.changed",
+     *   "callbackUrl": "https://example.org/webhook"
+     * }
+     *
+     * ```
+     */
+    export interface EventSubscription {
+        /**
+         * Unique id of the event subscription generated on creation by lexoffice.
+         * *Read-only.*
+         */
+        readonly subscriptionId?: string | null;
+        /**
+         * Unique id of the organization the event subscription belongs to.
+         * *Read-only.*
+         */
+        readonly organizationId?: string | null;
+        /**
+         * The instant of time when the event subscription was created by lexoffice in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).
+         * *Read-only.*
+         */
+        readonly createdDate?: string | null;
+        /**
+         * The event type is a combined key which defines the resource and its event name you are subscribing to. All available events receivable via the API can be taken from the table [Event Types](https://developers.lexoffice.io/docs/#event-subscriptions-endpoint-event-types).
+         */
+        eventType: EventType;
+        /**
+         * When a resource entity triggers an event, the callback url is used to notify the subscriber about it. The payload of the callback is described in [Webhook Callback Properties](https://developers.lexoffice.io/docs/#event-subscriptions-endpoint-webhook-callback-properties).
+         */
+        callbackUrl: string;
+    }
+
+    /**
+     * ```json
+     * {
+     *   "organizationId": "aa93e8a8-2aa3-470b-b914-caad8a255dd8",
+     *   "eventType": "contact.changed",
+     *   "resourceId": "4d43ad14-671d-4e0c-fd4b-2fd8cc117eff",
+     *   "eventDate": "2018-04-11T12:30:00.000+02:00"
+     * }
+     *
+     * ```
+     *
+     * Subscribed events will send a POST request to your given webhook url and contain the following JSON payload.
+     */
+    export interface WebhookCallback {
+        /**
+         * The organization for which an event has been triggered.
+         */
+        organizationId: string;
+        /**
+         * Describes the occurred event. The eventType describes the resource and the event name.
+         */
+        eventType: EventType;
+        /**
+         * The resource entity on which the event has occurred. Use the corresponding resource endpoint and the resourceId to get the latest data of the resource entity.
+         */
+        resourceId: string;
+        /**
+         * The instant of time when the event was triggered in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).
+         * *Read-only.*
+         */
+        readonly eventDate?: string | null;
+    }
+}
+
+/**
+ * Use this endpoint to upload and/or download files, e.g. vouchers or invoices.
+ */
+export namespace files {
+    export interface File {}
+}
+
+/**
+ * This endpoint provides read and write access to invoices. The created document(s) can be rendered as PDF files, and, depending on the [input data](#faq-xrechnung), in the German [XRechung](#faq-xrechnung) data format. PDF files can be downloaded. Invoices can be created in draft or open (finalized) states.
+ *
+ * A higher level description of the handling of invoices via the lexoffice API can be found in the [invoice cookbook](../cookbooks/invoices/) (German only).
+ *
+ * It is possible to create invoices with value-added tax such as of type net (*Netto*), gross (*Brutto*) or different types of vat-free. For tax-exempt organizations vat-free (*Steuerfrei*) invoices can be created exclusively. All other vat-free tax types are only usable in combination with a referenced contact in lexoffice. For recipients within the EU these are intra-community supply (*Innergemeinschaftliche Lieferung gem. §13b UStG*), constructional services (*Bauleistungen gem. §13b UStG*) and external services (*Fremdleistungen innerhalb der EU gem. §13b UStG*). For invoices to third countries, the tax types third party country service (*Dienstleistungen an Drittländer*) and third party country delivery (*Ausfuhrlieferungen an Drittländer*) are possible.
+ *
+ * Read-only support for invoices for down payment (*Abschlagsrechnung*) is provided by the [Down Payment Invoice Endpoint](#down-payment-invoice-endpoint).
+ */
+export namespace invoices {
+    export type TaxType =
+        | "constructionService13b"
+        | "externalService13b"
+        | "gross"
+        | "intraCommunitySupply"
+        | "net"
+        | "thirdPartyCountryDelivery"
+        | "thirdPartyCountryService"
+        | "vatfree";
+
+    export type Type =
+        | "custom"
+        | "material"
+        | "service"
+        | "text";
+
+    export type ShippingType =
+        | "delivery"
+        | "deliveryperiod"
+        | "none"
+        | "service"
+        | "serviceperiod";
+
+    export type TaxSubType =
+        | "distanceSales"
+        | "electronicServices";
+
+    export type VoucherStatus =
+        | "draft"
+        | "open"
+        | "paid"
+        | "voided";
+
+    /**
+     * ```json
+     * {
+     *    "id":"e9066f04-8cc7-4616-93f8-ac9ecc8479c8",
+     *    "organizationId":"aa93e8a8-2aa3-470b-b914-caad8a255dd8",
+     *    "createdDate":"2017-04-24T08:20:22.528+02:00",
+     *    "updatedDate":"2017-04-24T08:20:22.528+02:00",
+     *    "version":0,
+     *    "language":"de",
+     *    "archived":false,
+     *    "voucherStatus":"draft",
+     *    "voucherNumber":"RE1019",
+     *    "voucherDate":"2017-02-22T00:00:00.000+01:00",
+     *    "dueDate":null,
+     *    "address":{
+     *       "contactId":null,
+     *       "name":"Bike & Ride GmbH & Co. KG",
+     *       "supplement":"Gebäude 10",
+     *       "street":"Musterstraße 42",
+     *       "city":"Freiburg",
+     *       "zip":"79112",
+     *       "countryCode":"DE"
+     *    },
+     *    "xRechnung":null,
+     *    "lineItems":[
+     *       {
+     *          "id":"97b98491-e953-4dc9-97a9-ae437a8052b4",
+     *          "type":"material",
+     *          "name":"Abus Kabelschloss Primo 590 ",
+     *          "description":"· 9,5 mm starkes, smoke-mattes Spiralkabel mit integrierter Halterlösung zur Befestigung am Sattelklemmbolzen · bewährter Qualitäts-Schließzylinder mit praktischem Wendeschlüssel · KabelØ: 9,5 mm, Länge: 150 cm",
+     *          "quantity":2,
+     *          "unitName":"Stück",
+     *          "unitPrice":{
+     *             "currency":"EUR",
+     *             "netAmount":13.4,
+     *             "grossAmount":15.95,
+     *             "taxRatePercentage":19
+     *          },
+     *          "discountPercentage":50,
+     *          "lineItemAmount":13.4
+     *       },
+     *       {
+     *          "id":"dc4c805b-7df1-4310-a548-22be4499eb04",
+     *          "type":"service",
+     *          "name":"Aufwändige Montage",
+     *          "description":"Aufwand für arbeitsintensive Montagetätigkeit",
+     *          "quantity":1,
+     *          "unitName":"Stunde",
+     *          "unitPrice":{
+     *             "currency":"EUR",
+     *             "netAmount":8.32,
+     *             "grossAmount":8.9,
+     *             "taxRatePercentage":7
+     *          },
+     *          "discountPercentage":0,
+     *          "lineItemAmount":8.32
+     *       },
+     *       {
+     *          "id":null,
+     *          "type":"custom",
+     *          "name":"Energieriegel Testpaket",
+     *          "description":null,
+     *          "quantity":1,
+     *          "unitName":"Stück",
+     *          "unitPrice":{
+     *             "currency":"EUR",
+     *             "netAmount":5,
+     *             "grossAmount":5,
+     *             "taxRatePercentage":0
+     *          },
+     *          "discountPercentage":0,
+     *          "lineItemAmount":5
+     *       },
+     *       {
+     *          "type":"text",
+     *          "name":"Freitextposition",
+     *          "description":"This item type can contain either a name or a description or both."
+     *       }
+     *    ],
+     *    "totalPrice":{
+     *       "currency":"EUR",
+     *       "totalNetAmount":26.72,
+     *       "totalGrossAmount":29.85,
+     *       "totalTaxAmount":3.13,
+     *       "totalDiscountAbsolute":null,
+     *       "totalDiscountPercentage":null
+     *    },
+     *    "taxAmounts":[
+     *       {
+     *          "taxRatePercentage":0,
+     *          "taxAmount":0,
+     *          "netAmount":5
+     *       },
+     *       {
+     *          "taxRatePercentage":7,
+     *          "taxAmount":0.58,
+     *          "netAmount":8.32
+     *       },
+     *       {
+     *          "taxRatePercentage":19,
+     *          "taxAmount":2.55,
+     *          "netAmount":13.4
+     *       }
+     *    ],
+     *    "taxConditions":{
+     *       "taxType":"net",
+     *       "taxTypeNote":null
+     *    },
+     *    "paymentConditions":{
+     *       "paymentTermLabel":"10 Tage - 3 %, 30 Tage netto",
+     *       "paymentTermLabelTemplate":"{discountRange} Tage -{discount}, {paymentRange} Tage netto",
+     *       "paymentTermDuration":30,
+     *       "paymentDiscountConditions":{
+     *          "discountPercentage":3,
+     *          "discountRange":10
+     *       }
+     *    },
+     *    "shippingConditions":{
+     *       "shippingDate":"2017-04-22T00:00:00.000+02:00",
+     *       "shippingEndDate":null,
+     *       "shippingType":"delivery"
+     *    },
+     *    "closingInvoice":false,
+     *    "claimedGrossAmount":null,
+     *    "downPaymentDeductions":null,
+     *    "recurringTemplateId":null,
+     *    "relatedVouchers":[],
+     *    "title":"Rechnung",
+     *    "introduction":"Ihre bestellten Positionen stellen wir Ihnen hiermit in Rechnung",
+     *    "remark":"Vielen Dank für Ihren Einkauf",
+     *    "files":{
+     *       "documentFileId":"75295db7-7e69-4630-befd-a7f4ddfdaa83"
+     *    }
+     * }
+     *
+     * ```
+     */
+    export interface Invoice {
+        /**
+         * Unique id generated on creation by lexoffice.
+         * *Read-only.*
+         */
+        readonly id?: string | null;
+        /**
+         * Unique id of the organization the invoice belongs to.
+         * *Read-only.*
+         */
+        readonly organizationId?: string | null;
+        /**
+         * The instant of time when the invoice was created by lexoffice in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).
+         * *Read-only.*
+         */
+        readonly createdDate?: string | null;
+        /**
+         * The instant of time when the invoice was updated by lexoffice in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).
+         * *Read-only.*
+         */
+        readonly updatedDate?: string | null;
+        /**
+         * Version *(revision)* number which will be increased on each change to handle [optimistic locking](https://developers.lexoffice.io/docs/#optimistic-locking).
+         * *Read-only.*
+         */
+        version?: number;
+        /**
+         * Specifies the language of the invoice which affects the print document but also set translated default text modules when no values are send (e.g. for introduction). Values accepted in ISO 639-1 code. Possible values are German **de** (default) and English **en**.
+         */
+        language?: string;
+        /**
+         * Specifies if the invoice is only available in the archive in lexoffice.
+         * *Read-only.*
+         */
+        readonly archived?: boolean | null;
+        /**
+         * Specifies the status of the invoice. Possible values are **draft** (is editable), **open** (finalized and no longer editable but yet unpaid or only partially paid), **paid** (has been fully paid), **voided** (cancelled)
+         * *Read-only.*
+         */
+        readonly voucherStatus?: VoucherStatus | null;
+        /**
+         * The specific number an invoice is aware of. This consecutive number is set by lexoffice on creation.
+         * *Read-only.*
+         */
+        readonly voucherNumber?: string | null;
+        /**
+         * The date of the invoice in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).
+         */
+        voucherDate?: string;
+        /**
+         * Sets the date on which the invoice is payable before becoming overdue in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).
+         * *Read-only.*
+         */
+        readonly dueDate?: string | null;
+        /**
+         * The address of the invoice recipient. For details see below.
+         */
+        address?: Address;
+        /**
+         * XRechnung related properties for XRechnung enabled invoices. For details see below
+         */
+        xRechnung?: XRechnung;
+        /**
+         * The items of the invoice. For details see below.
+         */
+        lineItems?: LineItems[];
+        /**
+         * The total price of the invoice. For details see below.
+         */
+        totalPrice?: TotalPrice;
+        /**
+         * The tax amounts for each tax rate. Please note: As done with every read-only element or object all submitted content (POST) will be ignored. For details see below.
+         * *Read-only.*
+         */
+        readonly taxAmounts?: TaxAmounts[] | null;
+        /**
+         * The tax conditions of the invoice. For details see below.
+         */
+        taxConditions?: TaxConditions;
+        /**
+         * The payment conditions of the invoice. The organization's (or contact-specific) default is used if no value was send. For details see below.
+         */
+        paymentConditions?: PaymentConditions;
+        /**
+         * The shipping conditions of the invoice. For details see below.
+         */
+        shippingConditions?: ShippingConditions;
+        /**
+         * Denotes whether this invoice is a closing invoice ([Schlussrechnung](https://developers.lexoffice.io/docs/#invoices-endpoint-closing-invoices))
+         * *Read-only.*
+         */
+        readonly closingInvoice?: boolean | null;
+        /**
+         * The remaining gross amount (see [description below](https://developers.lexoffice.io/docs/#invoices-endpoint-closing-invoices))
+         * *Read-only.*
+         */
+        readonly claimedGrossAmount?: number | null;
+        /**
+         * The down payments connected to this closing invoice.
+         * *Read-only.*
+         */
+        readonly downPaymentDeductions?: DownPaymentDeductions[] | null;
+        /**
+         * The id of the recurring template, if this is a recurring invoice deduced from a template. Null otherwise.
+         */
+        recurringTemplateId?: string;
+        /**
+         * The related vouchers of the invoice. *Read-only.*
+         */
+        readonly relatedVouchers?: RelatedVouchers[] | null;
+        /**
+         * (Optional) A title text. The organization's default is used if no value was sent.
+         */
+        title?: string;
+        /**
+         * (Optional) An introductory text / header. The organization's default is used if no value was send.
+         */
+        introduction?: string;
+        /**
+         * (Optional) A closing text note. The organization's default is used if no value was send.
+         */
+        remark?: string;
+        /**
+         * The document id for the PDF version of the invoice. For details see below.
+         * *Read-only.*
+         */
+        readonly files?: Files | null;
+    }
+
+    /**
+     * There are two main options to address the recipient of an invoice. First, using an existing lexoffice contact or second, creating a new address.
+     *
+     * For **referencing an existing contact** it is only necessary to provide the UUID of that contact. Usually the billing address is used (for delivery notes, the shipping address will be preferred). Additionally, the referenced address can also be modified for this specific invoice. This can be done by setting all required address fields and this deviated address will not be stored back to the lexoffice contacts.
+     *
+     * The referenced contact needs to have the role customer. For more information please refer to the [contacts endpoint](https://developers.lexoffice.io/docs/#contacts-endpoint).
+     *
+     * Otherwise, a **new address** for the invoice recipient can be created. That type of address is called a "one-time address". A one-time address will not create a new contact in lexoffice. For instance, this could be useful when it is not needed to create a contact in lexoffice for each new invoice.
+     *
+     * Please get in touch with us if you are not sure which option fits your use case best.
+     */
+    export interface Address {
+        /**
+         * If the invoice recipient is (optionally) registered as a contact in lexoffice, this field specifies the related id of the contact.
+         */
+        contactId?: string;
+        /**
+         * The name of the invoice recipient. To use an existing contact of an individual person, provide the name in the format {firstname} {lastname}.
+         */
+        name?: string;
+        /**
+         * (Optional) An address supplement.
+         */
+        supplement?: string;
+        /**
+         * The street (street and street number) of the address.
+         */
+        street?: string;
+        /**
+         * The city of the address.
+         */
+        city?: string;
+        /**
+         * The zip code of the address.
+         */
+        zip?: string;
+        /**
+         * The [ISO 3166 alpha2 country code](https://developers.lexoffice.io/docs/#faq-country-codes) of the address.
+         */
+        countryCode?: string;
+        /**
+         * The contact person selected while editing the voucher. The primary contact person will be used when creating vouchers via the API with a referenced `contactId`.
+         * *Read-only.*
+         */
+        readonly contactPerson?: string | null;
+    }
+
+    export interface LineItems {
+        /**
+         * The field specifies the related id of the product/service.
+         * *Read-only.*
+         */
+        readonly id?: string | null;
+        /**
+         * The type of the item. Possible values are **service** (the line item is related to a supply of services), **material** (the line item is related to a physical product), **custom** (an item without reference in lexoffice and has no id) or **text** (contains only a name and/or a description for informative purposes).
+         */
+        type?: Type;
+        /**
+         * The name of the item.
+         */
+        name?: string;
+        /**
+         * The description of the item.
+         */
+        description?: string;
+        /**
+         * The amount of the purchased item. The value can contain up to 4 decimals.
+         */
+        quantity?: number;
+        /**
+         * The unit name of the purchased item. If the provided unit name is not known in lexoffice it will be created on the fly.
+         */
+        unitName?: string;
+        /**
+         * The unit price of the purchased item. For details see below.
+         */
+        unitPrice?: UnitPrice;
+        /**
+         * The offered discount for the item. The value can contain up to 2 decimals.
+         */
+        discountPercentage?: number;
+        /**
+         * The total price of this line item. Depending by the selected *taxType* in *taxConditions*, the amount must be given either as net or gross. The value can contain up to 2 decimals.
+         * *Read-only.*
+         */
+        readonly lineItemAmount?: number | null;
+    }
+
+    export interface UnitPrice {
+        /**
+         * The currency of the price. Currently only **EUR** is supported.
+         */
+        currency?: string;
+        /**
+         * The net price of the unit price. The value can contain up to 4 decimals.
+         */
+        netAmount?: number;
+        /**
+         * The gross price of the unit price. The value can contain up to 4 decimals.
+         */
+        grossAmount?: number;
+        /**
+         * The tax rate of the unit price. See [the "Supported tax rates" FAQ](https://developers.lexoffice.io/docs/#faq-valid-tax-rates) for more information and a list of possible values.. For vat-free sales vouchers the tax rate percentage must be **0**.
+         */
+        taxRatePercentage?: number;
+    }
+
+    export interface TotalPrice {
+        /**
+         * The currency of the total price. Currently only **EUR** is supported.
+         */
+        currency?: string;
+        /**
+         * The total net price over all line items. The value can contain up to 2 decimals.
+         * *Read-only.*
+         */
+        readonly totalNetAmount?: number | null;
+        /**
+         * The total gross price over all line items. The value can contain up to 2 decimals.
+         * *Read-only.*
+         */
+        readonly totalGrossAmount?: number | null;
+        /**
+         * The total tax amount over all line items. The value can contain up to 2 decimals.
+         * *Read-only.*
+         */
+        readonly totalTaxAmount?: number | null;
+        /**
+         * (Optional) A total discount as absolute value. The value can contain up to 2 decimals.
+         */
+        totalDiscountAbsolute?: number;
+        /**
+         * (Optional) A total discount relative to the gross amount or net amount dependent on the given tax conditions. A contact-specific default will be set if available and no total discount was send. The value can contain up to 2 decimals.
+         */
+        totalDiscountPercentage?: number;
+    }
+
+    export interface TaxAmounts {
+        /**
+         * Tax rate as percentage value. See [the "Supported tax rates" FAQ](https://developers.lexoffice.io/docs/#faq-valid-tax-rates) for more information and a list of possible values..
+         */
+        taxRatePercentage?: number;
+        /**
+         * The total tax amount for this tax rate. The value can contain up to 2 decimals.
+         */
+        taxAmount?: number;
+        /**
+         * The total net amount for this tax rate. The value can contain up to 2 decimals.
+         */
+        netAmount?: number;
+    }
+
+    /**
+     * ```json
+     * "taxConditions": {
+     *     "taxType": "constructionService13b",
+     *     "taxTypeNote": "Steuerschuldnerschaft des Leistungsempfängers (Reverse Charge)"
+     * }
+     *
+     * ```
+     */
+    export interface TaxConditions {
+        /**
+         * The tax type for the invoice. Possible values are **net**, **gross**, **vatfree** (*Steuerfrei*), **intraCommunitySupply** (*Innergemeinschaftliche Lieferung gem. §13b UStG*), **constructionService13b** (*Bauleistungen gem. §13b UStG*), **externalService13b** (*Fremdleistungen innerhalb der EU gem. §13b UStG*), **thirdPartyCountryService** (*Dienstleistungen an Drittländer*), and **thirdPartyCountryDelivery** (*Ausfuhrlieferungen an Drittländer*)
+         */
+        taxType?: TaxType;
+        /**
+         * A tax subtype. Only required for dedicated cases. For vouchers referencing a B2C customer in the EU, and with a `taxType` of `net` or `gross`, the taxSubType may be set to **distanceSales**, or **electronicServices**. Passing a null value results in a standard voucher.
+         * If the organization's `distanceSalesPrinciple` ([profile endpoint](https://developers.lexoffice.io/docs/#profile-endpoint)) is set to `DESTINATION` *and* this attribute is set to **distanceSales** or **electronicServices**, the voucher needs to reference the destination country's tax rates.
+         */
+        taxSubType?: TaxSubType;
+        /**
+         * When *taxType* is set to a vat-free tax type then a note regarding the conditions can be set. When omitted lexoffice sets the organization's default.
+         */
+        taxTypeNote?: string;
+    }
+
+    /**
+     * The payment conditions are optional and the organization's or contact-specific defaults will be used if ommitted.
+     */
+    export interface PaymentConditions {
+        /**
+         * A textual note regarding the payment conditions.
+         */
+        paymentTermLabel?: string;
+        /**
+         * A textual note regarding the payment conditions. This label template may contain variables such as the discount range. These variables are enclosed in curly braces, e.g., *{discountRange}*.'
+         * *Read-only.*
+         */
+        readonly paymentTermLabelTemplate?: string | null;
+        /**
+         * The time left (in days) until the payment must be conducted.
+         */
+        paymentTermDuration?: number;
+        /**
+         * The payment discount conditions for the invoice.
+         */
+        paymentDiscountConditions?: PaymentDiscountConditions[];
+    }
+
+    export interface PaymentDiscountConditions {
+        /**
+         * The discount offered in return for payment within the **discountRange**. The value can contain up to 2 decimals.
+         */
+        discountPercentage?: number;
+        /**
+         * The time left (in days) the discount is valid.
+         */
+        discountRange?: number;
+    }
+
+    export interface ShippingConditions {
+        /**
+         * The instant of time when the purchased items have to be shipped. Value in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).
+         */
+        shippingDate?: string;
+        /**
+         * An end instant in order to specify a shipping period of time. Value in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*). Must not specify an instant before *shippingDate*.
+         */
+        shippingEndDate?: string;
+        /**
+         * The type of the shipping. Possible values are **service** (a service is supplied on *shippingDate*), **serviceperiod** (a service is supplied within the period [*shippingDate*,*shippingEndDate*] ), **delivery** (a product is delivered), **deliveryperiod** (a product is delivered within the period [*shippingDate*,*shippingEndDate*]) and **none** (no shipping date has to be provided)
+         */
+        shippingType?: ShippingType;
+    }
+
+    /**
+     * The *relatedVouchers* property documents all existing voucher relations for the current sales voucher. If no related vouchers exist, an empty list will be returned.
+     */
+    export interface RelatedVouchers {
+        /**
+         * The related sales voucher's unique id.
+         */
+        readonly id?: string | null;
+        /**
+         * The specific number of the related sales voucher.
+         * *Read-only.*
+         */
+        readonly voucherNumber?: string | null;
+        /**
+         * Voucher type of the related sales voucher.
+         */
+        voucherType?: string;
+    }
+
+    /**
+     * Use the [Down Payment Invoices endpoint](https://developers.lexoffice.io/docs/#down-payment-invoices-endpoint) to retrieve details of a down payment invoice.
+     */
+    export interface DownPaymentDeductions {
+        /**
+         * The down payment deduction's unique id.
+         */
+        readonly id?: string | null;
+        /**
+         * Voucher type of the down payment. Currently, always contains the string `downpaymentinvoice`.
+         */
+        voucherType?: string;
+        /**
+         * Down payment's title
+         */
+        title?: string;
+        /**
+         * Down payment's voucher number
+         */
+        voucherNumber?: string;
+        /**
+         * Down payment's date in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).
+         */
+        voucherDate?: string;
+        /**
+         * The gross amount received for this down payment invoice
+         */
+        receivedGrossAmount?: number;
+        /**
+         * The net amount received for this down payment invoice
+         */
+        receivedNetAmount?: number;
+        /**
+         * Tax received for this down payment invoice
+         */
+        receivedTaxAmount?: number;
+        /**
+         * The tax rate used for amount calculation in this down payment invoice
+         */
+        taxRatePercentage?: number;
+    }
+
+    export interface Files {
+        /**
+         * The id of the invoice PDF. The PDF will be created when the invoice turns from **draft** into status **open**. To download the invoice PDF file please use the files endpoint.
+         */
+        documentFileId?: string;
+    }
+
+    /**
+     * XRechnung properties are only relevant if an XRechnung enabled contact is referenced. In this case, if `xRechnung` is ommitted, the contact's buyer reference is used by default.
+     * If `xRechnung` is present, `buyerReference` is a mandatory field.
+     *
+     * The `buyerReference` (*Leitweg-ID*) stored in the referenced contact can be overwritten for a specific invoice by transmitting a different `buyerReference` during invoice creation.
+     * If a buyer reference is specified, but the linked contact has no buyer reference and vendor number at the customer, request attempts are rejected with 406.
+     *
+     * It is also possible to create a standard invoice for an XRechnung enabled contact. To do so, please set the *buyerReference* to an empty string.
+     */
+    export interface XRechnung {
+        /**
+         * The customer's *Leitweg-ID* for XRechnung enabled invoices
+         */
+        buyerReference?: string;
+    }
+}
+
+/**
+ * This endpoint provides read and write access to order confirmations and also the possibility to render the document as a PDF in order to download it. Order confirmations are always created in draft mode and do not need to be finalized.
+ *
+ * It is possible to create order confirmations with value-added tax such as of type net (*Netto*), gross (*Brutto*) or different types of vat-free. For tax-exempt organizations vat-free (*Steuerfrei*) order confirmations can be created exclusively. All other vat-free tax types are only usable in combination with a referenced contact in lexoffice. For recipients within the EU these are intra-community supply (*Innergemeinschaftliche Lieferung gem. §13b UStG*), constructional services (*Bauleistungen gem. §13b UStG*) and external services (*Fremdleistungen innerhalb der EU gem. §13b UStG*). For order confirmations to third countries, the tax types third party country service (*Dienstleistungen an Drittländer*) and third party country delivery (*Ausfuhrlieferungen an Drittländer*) are possible.
+ */
+export namespace order_confirmations {
+    export type TaxType =
+        | "constructionService13b"
+        | "externalService13b"
+        | "gross"
+        | "intraCommunitySupply"
+        | "net"
+        | "thirdPartyCountryDelivery"
+        | "thirdPartyCountryService"
+        | "vatfree";
+
+    export type Type =
+        | "custom"
+        | "material"
+        | "service"
+        | "text";
+
+    export type ShippingType =
+        | "delivery"
+        | "deliveryperiod"
+        | "none"
+        | "service"
+        | "serviceperiod";
+
+    export type TaxSubType =
+        | "distanceSales"
+        | "electronicServices";
+
+    export type VoucherStatus =
+        | "draft";
+
+    /**
+     * ```json
+     * {
+     *   "id": "e9066f04-8cc7-4616-93f8-ac9ecc8479c8",
+     *   "organizationId": "aa93e8a8-2aa3-470b-b914-caad8a255dd8",
+     *   "createdDate": "2017-04-24T08:20:22.528+02:00",
+     *   "updatedDate": "2017-04-24T08:20:22.528+02:00",
+     *   "version": 0,
+     *   "language": "de",
+     *   "archived": false,
+     *   "voucherStatus": "draft",
+     *   "voucherNumber": "AB1019",
+     *   "voucherDate": "2017-02-22T00:00:00.000+01:00",
+     *   "address": {
+     *     "contactId": null,
+     *     "name": "Bike & Ride GmbH & Co. KG",
+     *     "supplement": "Gebäude 10",
+     *     "street": "Musterstraße 42",
+     *     "city": "Freiburg",
+     *     "zip": "79112",
+     *     "countryCode": "DE"
+     *   },
+     *   "lineItems": [
+     *     {
+     *       "id": "97b98491-e953-4dc9-97a9-ae437a8052b4",
+     *       "type": "material",
+     *       "name": "Abus Kabelschloss Primo 590 ",
+     *       "description": "· 9,5 mm starkes, smoke-mattes Spiralkabel mit integrierter Halterlösung zur Befestigung am Sattelklemmbolzen · bewährter Qualitäts-Schließzylinder mit praktischem Wendeschlüssel · KabelØ: 9,5 mm, Länge: 150 cm",
+     *       "quantity": 2,
+     *       "unitName": "Stück",
+     *       "unitPrice": {
+     *         "currency": "EUR",
+     *         "netAmount": 13.4,
+     *         "grossAmount": 15.95,
+     *         "taxRatePercentage": 19
+     *       },
+     *       "discountPercentage": 50,
+     *       "lineItemAmount": 13.4
+     *     },
+     *     {
+     *       "id": "dc4c805b-7df1-4310-a548-22be4499eb04",
+     *       "type": "service",
+     *       "name": "Aufwändige Montage",
+     *       "description": "Aufwand für arbeitsintensive Montagetätigkeit",
+     *       "quantity": 1,
+     *       "unitName": "Stunde",
+     *       "unitPrice": {
+     *         "currency": "EUR",
+     *         "netAmount": 8.32,
+     *         "grossAmount": 8.9,
+     *         "taxRatePercentage": 7
+     *       },
+     *       "discountPercentage": 0,
+     *       "lineItemAmount": 8.32
+     *     },
+     *     {
+     *       "id": null,
+     *       "type": "custom",
+     *       "name": "Energieriegel Testpaket",
+     *       "description": null,
+     *       "quantity": 1,
+     *       "unitName": "Stück",
+     *       "unitPrice": {
+     *         "currency": "EUR",
+     *         "netAmount": 5,
+     *         "grossAmount": 5,
+     *         "taxRatePercentage": 0
+     *       },
+     *       "discountPercentage": 0,
+     *       "lineItemAmount": 5
+     *     },
+     *     {
+     *       "type": "text",
+     *       "name": "Freitextposition",
+     *       "description": "This item type can contain either a name or a description or both."
+     *     }
+     *   ],
+     *   "totalPrice": {
+     *     "currency": "EUR",
+     *     "totalNetAmount": 26.72,
+     *     "totalGrossAmount": 29.85,
+     *     "totalTaxAmount": 3.13,
+     *     "totalDiscountAbsolute": null,
+     *     "totalDiscountPercentage": null
+     *   },
+     *   "taxAmounts": [
+     *     {
+     *       "taxRatePercentage": 0,
+     *       "taxAmount": 0,
+     *       "netAmount": 5
+     *     },
+     *     {
+     *       "taxRatePercentage": 7,
+     *       "taxAmount": 0.58,
+     *       "netAmount": 8.32
+     *     },
+     *     {
+     *       "taxRatePercentage": 19,
+     *       "taxAmount": 2.55,
+     *       "netAmount": 13.4
+     *     }
+     *   ],
+     *   "taxConditions": {
+     *     "taxType": "net",
+     *     "taxTypeNote": null
+     *   },
+     *   "paymentConditions": {
+     *     "paymentTermLabel": "10 Tage - 3 %, 30 Tage netto",
+     *     "paymentTermLabelTemplate": "{discountRange} Tage -{discount}, {paymentRange} Tage netto",
+     *     "paymentTermDuration": 30,
+     *     "paymentDiscountConditions": {
+     *       "discountPercentage": 3,
+     *       "discountRange": 10
+     *     }
+     *   },
+     *   "shippingConditions": {
+     *     "shippingDate": "2017-04-22T00:00:00.000+02:00",
+     *     "shippingEndDate": null,
+     *     "shippingType": "delivery"
+     *   },
+     *   "relatedVouchers": [],
+     *   "title": "Auftragsbestätigung",
+     *   "introduction": "Ihre bestellten Positionen stellen wir Ihnen hiermit in Rechnung",
+     *   "remark": "Vielen Dank für Ihren Einkauf",
+     *   "deliveryTerms": "Lieferung an die angegebene Lieferadresse"
+     * }
+     *
+     * ```
+     */
+    export interface OrderConfirmation {
+        /**
+         * Unique id generated on creation by lexoffice.
+         * *Read-only.*
+         */
+        readonly id?: string | null;
+        /**
+         * Unique id of the organization the order confirmation belongs to.
+         * *Read-only.*
+         */
+        readonly organizationId?: string | null;
+        /**
+         * The instant of time when the order confirmation was created by lexoffice in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).
+         * *Read-only.*
+         */
+        readonly createdDate?: string | null;
+        /**
+         * The instant of time when the order confirmation was updated by lexoffice in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).
+         * *Read-only.*
+         */
+        readonly updatedDate?: string | null;
+        /**
+         * Version *(revision)* number which will be increased on each change to handle [optimistic locking](https://developers.lexoffice.io/docs/#optimistic-locking).
+         * *Read-only.*
+         */
+        version?: number;
+        /**
+         * Specifies the language of the order confirmation which affects the print document but also set translated default text modules when no values are send (e.g. for introduction). Values accepted in ISO 639-1 code. Possible values are German **de** (default) and English **en**.
+         */
+        language?: string;
+        /**
+         * Specifies if the order confirmation is only available in the archive in lexoffice.
+         * *Read-only.*
+         */
+        readonly archived?: boolean | null;
+        /**
+         * Specifies the status of the order confirmation. The only possible status is **draft** (is editable).
+         * *Read-only.*
+         */
+        readonly voucherStatus?: VoucherStatus | null;
+        /**
+         * The specific number an order confirmation is aware of. This consecutive number set is by lexoffice on creation.
+         * *Read-only.*
+         */
+        readonly voucherNumber?: string | null;
+        /**
+         * The date of order confirmation in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).
+         */
+        voucherDate?: string;
+        /**
+         * The address of the order confirmation recipient. For details see below.
+         */
+        address?: Address;
+        /**
+         * The items of the order confirmation. For details see below.
+         */
+        lineItems?: LineItems[];
+        /**
+         * The total price of the order confirmation. For details see below.
+         */
+        totalPrice?: TotalPrice;
+        /**
+         * The tax amounts for each tax rate. Please note: As done with every read-only element or object all submitted content (POST) will be ignored. For details see below.
+         * *Read-only.*
+         */
+        readonly taxAmounts?: TaxAmounts[] | null;
+        /**
+         * The tax conditions of the order confirmation. For details see below.
+         */
+        taxConditions?: TaxConditions;
+        /**
+         * The payment conditions of the order confirmation. The organization's (or contact-specific) default is used if no value was send. For details see below.
+         */
+        paymentConditions?: PaymentConditions;
+        /**
+         * The shipping conditions of the order confirmation. For details see below.
+         */
+        shippingConditions?: ShippingConditions;
+        /**
+         * The related vouchers of the invoice. *Read-only.*
+         */
+        readonly relatedVouchers?: RelatedVouchers[] | null;
+        /**
+         * (Optional) A title text. The organization's default is used if no value was sent.
+         */
+        title?: string;
+        /**
+         * (Optional) An introductory text / header. The organization's default is used if no value was send.
+         */
+        introduction?: string;
+        /**
+         * (Optional) A closing text note. The organization's default is used if no value was send.
+         */
+        remark?: string;
+        /**
+         * (Optional) Describes the terms for delivery. The organization's (or contact-specific) default is used if no value was send.
+         */
+        deliveryTerms?: string;
+        /**
+         * The document id for the PDF version of the order confirmation. For details see below.
+         * *Read-only.*
+         */
+        readonly files?: Files | null;
+    }
+
+    /**
+     * There are two main options to address the recipient of an order confirmation. First, using an existing lexoffice contact or second, creating a new address.
+     *
+     * For **referencing an existing contact** it is only necessary to provide the UUID of that contact. Usually the billing address is used (for delivery notes, the shipping address will be preferred). Additionally, the referenced address can also be modified for this specific order confirmation. This can be done by setting all required address fields and this deviated address will not be stored back to the lexoffice contacts.
+     *
+     * The referenced contact needs to have the role customer. For more information please refer to the [contacts endpoint](https://developers.lexoffice.io/docs/#contacts-endpoint).
+     *
+     * Otherwise, a **new address** for the order confirmation recipient can be created. That type of address is called a "one-time address". A one-time address will not create a new contact in lexoffice. For instance, this could be useful when it is not needed to create a contact in lexoffice for each new order confirmation.
+     *
+     * Please get in touch with us if you are not sure which option fits your use case best.
+     */
+    export interface Address {
+        /**
+         * If the order confirmation recipient is (optionally) registered as a contact in lexoffice, this field specifies the related id of the contact.
+         */
+        contactId?: string;
+        /**
+         * The name of the order confirmation recipient. To use an existing contact of an individual person, provide the name in the format {firstname} {lastname}.
+         */
+        name?: string;
+        /**
+         * (Optional) An address supplement.
+         */
+        supplement?: string;
+        /**
+         * The street (street and street number) of the address.
+         */
+        street?: string;
+        /**
+         * The city of the address.
+         */
+        city?: string;
+        /**
+         * The zip code of the address.
+         */
+        zip?: string;
+        /**
+         * The [ISO 3166 alpha2 country code](https://developers.lexoffice.io/docs/#faq-country-codes) of the address.
+         */
+        countryCode?: string;
+        /**
+         * The contact person selected while editing the voucher. The primary contact person will be used when creating vouchers via the API with a referenced `contactId`.
+         * *Read-only.*
+         */
+        readonly contactPerson?: string | null;
+    }
+
+    export interface LineItems {
+        /**
+         * The field specifies the related id of the product/service.
+         * *Read-only.*
+         */
+        readonly id?: string | null;
+        /**
+         * The type of the item. Possible values are **service** (the line item is related to a supply of services), **material** (the line item is related to a physical product), **custom** (an item without reference in lexoffice and has no id) or **text** (contains only a name and/or a description for informative purposes).
+         */
+        type?: Type;
+        /**
+         * The name of the item.
+         */
+        name?: string;
+        /**
+         * The description of the item.
+         */
+        description?: string;
+        /**
+         * The amount of the purchased item. The value can contain up to 4 decimals.
+         */
+        quantity?: number;
+        /**
+         * The unit name of the purchased item. If the provided unit name is not known in lexoffice it will be created on the fly.
+         */
+        unitName?: string;
+        /**
+         * The unit price of the purchased item. For details see below.
+         */
+        unitPrice?: UnitPrice;
+        /**
+         * The offered discount for the item. The value can contain up to 2 decimals.
+         */
+        discountPercentage?: number;
+        /**
+         * The total price of this line item. Depending by the selected *taxType* in *taxConditions*, the amount must be given either as net or gross. The value can contain up to 2 decimals.
+         * *Read-only.*
+         */
+        readonly lineItemAmount?: number | null;
+    }
+
+    export interface UnitPrice {
+        /**
+         * The currency of the price. Currently only **EUR** is supported.
+         */
+        currency?: string;
+        /**
+         * The net price of the unit price. The value can contain up to 4 decimals.
+         */
+        netAmount?: number;
+        /**
+         * The gross price of the unit price. The value can contain up to 4 decimals.
+         */
+        grossAmount?: number;
+        /**
+         * The tax rate of the unit price. See [the "Supported tax rates" FAQ](https://developers.lexoffice.io/docs/#faq-valid-tax-rates) for more information and a list of possible values.. For vat-free sales vouchers the tax rate percentage must be **0**.
+         */
+        taxRatePercentage?: number;
+    }
+
+    export interface TotalPrice {
+        /**
+         * The currency of the total price. Currently only **EUR** is supported.
+         */
+        currency?: string;
+        /**
+         * The total net price over all line items. The value can contain up to 2 decimals.
+         * *Read-only.*
+         */
+        readonly totalNetAmount?: number | null;
+        /**
+         * The total gross price over all line items. The value can contain up to 2 decimals.
+         * *Read-only.*
+         */
+        readonly totalGrossAmount?: number | null;
+        /**
+         * The total tax amount over all line items. The value can contain up to 2 decimals.
+         * *Read-only.*
+         */
+        readonly totalTaxAmount?: number | null;
+        /**
+         * (Optional) A total discount as absolute value. The value can contain up to 2 decimals.
+         */
+        totalDiscountAbsolute?: number;
+        /**
+         * (Optional) A total discount relative to the gross amount or net amount dependent on the given tax conditions. A contact-specific default will be set if available and no total discount was send. The value can contain up to 2 decimals.
+         */
+        totalDiscountPercentage?: number;
+    }
+
+    export interface TaxAmounts {
+        /**
+         * Tax rate as percentage value. See [the "Supported tax rates" FAQ](https://developers.lexoffice.io/docs/#faq-valid-tax-rates) for more information and a list of possible values..
+         */
+        taxRatePercentage?: number;
+        /**
+         * The total tax amount for this tax rate. The value can contain up to 2 decimals.
+         */
+        taxAmount?: number;
+        /**
+         * The total net amount for this tax rate. The value can contain up to 2 decimals.
+         */
+        netAmount?: number;
+    }
+
+    /**
+     * ```json
+     * "taxConditions": {
+     *     "taxType": "constructionService13b",
+     *     "taxTypeNote": "Steuerschuldnerschaft des Leistungsempfängers (Reverse Charge)"
+     * }
+     *
+     * ```
+     */
+    export interface TaxConditions {
+        /**
+         * The tax type for the order confirmation. Possible values are **net**, **gross**, **vatfree** (*Steuerfrei*), **intraCommunitySupply** (*Innergemeinschaftliche Lieferung gem. §13b UStG*), **constructionService13b** (*Bauleistungen gem. §13b UStG*), **externalService13b** (*Fremdleistungen innerhalb der EU gem. §13b UStG*), **thirdPartyCountryService** (*Dienstleistungen an Drittländer*), and **thirdPartyCountryDelivery** (*Ausfuhrlieferungen an Drittländer*)
+         */
+        taxType?: TaxType;
+        /**
+         * A tax subtype. Only required for dedicated cases. For vouchers referencing a B2C customer in the EU, and with a `taxType` of `net` or `gross`, the taxSubType may be set to **distanceSales**, or **electronicServices**. Passing a null value results in a standard voucher.
+         * If the organization's `distanceSalesPrinciple` ([profile endpoint](https://developers.lexoffice.io/docs/#profile-endpoint)) is set to `DESTINATION` *and* this attribute is set to **distanceSales** or **electronicServices**, the voucher needs to reference the destination country's tax rates.
+         */
+        taxSubType?: TaxSubType;
+        /**
+         * When *taxType* is set to a vat-free tax type then a note regarding the conditions can be set. When omitted lexoffice sets the organization's default.
+         */
+        taxTypeNote?: string;
+    }
+
+    /**
+     * The payment conditions are optional and the organization's or contact-specific defaults will be used if ommitted.
+     */
+    export interface PaymentConditions {
+        /**
+         * A textual note regarding the payment conditions.
+         */
+        paymentTermLabel?: string;
+        /**
+         * A textual note regarding the payment conditions. This label template may contain variables such as the discount range. These variables are enclosed in curly braces, e.g., *{discountRange}*.'
+         * *Read-only.*
+         */
+        readonly paymentTermLabelTemplate?: string | null;
+        /**
+         * The time left (in days) until the payment must be conducted.
+         */
+        paymentTermDuration?: number;
+        /**
+         * The payment discount conditions for the order confirmation.
+         */
+        paymentDiscountConditions?: PaymentDiscountConditions[];
+    }
+
+    export interface PaymentDiscountConditions {
+        /**
+         * The discount offered in return for payment within the **discountRange**. The value can contain up to 2 decimals.
+         */
+        discountPercentage?: number;
+        /**
+         * The time left (in days) the discount is valid.
+         */
+        discountRange?: number;
+    }
+
+    export interface ShippingConditions {
+        /**
+         * The instant of time when the purchased items have to be shipped. Value in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).
+         */
+        shippingDate?: string;
+        /**
+         * An end instant in order to specify a shipping period of time. Value in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*). Must not specify an instant before *shippingDate*.
+         */
+        shippingEndDate?: string;
+        /**
+         * The type of the shipping. Possible values are **service** (a service is supplied on *shippingDate*), **serviceperiod** (a service is supplied within the period [*shippingDate*,*shippingEndDate*] ), **delivery** (a product is delivered), **deliveryperiod** (a product is delivered within the period [*shippingDate*,*shippingEndDate*]) and **none** (no shipping date has to be provided)
+         */
+        shippingType?: ShippingType;
+    }
+
+    /**
+     * The *relatedVouchers* property documents all existing voucher relations for the current sales voucher. If no related vouchers exist, an empty list will be returned.
+     */
+    export interface RelatedVouchers {
+        /**
+         * The related sales voucher's unique id.
+         */
+        readonly id?: string | null;
+        /**
+         * The specific number of the related sales voucher.
+         * *Read-only.*
+         */
+        readonly voucherNumber?: string | null;
+        /**
+         * Voucher type of the related sales voucher.
+         */
+        voucherType?: string;
+    }
+
+    export interface Files {
+        /**
+         * The id of the order confirmation PDF. To download the order confirmation PDF file please use the files endpoint.
+         */
+        documentFileId?: string;
+    }
+}
+
+/**
+ * The payment conditions endpoint provides read access to the list of payment conditions configured in lexoffice.
+ */
+export namespace payment_conditions {
+    /**
+     * The payment conditions are optional and the organization's or contact-specific defaults will be used if ommitted.
+     */
+    export interface PaymentCondition {
+        /**
+         * The payment conditions' identifier
+         */
+        readonly id?: string | null;
+        /**
+         * True for one payment condition object that was selected by the user as her default, false for all others
+         */
+        organizationDefault?: boolean;
+        /**
+         * A textual note regarding the payment conditions. This label template may contain variables such as the discount range. These variables are enclosed in curly braces, e.g., *{discountRange}*.'
+         * *Read-only.*
+         */
+        readonly paymentTermLabelTemplate?: string | null;
+        /**
+         * The time left (in days) until the payment must be conducted.
+         */
+        paymentTermDuration?: number;
+        /**
+         * The payment discount conditions for the payment condition.
+         */
+        paymentDiscountConditions?: PaymentDiscountConditions;
+    }
+
+    export interface PaymentDiscountConditions {
+        /**
+         * The discount offered in return for payment within the **discountRange**. The value can contain up to 2 decimals.
+         */
+        discountPercentage?: number;
+        /**
+         * The time left (in days) the discount is valid.
+         */
+        discountRange?: number;
+    }
+}
+
+/**
+ * The payments endpoint provides read access to the payment status of (bookkeeping or sales) vouchers, including invoices and credit notes.
+ */
+export namespace payments {
+    export type PaymentStatus =
+        | "balanced"
+        | "openExpense"
+        | "openRevenue";
+
+    export type VoucherStatus =
+        | "open"
+        | "paid"
+        | "paidoff"
+        | "sepadebit"
+        | "transferred"
+        | "voided";
+
+    export type VoucherType =
+        | "creditnote"
+        | "downpaymentinvoice"
+        | "invoice"
+        | "purchasecreditnote"
+        | "purchaseinvoice"
+        | "salescreditnote"
+        | "salesinvoice";
+
+    export type PaymentItemType =
+        | "cashDiscount"
+        | "forexGain"
+        | "forexLoss"
+        | "irrecoverableReceivable"
+        | "manualPayment";
+
+    /**
+     * ```json
+     * {
+     *   "openAmount": 200.00,
+     *   "currency": "EUR",
+     *   "paymentStatus": "openRevenue",
+     *   "voucherType": "invoice",
+     *   "voucherStatus": "open"
+     * }
+     *
+     * {
+     *   "openAmount": 39.90,
+     *   "paymentStatus": "openExpense",
+     *   "currency": "EUR",
+     *   "voucherType": "purchaseinvoice",
+     *   "voucherStatus": "open"
+     * }
+     *
+     * {
+     *   "openAmount": 0,
+     *   "currency": "EUR",
+     *   "paymentStatus": "balanced",
+     *   "voucherType": "purchasecreditnote",
+     *   "voucherStatus": "paidoff"
+     * }
+     *
+     * ```
+     */
+    export interface Payment {
+        /**
+         * Open amount. Positive value both for revenues and expenses
+         */
+        openAmount?: number;
+        /**
+         * Always contains the value **EUR**, the only currently supported currency
+         */
+        currency?: string;
+        /**
+         * The payment status is one of the values *balanced*, *openRevenue*, or *openExpense*
+         */
+        paymentStatus?: PaymentStatus;
+        /**
+         * Contains the voucher type: *salesinvoice*, *salescreditnote*, *purchaseinvoice*, *purchasecreditnote*, *invoice*, *downpaymentinvoice*, *creditnote*
+         */
+        voucherType?: VoucherType;
+        /**
+         * Contains one of the following voucher states: *open*, *paid*, *paidoff*, *voided*, *transferred*, *sepadebit*
+         */
+        voucherStatus?: VoucherStatus;
+        /**
+         * The date of the last payment. Only present if the voucher is balanced.
+         */
+        paidDate?: string;
+        /**
+         * The payments posted on the voucher. For details see below.
+         */
+        paymentItems?: PaymentItems[];
+    }
+
+    export interface PaymentItems {
+        /**
+         * The type of the payment item. Possible values are **manualPayment**, **cashDiscount**, **forexGain**, **forexLoss** and **irrecoverableReceivable**
+         */
+        paymentItemType?: PaymentItemType;
+        /**
+         * The date the payment was posted
+         */
+        postingDate?: string;
+        /**
+         * The amount of the payment
+         */
+        amount?: number;
+        /**
+         * The currency of the payment
+         */
+        currency?: string;
+    }
+}
+
+/**
+ * This endpoint provides read access to the list of posting categories for the (bookkeeping) vouchers revenue or expense which are supported in lexoffice.
+ *
+ * Category ids with type *income* can be used for revenue vouchers such as *salesinvoice* and *salescreditnote* and posting categories with type *outgo* can be applied for expense vouchers with voucher types *purchaseinvoice* or *purchasecreditnote*.
+ */
+export namespace posting_categories {
+    export interface PostingCategory {
+        /**
+         * Unique id of the posting category.
+         */
+        readonly id?: string | null;
+        /**
+         * Name of the posting category.
+         */
+        name?: string;
+        /**
+         * Type of the posting category. Possible values are **income** for revenues and **outgo** for expenses.
+         */
+        type?: string;
+        /**
+         * Flags if a referenced contact is required for this posting category. Possible values are **true** and **false**.
+         */
+        contactRequired?: boolean;
+        /**
+         * Flags if items with different tax rate percentages (e.g. 7% and 19%) are allowed for this posting category. Possible values are **true** and **false**.
+         */
+        splitAllowed?: boolean;
+        /**
+         * Name of the top level posting category.
+         */
+        groupName?: string;
+    }
+}
+
+/**
+ * The profile endpoint provides read access to basic profile information such as company name, user id, name and email of the connected lexoffice account.
+ */
+export namespace profile {
+    export type DistanceSalesPrinciple =
+        | "DESTINATION"
+        | "ORIGIN";
+
+    export type TaxType =
+        | "gross"
+        | "net"
+        | "vatfree";
+
+    export interface Profile {
+        /**
+         * Unique id of your company.
+         */
+        organizationId?: string;
+        /**
+         * Name of your company registered at lexoffice.
+         */
+        companyName?: string;
+        /**
+         * Information about the established connection to lexoffice. For specification of object `created` please see below.
+         */
+        created?: Created;
+        /**
+         * The id of the current API connection.
+         */
+        connectionId?: string;
+        /**
+         * Configured tax type. Possible values are **net**, **gross**, and **vatfree**.
+         */
+        taxType?: TaxType;
+        /**
+         * The distance sales principle configured by the user, or undefined if not yet set. Possible values are **ORIGIN** and **DESTINATION**. See the in-app documentation (Section "Umsatzsteuer bei Privatpersonen im EU-Ausland" in [https://app.lexoffice.de/settings/#/general](https://app.lexoffice.de/settings/#/general)) for information about the implications of this setting.
+         */
+        distanceSalesPrinciple?: DistanceSalesPrinciple;
+        /**
+         * Reflects whether the organization is marked as a "small business" (*Kleinunternehmer* according to §19 UStG.)
+         */
+        smallBusiness?: boolean;
+    }
+
+    export interface Created {
+        /**
+         * Unique id of the user who established the connection to lexoffice.
+         */
+        userId?: string;
+        /**
+         * The user who established the connection to lexoffice.
+         */
+        userName?: string;
+        /**
+         * The user's email who established the connection to lexoffice.
+         */
+        userEmail?: string;
+        /**
+         * The date when the connection was established in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).
+         */
+        date?: string;
+    }
+}
+
+/**
+ * This endpoint provides read and write access to quotations and also the possibility to render the document as a PDF in order to download it. Quotations can be created as a draft or finalized in open mode.
+ *
+ * Please note that Public API connections that were established prior to the release of the quotations endpoint (see [Change Log](#change-log)) are not automatically granted the permission for quotations access. Re-generate a new Public API key to benefit from quotations access.
+ *
+ * It is possible to create quotations with value-added tax such as of type net (*Netto*), gross (*Brutto*) or different types of vat-free. For tax-exempt organizations vat-free (*Steuerfrei*) quotations can be created exclusively. All other vat-free tax types are only usable in combination with a referenced contact in lexoffice. For recipients within the EU these are intra-community supply (*Innergemeinschaftliche Lieferung gem. §13b UStG*), constructional services (*Bauleistungen gem. §13b UStG*) and external services (*Fremdleistungen innerhalb der EU gem. §13b UStG*). For quotations to third countries, the tax types third party country service (*Dienstleistungen an Drittländer*) and third party country delivery (*Ausfuhrlieferungen an Drittländer*) are possible.
+ */
+export namespace quotations {
+    export type VoucherStatus =
+        | "accepted"
+        | "draft"
+        | "open"
+        | "rejected";
+
+    export type TaxType =
+        | "constructionService13b"
+        | "externalService13b"
+        | "gross"
+        | "intraCommunitySupply"
+        | "net"
+        | "thirdPartyCountryDelivery"
+        | "thirdPartyCountryService"
+        | "vatfree";
+
+    export type Type =
+        | "custom"
+        | "material"
+        | "service"
+        | "text";
+
+    export type TaxSubType =
+        | "distanceSales"
+        | "electronicServices";
+
+    /**
+     * ```json
+     * {
+     *     "id": "424f784e-1f4e-439e-8f71-19673e6d6583",
+     *     "organizationId": "aa93e8a8-2aa3-470b-b914-caad8a255dd8",
+     *     "createdDate": "2019-12-16T12:43:16.689+01:00",
+     *     "updatedDate": "2019-12-16T15:26:30.074+01:00",
+     *     "version": 4,
+     *     "language": "de",
+     *     "archived": false,
+     *     "voucherStatus": "open",
+     *     "voucherNumber": "AG0006",
+     *     "voucherDate": "2019-12-16T12:43:03.900+01:00",
+     *     "expirationDate": "2020-01-15T12:43:03.900+01:00",
+     *     "address": {
+     *         "contactId": "97c5794f-8ab2-43ad-b459-c5980b055e4d",
+     *         "name": "Berliner Kindl GmbH",
+     *         "street": "Jubiläumsweg 25",
+     *         "city": "Berlin",
+     *         "zip": "14089",
+     *         "countryCode": "DE"
+     *     },
+     *     "lineItems": [
+     *         {
+     *             "id": "68569bfc-e5ae-472d-bbdf-6d51a82b1d2f",
+     *             "type": "material",
+     *             "name": "Axa Rahmenschloss Defender RL",
+     *             "description": "Vollständig symmetrisches Design in metallicfarbener Ausführung. Der ergonomische Bedienkopf garantiert die große Benutzerfreundlichkeit dieses Schlosses. Sehr niedrige Kopfhöhe von 46 mm, also mehr Rahmenfreiheit... ",
+     *             "quantity": 1,
+     *             "unitName": "Stück",
+     *             "unitPrice": {
+     *                 "currency": "EUR",
+     *                 "netAmount": 20.08,
+     *                 "grossAmount": 23.9,
+     *                 "taxRatePercentage": 19
+     *             },
+     *             "discountPercentage": 0,
+     *             "lineItemAmount": 23.90,
+     *             "subItems": [
+     *                 {
+     *                     "id": "97b98491-e953-4dc9-97a9-ae437a8052b4",
+     *                     "type": "material",
+     *                     "name": "Abus Kabelschloss Primo 590 ",
+     *                     "description": "· 9,5 mm starkes, smoke-mattes Spiralkabel mit integrierter Halterlösung zur Befestigung am Sattelklemmbolzen · bewährter Qualitäts-Schließzylinder mit praktischem Wendeschlüssel · KabelØ: 9,5 mm, Länge: 150 cm",
+     *                     "quantity": 1,
+     *                     "unitName": "Stück",
+     *                     "unitPrice": {
+     *                         "currency": "EUR",
+     *                         "netAmount": 13.4,
+     *                         "grossAmount": 15.95,
+     *                         "taxRatePercentage": 19
+     *                     },
+     *                     "discountPercentage": 0,
+     *                     "lineItemAmount": 15.95,
+     *                     "alternative": true,
+     *                     "optional": false
+     *                 }
+     *             ],
+     *             "alternative": false,
+     *             "optional": false
+     *         },
+     *         {
+     *             "id": "0722bcc6-d1b7-417b-b834-3b47794fa9ab",
+     *             "type": "service",
+     *             "name": "Einfache Montage",
+     *             "description": "Aufwand für einfache Montagetätigkeit",
+     *             "quantity": 1,
+     *             "unitName": "Stunde",
+     *             "unitPrice": {
+     *                 "currency": "EUR",
+     *                 "netAmount": 4.12,
+     *                 "grossAmount": 4.9,
+     *                 "taxRatePercentage": 19
+     *             },
+     *             "discountPercentage": 0,
+     *             "lineItemAmount": 4.90,
+     *             "alternative": false,
+     *             "optional": true
+     *         }
+     *     ],
+     *     "totalPrice": {
+     *         "currency": "EUR",
+     *         "totalNetAmount": 20.08,
+     *         "totalGrossAmount": 23.90,
+     *         "totalTaxAmount": 3.82
+     *     },
+     *     "taxAmounts": [
+     *         {
+     *             "taxRatePercentage": 19,
+     *             "taxAmount": 3.82,
+     *             "netAmount": 20.08
+     *         }
+     *     ],
+     *     "taxConditions": {
+     *         "taxType": "gross"
+     *     },
+     *     "paymentConditions": {
+     *         "paymentTermLabel": "10 Tage - 3 %, 30 Tage netto",
+     *         "paymentTermLabelTemplate": "{discountRange} Tage -{discount}, {paymentRange} Tage netto",
+     *         "paymentTermDuration": 30,
+     *         "paymentDiscountConditions": {
+     *             "discountPercentage": 3,
+     *             "discountRange": 10
+     *         }
+     *     },
+     *     "relatedVouchers": [],
+     *     "introduction": "Gerne bieten wir Ihnen an:",
+     *     "remark": "Wir freuen uns auf Ihre Auftragserteilung und sichern eine einwandfreie Ausführung zu.",
+     *     "files": {
+     *         "documentFileId": "ebd84e8a-716d-4a20-a76d-21de75a6d3d1"
+     *     },
+     *     "title": "Angebot"
+     * }
+     *
+     * ```
+     */
+    export interface Quotation {
+        /**
+         * Unique id generated on creation by lexoffice.
+         * *Read-only.*
+         */
+        readonly id?: string | null;
+        /**
+         * Unique id of the organization the quotation belongs to.
+         * *Read-only.*
+         */
+        readonly organizationId?: string | null;
+        /**
+         * The instant of time when the quotation was created by lexoffice in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).
+         * *Read-only.*
+         */
+        readonly createdDate?: string | null;
+        /**
+         * The instant of time when the quotation was updated by lexoffice in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).
+         * *Read-only.*
+         */
+        readonly updatedDate?: string | null;
+        /**
+         * The instant of time when the quotation will expire. Value in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).
+         */
+        expirationDate: string;
+        /**
+         * Version *(revision)* number which will be increased on each change to handle [optimistic locking](https://developers.lexoffice.io/docs/#optimistic-locking).
+         * *Read-only.*
+         */
+        version?: number;
+        /**
+         * Specifies the language of the quotation which affects the print document but also set translated default text modules when no values are send (e.g. for introduction). Values accepted in ISO 639-1 code. Possible values are German **de** (default) and English **en**.
+         */
+        language?: string;
+        /**
+         * Specifies if the quotation is only available in the archive in lexoffice.
+         * *Read-only.*
+         */
+        readonly archived?: boolean | null;
+        /**
+         * Specifies the status of the quotation. Possible values are **draft** (is editable), **open** (finalized and no longer editable but yet neither accepted nor rejected), **accepted** (has been accepted by the customer), **rejected** (rejected by the customer)
+         * *Read-only.*
+         */
+        readonly voucherStatus?: VoucherStatus | null;
+        /**
+         * The specific number a quotation is aware of. This consecutive number is set by lexoffice on creation.
+         * *Read-only.*
+         */
+        readonly voucherNumber?: string | null;
+        /**
+         * The date of quotation in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).
+         */
+        voucherDate: string;
+        /**
+         * The address of the quotation recipient. For details see below.
+         */
+        address: Address;
+        /**
+         * The items of the quotation. For details see below.
+         */
+        lineItems: LineItems[];
+        /**
+         * The total price of the quotation. For details see below.
+         */
+        totalPrice: TotalPrice;
+        /**
+         * The tax amounts for each tax rate. Please note: As done with every read-only element or object all submitted content (POST) will be ignored. For details see below.
+         * *Read-only.*
+         */
+        readonly taxAmounts?: TaxAmounts[] | null;
+        /**
+         * The tax conditions of the quotation. For details see below.
+         */
+        taxConditions: TaxConditions;
+        /**
+         * The payment conditions of the quotation. The organization's (or contact-specific) default is used if no value was send. For details see below.
+         */
+        paymentConditions?: PaymentConditions;
+        /**
+         * The related vouchers of the invoice. *Read-only.*
+         */
+        readonly relatedVouchers?: RelatedVouchers[] | null;
+        /**
+         * (Optional) A title text. The organization's default is used if no value was sent.
+         */
+        title?: string;
+        /**
+         * (Optional) An introductory text / header. The organization's default is used if no value was send.
+         */
+        introduction?: string;
+        /**
+         * (Optional) A closing text note. The organization's default is used if no value was send.
+         */
+        remark?: string;
+        /**
+         * The document id for the PDF version of the quotation. For details see below.
+         * *Read-only.*
+         */
+        readonly files?: Files | null;
+    }
+
+    /**
+     * There are two main options to address the recipient of a quotation. First, using an existing lexoffice contact or second, creating a new address.
+     *
+     * For **referencing an existing contact** it is only necessary to provide the UUID of that contact. Usually the billing address is used (for delivery notes, the shipping address will be preferred). Additionally, the referenced address can also be modified for this specific quotation. This can be done by setting all required address fields and this deviated address will not be stored back to the lexoffice contacts.
+     *
+     * The referenced contact needs to have the role customer. For more information please refer to the [contacts endpoint](https://developers.lexoffice.io/docs/#contacts-endpoint).
+     *
+     * Otherwise, a **new address** for the quotation recipient can be created. That type of address is called a "one-time address". A one-time address will not create a new contact in lexoffice. For instance, this could be useful when it is not needed to create a contact in lexoffice for each new quotation.
+     *
+     * Please get in touch with us if you are not sure which option fits your use case best.
+     */
+    export interface Address {
+        /**
+         * If the quotation recipient is (optionally) registered as a contact in lexoffice, this field specifies the related id of the contact.
+         */
+        contactId?: string;
+        /**
+         * The name of the quotation recipient. To use an existing contact of an individual person, provide the name in the format {firstname} {lastname}.
+         */
+        name?: string;
+        /**
+         * (Optional) An address supplement.
+         */
+        supplement?: string;
+        /**
+         * The street (street and street number) of the address.
+         */
+        street?: string;
+        /**
+         * The city of the address.
+         */
+        city?: string;
+        /**
+         * The zip code of the address.
+         */
+        zip?: string;
+        /**
+         * The [ISO 3166 alpha2 country code](https://developers.lexoffice.io/docs/#faq-country-codes) of the address.
+         */
+        countryCode?: string;
+        /**
+         * The contact person selected while editing the voucher. The primary contact person will be used when creating vouchers via the API with a referenced `contactId`.
+         * *Read-only.*
+         */
+        readonly contactPerson?: string | null;
+    }
+
+    export interface LineItems {
+        /**
+         * The field specifies the related id of the product/service.
+         * *Read-only.*
+         */
+        readonly id?: string | null;
+        /**
+         * The type of the item. Possible values are **service** (the line item is related to a supply of services), **material** (the line item is related to a physical product), **custom** (an item without reference in lexoffice and has no id) or **text** (contains only a name and/or a description for informative purposes).
+         */
+        type: Type;
+        /**
+         * The name of the item.
+         */
+        name: string;
+        /**
+         * The description of the item.
+         */
+        description?: string;
+        /**
+         * The amount of the purchased item. The value can contain up to 4 decimals.
+         */
+        quantity?: number;
+        /**
+         * The unit name of the purchased item. If the provided unit name is not known in lexoffice it will be created on the fly.
+         */
+        unitName?: string;
+        /**
+         * The unit price of the purchased item. For details see below.
+         */
+        unitPrice?: UnitPrice;
+        /**
+         * The offered discount for the item. The value can contain up to 2 decimals.
+         */
+        discountPercentage?: number;
+        /**
+         * The total price of this line item. Depending by the selected *taxType* in *taxConditions*, the amount must be given either as net or gross. The value can contain up to 2 decimals.
+         * *Read-only.*
+         */
+        readonly lineItemAmount?: number | null;
+        /**
+         * A list of subitems of this line item. At this time, all `subItems` need to be alternative items.
+         */
+        subItems?: LineItems[];
+        /**
+         * If true, the line item is optional ("Optionale Position"). Not a valid attribute for subitems. Defaults to false if unset
+         */
+        optional?: boolean;
+        /**
+         * If true, the line item is an alternative position for its parent item. Currently only valid for subitems, and mandatory to be true in that case. Defaults to false if unset
+         */
+        alternative?: boolean;
+    }
+
+    export interface UnitPrice {
+        /**
+         * The currency of the price. Currently only **EUR** is supported.
+         */
+        currency: string;
+        /**
+         * The net price of the unit price. The value can contain up to 4 decimals.
+         */
+        netAmount?: number;
+        /**
+         * The gross price of the unit price. The value can contain up to 4 decimals.
+         */
+        grossAmount?: number;
+        /**
+         * The tax rate of the unit price. See [the "Supported tax rates" FAQ](https://developers.lexoffice.io/docs/#faq-valid-tax-rates) for more information and a list of possible values.. For vat-free sales vouchers the tax rate percentage must be **0**.
+         */
+        taxRatePercentage: number;
+    }
+
+    export interface TotalPrice {
+        /**
+         * The currency of the total price. Currently only **EUR** is supported.
+         */
+        currency: string;
+        /**
+         * The total net price over all line items. The value can contain up to 2 decimals.
+         * *Read-only.*
+         */
+        readonly totalNetAmount?: number | null;
+        /**
+         * The total gross price over all line items. The value can contain up to 2 decimals.
+         * *Read-only.*
+         */
+        readonly totalGrossAmount?: number | null;
+        /**
+         * The total tax amount over all line items. The value can contain up to 2 decimals.
+         * *Read-only.*
+         */
+        readonly totalTaxAmount?: number | null;
+        /**
+         * (Optional) A total discount as absolute value. The value can contain up to 2 decimals.
+         */
+        totalDiscountAbsolute?: number;
+        /**
+         * (Optional) A total discount relative to the gross amount or net amount dependent on the given tax conditions. A contact-specific default will be set if available and no total discount was send. The value can contain up to 2 decimals.
+         */
+        totalDiscountPercentage?: number;
+    }
+
+    export interface TaxAmounts {
+        /**
+         * Tax rate as percentage value. See [the "Supported tax rates" FAQ](https://developers.lexoffice.io/docs/#faq-valid-tax-rates) for more information and a list of possible values..
+         */
+        taxRatePercentage?: number;
+        /**
+         * The total tax amount for this tax rate. The value can contain up to 2 decimals.
+         */
+        taxAmount?: number;
+        /**
+         * The total net amount for this tax rate. The value can contain up to 2 decimals.
+         */
+        netAmount?: number;
+    }
+
+    /**
+     * ```json
+     * "taxConditions": {
+     *     "taxType": "constructionService13b",
+     *     "taxTypeNote": "Steuerschuldnerschaft des Leistungsempfängers (Reverse Charge)"
+     * }
+     *
+     * ```
+     */
+    export interface TaxConditions {
+        /**
+         * The tax type for the quotation. Possible values are **net**, **gross**, **vatfree** (*Steuerfrei*), **intraCommunitySupply** (*Innergemeinschaftliche Lieferung gem. §13b UStG*), **constructionService13b** (*Bauleistungen gem. §13b UStG*), **externalService13b** (*Fremdleistungen innerhalb der EU gem. §13b UStG*), **thirdPartyCountryService** (*Dienstleistungen an Drittländer*), and **thirdPartyCountryDelivery** (*Ausfuhrlieferungen an Drittländer*)
+         */
+        taxType?: TaxType;
+        /**
+         * A tax subtype. Only required for dedicated cases. For vouchers referencing a B2C customer in the EU, and with a `taxType` of `net` or `gross`, the taxSubType may be set to **distanceSales**, or **electronicServices**. Passing a null value results in a standard voucher.
+         * If the organization's `distanceSalesPrinciple` ([profile endpoint](https://developers.lexoffice.io/docs/#profile-endpoint)) is set to `DESTINATION` *and* this attribute is set to **distanceSales** or **electronicServices**, the voucher needs to reference the destination country's tax rates.
+         */
+        taxSubType?: TaxSubType;
+        /**
+         * When *taxType* is set to a vat-free tax type then a note regarding the conditions can be set. When omitted lexoffice sets the organization's default.
+         */
+        taxTypeNote?: string;
+    }
+
+    /**
+     * The payment conditions are optional and the organization's or contact-specific defaults will be used if ommitted.
+     */
+    export interface PaymentConditions {
+        /**
+         * A textual note regarding the payment conditions.
+         */
+        paymentTermLabel?: string;
+        /**
+         * A textual note regarding the payment conditions. This label template may contain variables such as the discount range. These variables are enclosed in curly braces, e.g., *{discountRange}*.'
+         * *Read-only.*
+         */
+        readonly paymentTermLabelTemplate?: string | null;
+        /**
+         * The time left (in days) until the payment must be conducted.
+         */
+        paymentTermDuration?: number;
+        /**
+         * The payment discount conditions for the quotation.
+         */
+        paymentDiscountConditions?: PaymentDiscountConditions[];
+    }
+
+    export interface PaymentDiscountConditions {
+        /**
+         * The discount offered in return for payment within the **discountRange**. The value can contain up to 2 decimals.
+         */
+        discountPercentage?: number;
+        /**
+         * The time left (in days) the discount is valid.
+         */
+        discountRange?: number;
+    }
+
+    /**
+     * The *relatedVouchers* property documents all existing voucher relations for the current sales voucher. If no related vouchers exist, an empty list will be returned.
+     */
+    export interface RelatedVouchers {
+        /**
+         * The related sales voucher's unique id.
+         */
+        readonly id?: string | null;
+        /**
+         * The specific number of the related sales voucher.
+         * *Read-only.*
+         */
+        readonly voucherNumber?: string | null;
+        /**
+         * Voucher type of the related sales voucher.
+         */
+        voucherType?: string;
+    }
+
+    export interface Files {
+        /**
+         * The id of the quotation PDF. The PDF will be created when the quotation turns from **draft** into status **open**. To download the quotation PDF file please use the files endpoint.
+         */
+        documentFileId?: string;
+    }
+}
+
+/**
+ * This endpoint provides read-only access to the templates of recurring invoices, either individually or all as collection. Based on recurring invoice templates, lexoffice will create regular invoices in configured intervals. This operation is executed at night around 2am CET/CEST.
+ *
+ * Please note that it is not possible to query all deduced invoices for a given recurring template. However, when GETting an [invoice](#invoices-endpoint) that was deduced from a recurring template, it will include a reference to the respective recurring template. This allows gathering of information such as the next execution date or the execution status.
+ */
+export namespace recurring_templates {
+    export type ExecutionStatus =
+        | "ACTIVE"
+        | "ENDED"
+        | "PAUSED";
+
+    export type ExecutionInterval =
+        | "ANNUALLY"
+        | "BIANNUALLY"
+        | "BIWEEKLY"
+        | "MONTHLY"
+        | "QUARTERLY"
+        | "WEEKLY";
+
+    export type TaxType =
+        | "constructionService13b"
+        | "externalService13b"
+        | "gross"
+        | "intraCommunitySupply"
+        | "net"
+        | "thirdPartyCountryDelivery"
+        | "thirdPartyCountryService"
+        | "vatfree";
+
+    export type Type =
+        | "custom"
+        | "material"
+        | "service"
+        | "text";
+
+    export type ShippingType =
+        | "delivery"
+        | "deliveryperiod"
+        | "none"
+        | "service"
+        | "serviceperiod";
+
+    export type TaxSubType =
+        | "distanceSales"
+        | "electronicServices";
+
+    /**
+     * The set of properties of recurring templates are almost the same as of regular invoices, however, recurring templates
+     * do not have any date values set because these will only be derived when the recurring invoices are created.
+     * Additionally, the configuration of recurring invoices is defined in a nested object.
+     * Recurring templates always reference an existing contact.
+     *
+     * ```json
+     * {
+     *   "id": "ac1d66a8-6d59-408b-9413-d56b1db7946f",
+     *   "organizationId": "aa93e8a8-2aa3-470b-b914-caad8a255dd8",
+     *   "createdDate": "2021-02-10T09:00:00.000+01:00",
+     *   "updatedDate": "2021-02-10T09:00:00.000+01:00",
+     *   "version": 0,
+     *   "language": "de",
+     *   "archived": false,
+     *   "address": {
+     *     "contactId": "464f4881-7a8c-4dc4-87de-7c6fd9a506b8",
+     *     "name": "Bike & Ride GmbH & Co. KG",
+     *     "supplement": "Gebäude 10",
+     *     "street": "Musterstraße 42",
+     *     "city": "Freiburg",
+     *     "zip": "79112",
+     *     "countryCode": "DE"
+     *   },
+     *   "lineItems": [
+     *     {
+     *       "id": "97b98491-e953-4dc9-97a9-ae437a8052b4",
+     *       "type": "material",
+     *       "name": "Abus Kabelschloss Primo 590 ",
+     *       "description": "· 9,5 mm starkes, smoke-mattes Spiralkabel mit integrierter Halterlösung zur Befestigung am Sattelklemmbolzen · bewährter Qualitäts-Schließzylinder mit praktischem Wendeschlüssel · KabelØ: 9,5 mm, Länge: 150 cm",
+     *       "quantity": 2,
+     *       "unitName": "Stück",
+     *       "unitPrice": {
+     *         "currency": "EUR",
+     *         "netAmount": 13.4,
+     *         "grossAmount": 15.95,
+     *         "taxRatePercentage": 19
+     *       },
+     *       "discountPercentage": 50,
+     *       "lineItemAmount": 13.4
+     *     },
+     *     {
+     *       "id": "dc4c805b-7df1-4310-a548-22be4499eb04",
+     *       "type": "service",
+     *       "name": "Aufwändige Montage",
+     *       "description": "Aufwand für arbeitsintensive Montagetätigkeit",
+     *       "quantity": 1,
+     *       "unitName": "Stunde",
+     *       "unitPrice": {
+     *         "currency": "EUR",
+     *         "netAmount": 8.32,
+     *         "grossAmount": 8.9,
+     *         "taxRatePercentage": 7
+     *       },
+     *       "discountPercentage": 0,
+     *       "lineItemAmount": 8.32
+     *     },
+     *     {
+     *       "id": null,
+     *       "type": "custom",
+     *       "name": "Energieriegel Testpaket",
+     *       "description": null,
+     *       "quantity": 1,
+     *       "unitName": "Stück",
+     *       "unitPrice": {
+     *         "currency": "EUR",
+     *         "netAmount": 5,
+     *         "grossAmount": 5,
+     *         "taxRatePercentage": 0
+     *       },
+     *       "discountPercentage": 0,
+     *       "lineItemAmount": 5
+     *     },
+     *     {
+     *       "type": "text",
+     *       "name": "Freitextposition",
+     *       "description": "This item type can contain either a name or a description or both."
+     *     }
+     *   ],
+     *   "totalPrice": {
+     *     "currency": "EUR",
+     *     "totalNetAmount": 26.72,
+     *     "totalGrossAmount": 29.85,
+     *     "totalTaxAmount": 3.13,
+     *     "totalDiscountAbsolute": null,
+     *     "totalDiscountPercentage": null
+     *   },
+     *   "taxAmounts": [
+     *     {
+     *       "taxRatePercentage": 0,
+     *       "taxAmount": 0,
+     *       "netAmount": 5
+     *     },
+     *     {
+     *       "taxRatePercentage": 7,
+     *       "taxAmount": 0.58,
+     *       "netAmount": 8.32
+     *     },
+     *     {
+     *       "taxRatePercentage": 19,
+     *       "taxAmount": 2.55,
+     *       "netAmount": 13.4
+     *     }
+     *   ],
+     *   "taxConditions": {
+     *     "taxType": "net",
+     *     "taxTypeNote": null
+     *   },
+     *   "paymentConditions": {
+     *     "paymentTermLabel": "10 Tage - 3 %, 30 Tage netto",
+     *     "paymentTermLabelTemplate": "{discountRange} Tage -{discount}, {paymentRange} Tage netto",
+     *     "paymentTermDuration": 30,
+     *     "paymentDiscountConditions": {
+     *       "discountPercentage": 3,
+     *       "discountRange": 10
+     *     }
+     *   },
+     *   "title": "Rechnung",
+     *   "introduction": "Ihre bestellten Positionen stellen wir Ihnen hiermit in Rechnung",
+     *   "remark": "Vielen Dank für Ihren Einkauf",
+     *   "recurringTemplateSettings": {
+     *     "id": "9c5b8bde-7d36-49e8-af5c-4fbe7dc9fa01",
+     *     "startDate": "2021-03-01",
+     *     "endDate": "2021-06-30",
+     *     "finalize": true,
+     *     "shippingType": "service",
+     *     "executionInterval": "MONTHLY",
+     *     "nextExecutionDate": "2021-03-01",
+     *     "lastExecutionFailed": false,
+     *     "lastExecutionErrorMessage": null,
+     *     "executionStatus": "ACTIVE"
+     *   }
+     * }
+     *
+     * ```
+     *
+     * Compared to invoices, recurring templates do not have a voucherStatus, voucherNumber, voucherDate, dueDate, shippingConditions, and files, as these are only derived or calculated during invoice creation.
+     */
+    export interface RecurringTemplate {
+        /**
+         * Unique id generated on creation by lexoffice.
+         * *Read-only.*
+         */
+        readonly id?: string | null;
+        /**
+         * Unique id of the organization the recurring template belongs to.
+         * *Read-only.*
+         */
+        readonly organizationId?: string | null;
+        /**
+         * The instant of time when the invoice was created by lexoffice in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).
+         * *Read-only.*
+         */
+        readonly createdDate?: string | null;
+        /**
+         * The instant of time when the invoice was updated by lexoffice in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).
+         * *Read-only.*
+         */
+        readonly updatedDate?: string | null;
+        /**
+         * Version *(revision)* number which will be increased on each change to handle [optimistic locking](https://developers.lexoffice.io/docs/#optimistic-locking).
+         * *Read-only.*
+         */
+        version?: number;
+        /**
+         * Specifies the language of the invoice which affects the print document but also set translated default text modules when no values are send (e.g. for introduction). Values accepted in ISO 639-1 code. Possible values are German **de** (default) and English **en**.
+         */
+        language?: string;
+        /**
+         * The address of the invoice recipient. For details see below.
+         */
+        address?: Address;
+        /**
+         * The items of the invoice. For details see below.
+         */
+        lineItems?: LineItems[];
+        /**
+         * The total price of the invoice. For details see below.
+         */
+        totalPrice?: TotalPrice;
+        /**
+         * The tax amounts for each tax rate. Please note: As done with every read-only element or object all submitted content (POST) will be ignored. For details see below.
+         * *Read-only.*
+         */
+        readonly taxAmounts?: TaxAmounts[] | null;
+        /**
+         * The tax conditions of the invoice. For details see below.
+         */
+        taxConditions?: TaxConditions;
+        /**
+         * The payment conditions of the invoice. The organization's (or contact-specific) default is used if no value was send. For details see below.
+         */
+        paymentConditions?: PaymentConditions;
+        /**
+         * (Optional) A title text. The organization's default is used if no value was sent.
+         */
+        title?: string;
+        /**
+         * (Optional) An introductory text / header. The organization's default is used if no value was send.
+         */
+        introduction?: string;
+        /**
+         * (Optional) A closing text note. The organization's default is used if no value was send.
+         */
+        remark?: string;
+        /**
+         * The settings for creating recurring template.
+         * *Read-only.*
+         */
+        readonly recurringTemplateSettings?: RecurringTemplateSettings | null;
+    }
+
+    export interface Address {
+        /**
+         * If the recurring-template recipient is (optionally) registered as a contact in lexoffice, this field specifies the related id of the contact.
+         */
+        contactId?: string;
+        /**
+         * The name of the recurring-template recipient. To use an existing contact of an individual person, provide the name in the format {firstname} {lastname}.
+         */
+        name?: string;
+        /**
+         * (Optional) An address supplement.
+         */
+        supplement?: string;
+        /**
+         * The street (street and street number) of the address.
+         */
+        street?: string;
+        /**
+         * The city of the address.
+         */
+        city?: string;
+        /**
+         * The zip code of the address.
+         */
+        zip?: string;
+        /**
+         * The [ISO 3166 alpha2 country code](https://developers.lexoffice.io/docs/#faq-country-codes) of the address.
+         */
+        countryCode?: string;
+        /**
+         * The contact person selected while editing the voucher. The primary contact person will be used when creating vouchers via the API with a referenced `contactId`.
+         * *Read-only.*
+         */
+        readonly contactPerson?: string | null;
+    }
+
+    export interface LineItems {
+        /**
+         * The field specifies the related id of the product/service.
+         * *Read-only.*
+         */
+        readonly id?: string | null;
+        /**
+         * The type of the item. Possible values are **service** (the line item is related to a supply of services), **material** (the line item is related to a physical product), **custom** (an item without reference in lexoffice and has no id) or **text** (contains only a name and/or a description for informative purposes).
+         */
+        type?: Type;
+        /**
+         * The name of the item.
+         */
+        name?: string;
+        /**
+         * The description of the item.
+         */
+        description?: string;
+        /**
+         * The amount of the purchased item. The value can contain up to 4 decimals.
+         */
+        quantity?: number;
+        /**
+         * The unit name of the purchased item. If the provided unit name is not known in lexoffice it will be created on the fly.
+         */
+        unitName?: string;
+        /**
+         * The unit price of the purchased item. For details see below.
+         */
+        unitPrice?: UnitPrice;
+        /**
+         * The offered discount for the item. The value can contain up to 2 decimals.
+         */
+        discountPercentage?: number;
+        /**
+         * The total price of this line item. Depending by the selected *taxType* in *taxConditions*, the amount must be given either as net or gross. The value can contain up to 2 decimals.
+         * *Read-only.*
+         */
+        readonly lineItemAmount?: number | null;
+    }
+
+    export interface UnitPrice {
+        /**
+         * The currency of the price. Currently only **EUR** is supported.
+         */
+        currency?: string;
+        /**
+         * The net price of the unit price. The value can contain up to 4 decimals.
+         */
+        netAmount?: number;
+        /**
+         * The gross price of the unit price. The value can contain up to 4 decimals.
+         */
+        grossAmount?: number;
+        /**
+         * The tax rate of the unit price. See [the "Supported tax rates" FAQ](https://developers.lexoffice.io/docs/#faq-valid-tax-rates) for more information and a list of possible values.. For vat-free sales vouchers the tax rate percentage must be **0**.
+         */
+        taxRatePercentage?: number;
+    }
+
+    export interface TotalPrice {
+        /**
+         * The currency of the total price. Currently only **EUR** is supported.
+         */
+        currency?: string;
+        /**
+         * The total net price over all line items. The value can contain up to 2 decimals.
+         * *Read-only.*
+         */
+        readonly totalNetAmount?: number | null;
+        /**
+         * The total gross price over all line items. The value can contain up to 2 decimals.
+         * *Read-only.*
+         */
+        readonly totalGrossAmount?: number | null;
+        /**
+         * The total tax amount over all line items. The value can contain up to 2 decimals.
+         * *Read-only.*
+         */
+        readonly totalTaxAmount?: number | null;
+        /**
+         * (Optional) A total discount as absolute value. The value can contain up to 2 decimals.
+         */
+        totalDiscountAbsolute?: number;
+        /**
+         * (Optional) A total discount relative to the gross amount or net amount dependent on the given tax conditions. A contact-specific default will be set if available and no total discount was send. The value can contain up to 2 decimals.
+         */
+        totalDiscountPercentage?: number;
+    }
+
+    export interface TaxAmounts {
+        /**
+         * Tax rate as percentage value. See [the "Supported tax rates" FAQ](https://developers.lexoffice.io/docs/#faq-valid-tax-rates) for more information and a list of possible values..
+         */
+        taxRatePercentage?: number;
+        /**
+         * The total tax amount for this tax rate. The value can contain up to 2 decimals.
+         */
+        taxAmount?: number;
+        /**
+         * The total net amount for this tax rate. The value can contain up to 2 decimals.
+         */
+        netAmount?: number;
+    }
+
+    /**
+     * ```json
+     * "taxConditions": {
+     *     "taxType": "constructionService13b",
+     *     "taxTypeNote": "Steuerschuldnerschaft des Leistungsempfängers (Reverse Charge)"
+     * }
+     *
+     * ```
+     */
+    export interface TaxConditions {
+        /**
+         * The tax type for the recurring-template. Possible values are **net**, **gross**, **vatfree** (*Steuerfrei*), **intraCommunitySupply** (*Innergemeinschaftliche Lieferung gem. §13b UStG*), **constructionService13b** (*Bauleistungen gem. §13b UStG*), **externalService13b** (*Fremdleistungen innerhalb der EU gem. §13b UStG*), **thirdPartyCountryService** (*Dienstleistungen an Drittländer*), and **thirdPartyCountryDelivery** (*Ausfuhrlieferungen an Drittländer*)
+         */
+        taxType?: TaxType;
+        /**
+         * A tax subtype. Only required for dedicated cases. For vouchers referencing a B2C customer in the EU, and with a `taxType` of `net` or `gross`, the taxSubType may be set to **distanceSales**, or **electronicServices**. Passing a null value results in a standard voucher.
+         * If the organization's `distanceSalesPrinciple` ([profile endpoint](https://developers.lexoffice.io/docs/#profile-endpoint)) is set to `DESTINATION` *and* this attribute is set to **distanceSales** or **electronicServices**, the voucher needs to reference the destination country's tax rates.
+         */
+        taxSubType?: TaxSubType;
+        /**
+         * When *taxType* is set to a vat-free tax type then a note regarding the conditions can be set. When omitted lexoffice sets the organization's default.
+         */
+        taxTypeNote?: string;
+    }
+
+    /**
+     * The payment conditions are optional and the organization's or contact-specific defaults will be used if ommitted.
+     */
+    export interface PaymentConditions {
+        /**
+         * A textual note regarding the payment conditions.
+         */
+        paymentTermLabel?: string;
+        /**
+         * A textual note regarding the payment conditions. This label template may contain variables such as the discount range. These variables are enclosed in curly braces, e.g., *{discountRange}*.'
+         * *Read-only.*
+         */
+        readonly paymentTermLabelTemplate?: string | null;
+        /**
+         * The time left (in days) until the payment must be conducted.
+         */
+        paymentTermDuration?: number;
+        /**
+         * The payment discount conditions for the recurring-template.
+         */
+        paymentDiscountConditions?: PaymentDiscountConditions[];
+    }
+
+    export interface PaymentDiscountConditions {
+        /**
+         * The discount offered in return for payment within the **discountRange**. The value can contain up to 2 decimals.
+         */
+        discountPercentage?: number;
+        /**
+         * The time left (in days) the discount is valid.
+         */
+        discountRange?: number;
+    }
+
+    export interface RecurringTemplateSettings {
+        /**
+         * The id of the recurring template settings.
+         * *Read-only.*
+         */
+        readonly id?: string | null;
+        /**
+         * (Optional) The start date of the first recurring invoice in short iso date `yyyy-MM-dd`. If null, recurring template is **PAUSED**.
+         */
+        startDate?: string;
+        /**
+         * (Optional) The end date of the first recurring invoice in short iso date `yyyy-MM-dd`.
+         */
+        endDate?: string;
+        /**
+         * Specifies the status of the invoice. If false recurring invoices are created as **draft** (is editable), otherwise they are finalized as **open** (finalized and no longer editable but yet unpaid or only partially paid). In contrast to the invoice endpoint, finalized recurring invoices will immediately and automatically be sent to the customer via email.
+         */
+        finalize?: boolean;
+        /**
+         * The same shipping types defined in the shipping conditions of invoices. Can be either one of: **service**, **serviceperiod**, **delivery**, **deliveryperiod**, **none**. The shipping dates/date range will be calculated automatically during execution.
+         */
+        shippingType?: ShippingType;
+        /**
+         * The execution interval defined as **WEEKLY**, **BIWEEKLY**, **MONTHLY**, **QUARTERLY**, **BIANNUALLY**, **ANNUALLY**.
+         */
+        executionInterval?: ExecutionInterval;
+        /**
+         * The date of the last execution in short iso date `yyyy-MM-dd`.
+         * *Read-only.*
+         */
+        readonly lastExecutionDate?: string | null;
+        /**
+         * The date of the next scheduled execution in short iso date `yyyy-MM-dd`.
+         * *Read-only.*
+         */
+        readonly nextExecutionDate?: string | null;
+        /**
+         * Whether the last execution of the recurring template was successful or not.
+         * *Read-only.*
+         */
+        readonly lastExecutionFailed?: boolean | null;
+        /**
+         * Describes the problem briefly when the last execution has failed.
+         * *Read-only.*
+         */
+        readonly lastExecutionErrorMessage?: string | null;
+        /**
+         * The status of the recurring template defined as **ACTIVE**, **PAUSED**, **ENDED**. Note, that there is no error state.
+         * *Read-only.*
+         */
+        readonly executionStatus?: ExecutionStatus | null;
+    }
+}
+
+/**
+ * The voucherlist endpoint provides read access to meta data of (bookkeeping) [vouchers](#vouchers-endpoint) (e.g. salesinvoices, salescreditnotes), [invoices](#invoices-endpoint) (including [down payment invoices](#down-payment-invoices-endpoint)), [credit notes](#credit-notes-endpoint), [order confirmations](#order-confirmations-endpoint), [quotations](#quotations-endpoint), and [delivery notes](#delivery-notes-endpoint). Details concerning items from the list are accessible by id using the respective endpoint. For more information on the different voucher types refer to the documentation on the respective endpoints. The voucherlist can be searched using various filters to get only the data you need.
+ */
+export namespace voucherlist {
+    export type VoucherStatus =
+        | "accepted"
+        | "draft"
+        | "open"
+        | "overdue"
+        | "paid"
+        | "paidoff"
+        | "rejected"
+        | "sepadebit"
+        | "transferred"
+        | "voided";
+
+    export type VoucherType =
+        | "creditnote"
+        | "deliverynote"
+        | "downpaymentinvoice"
+        | "invoice"
+        | "orderconfirmation"
+        | "purchasecreditnote"
+        | "purchaseinvoice"
+        | "quotation"
+        | "salescreditnote"
+        | "salesinvoice";
+
+    /**
+     * This section describes the properties of the meta data object for vouchers returned by this endpoint.
+     */
+    export interface Voucherlist {
+        /**
+         * Unique id of the voucher in lexoffice.
+         */
+        readonly id?: string | null;
+        /**
+         * Type of the voucher. Possible values are **salesinvoice**, **salescreditnote**, **purchaseinvoice**, **purchasecreditnote**, **invoice**, **downpaymentinvoice**, **creditnote**, **orderconfirmation**, **quotation**, and **deliverynote**.
+         */
+        voucherType?: VoucherType;
+        /**
+         * Showing the current workflow status of the voucher in lexoffice. Possible values are **draft**, **open**, **paid**, **paidoff**, **voided**, **transferred**, **sepadebit**, **overdue**, **accepted**, and **rejected**.
+         */
+        voucherStatus?: VoucherStatus;
+        /**
+         * Identification/Reference number of the voucher.
+         */
+        voucherNumber?: string;
+        /**
+         * Date when the voucher was issued. Value in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).
+         */
+        voucherDate?: string;
+        /**
+         * Date when the voucher was created in lexoffice. Value in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).
+         */
+        createdDate?: string;
+        /**
+         * Date when the voucher was last changed (or status changed) in lexoffice. Value in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).
+         */
+        updatedDate?: string;
+        /**
+         * Date when the voucher's payment has to be settled. Value in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).
+         */
+        dueDate?: string;
+        /**
+         * The id of an existing contact in lexoffice which is the recipient or invoicing party. Will be null for the [Collective Contact](https://developers.lexoffice.io/docs/#faq).
+         */
+        contactId?: string;
+        /**
+         * Name of the recipient or invoicing party.
+         */
+        contactName?: string;
+        /**
+         * Total amount of the voucher (may include taxes). Format is **##.00** *(119.00)*.
+         */
+        totalAmount?: number;
+        /**
+         * Open amount of the voucher. May be null (e.g., for invoices in draft, or various non-invoice vouchers). Format is **##.00** *(123.00)*.
+         */
+        openAmount?: number;
+        /**
+         * Currency of the voucher. Only possible value is **EUR**.
+         */
+        currency?: string;
+        /**
+         * Indicates if the voucher is marked as archived in lexoffice.
+         */
+        archived?: boolean;
+    }
+}
+
+/**
+ * The voucher endpoint provides read/write access to (bookkeeping) sales vouchers (e.g. invoices, creditnotes). For the lexoffice API, write access is currently restricted to sales vouchers, while purchase vouchers can be read.
+ *
+ * In contrast to usual invoices which contain item positions, stocks, etc., bookkeeping vouchers are more about bookkeeping and thus this only have positions grouped by tax rate. Optionally, a file (pdf or image) can be added to the voucher as a *receipt* which was created by an external system.
+ *
+ * A higher level description of the handling of vouchers via the lexoffice API can be found in the [bookkeeping cookbook](../cookbooks/bookkeeping/) (German only).
+ */
+export namespace vouchers {
+    export type TaxType =
+        | "gross"
+        | "net";
+
+    export type VoucherStatus =
+        | "open"
+        | "paid"
+        | "paidoff"
+        | "sepadebit"
+        | "transferred"
+        | "voided";
+
+    export type Type =
+        | "purchasecreditnote"
+        | "purchaseinvoice"
+        | "salescreditnote"
+        | "salesinvoice";
+
+    /**
+     * ```json
+     * {
+     *     "id": "a8691b5d-2393-4317-888d-bcd5d564f7d1",
+     *     "organizationId": "aa93e8a8-2aa3-470b-b914-caad8a255dd8",
+     *     "type": "salesinvoice",
+     *     "voucherStatus": "open",
+     *     "voucherNumber": "2016-000321",
+     *     "voucherDate": "2016-06-30T00:00:00.000+02:00",
+     *     "shippingDate": "2016-07-02T00:00:00.000+02:00",
+     *     "dueDate": "2016-07-07T00:00:00.000+02:00",
+     *     "totalGrossAmount": 326.00,
+     *     "totalTaxAmount": 26.00,
+     *     "taxType": "gross",
+     *     "useCollectiveContact": true,
+     *     "remark": "Bestellung von Max Mustermann.",
+     *     "voucherItems": [
+     *         {
+     *             "amount": 119.00,
+     *             "taxAmount": 19.00,
+     *             "taxRatePercent": 19.00,
+     *             "categoryId": "8f8664a8-fd86-11e1-a21f-0800200c9a66"
+     *         },
+     *         {
+     *             "amount": 107.00,
+     *             "taxAmount": 7.00,
+     *             "taxRatePercent": 7.00,
+     *             "categoryId": "8f8664a8-fd86-11e1-a21f-0800200c9a66"
+     *         },
+     *         {
+     *             "amount": 100.00,
+     *             "taxAmount": 0,
+     *             "taxRatePercent": 0.00,
+     *             "categoryId": "8f8664a8-fd86-11e1-a21f-0800200c9a66"
+     *         }
+     *     ],
+     *     "files": [],
+     *     "createdDate": "2016-06-30T13:28:51.012+02:00",
+     *     "updatedDate": "2016-06-30T13:28:51.012+02:00",
+     *     "version": 2
+     * }
+     *
+     * ```
+     */
+    export interface Voucher {
+        /**
+         * Unique id of the voucher generated on creation by lexoffice.
+         */
+        readonly id?: string | null;
+        /**
+         * Unique id of the organization the voucher was generated on.
+         */
+        organizationId?: string;
+        /**
+         * Type of the voucher. Possible values are **salesinvoice** (e.g. for sales orders), **salescreditnote** (e.g. for refunds or returned sales orders), **purchaseinvoice** and **purchasecreditnote**. Note that the same categoryId can be used for salescreditnotes and for salesinvoices.
+         */
+        type: Type;
+        /**
+         * Billing state of the voucher. Possible values are **open**, **paid**, **paidoff**, **voided**, **transferred** and **sepadebit**.
+         * *Read-only*.
+         */
+        readonly voucherStatus?: VoucherStatus | null;
+        /**
+         * Number of the voucher. Should be the order's identification/reference number.
+         */
+        voucherNumber: string;
+        /**
+         * Date when the voucher was issued. Format must be `yyyy-MM-dd` (e.g. *2016-06-28*).
+         */
+        voucherDate: string;
+        /**
+         * Date when the purchased item/service has to be shipped/supplied. If it is a period of time, the end date must be given. Format must be `yyyy-MM-dd` (e.g. *2016-07-02*). Please note: ShippingDate can only be specified for voucher types **salesinvoice** and **salescreditnote**.
+         */
+        shippingDate?: string;
+        /**
+         * Date when the voucher's payment has to be settled. Format must be `yyyy-MM-dd` (e.g. *2016-06-28*).
+         */
+        dueDate?: string;
+        /**
+         * Total gross amount of the voucher. Must match the sum of all positions with added/calculated tax amounts. Format must be **##.00** *(119.00)*.
+         */
+        totalGrossAmount: number;
+        /**
+         * Total tax amount of the voucher. Must match the sum of all positions' tax amounts. Format must be **##.00** *(19.00)*.
+         */
+        totalTaxAmount: number;
+        /**
+         * Tax type of the order. Possible values are **net** (positions amount will be provided net and taxes have to be calculated on top), **gross** (positions amount will be provided gross and tax is already included).
+         */
+        taxType: TaxType;
+        /**
+         * Set to true if the [Collective Contact](https://developers.lexoffice.io/docs/#faq) (customer/vendor) within lexoffice should be used. If used, the optional contactId will be ignored.
+         */
+        useCollectiveContact?: boolean;
+        /**
+         * If not using the collective contact option, an existing contact id must be provided. This must exist within lexoffice before and can be created via the [Contacts](https://developers.lexoffice.io/docs/#contacts-endpoint) endpoint. If a contact is assigned to a voucher, its role must either be **Customer**, or both **Customer** and **Vendor**.
+         */
+        contactId?: string;
+        /**
+         * Any comments or remarks to the order. This field is part of the full text search of lexoffice, any information for finding the voucher can be placed here as convenience of the lexoffice user.
+         */
+        remark?: string;
+        /**
+         * Positions of the voucher grouped by tax rate. Specification of object voucherItems please see below.
+         */
+        voucherItems: VoucherItems[];
+        /**
+         * A list of voucher image file uuids. Voucher images can be uploaded and assigned to an existing voucher via the [Upload a File to a Voucher](https://developers.lexoffice.io/docs/#Upload-a-File-to-a-Voucher) sub-resource endpoint. **Please note: Each file (voucher image) can only be assigned once.**
+         */
+        files?: string[];
+        /**
+         * The instant of time when the voucher was created by lexoffice in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).
+         * *Read-only.*
+         */
+        readonly createdDate?: string | null;
+        /**
+         * The instant of time when the voucher was updated by lexoffice in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).
+         * *Read-only.*
+         */
+        readonly updatedDate?: string | null;
+        /**
+         * Version *(revision)* number which will be increased on each change to handle [optimistic locking](https://developers.lexoffice.io/docs/#optimistic-locking). Set to **0 for initial POST**, for **PUT get latest version from lexoffice** *(via GET)* and merge with your changes. **Please note: If the version did not match the version stored in your system, the user must be informed about losing changes from lexoffice.**
+         */
+        version?: number;
+    }
+
+    export interface VoucherItems {
+        /**
+         * Amount of the position. Net or gross amount, according to the voucher's taxType. Format must be **##.00** *(119.00)*.
+         */
+        amount: number;
+        /**
+         * Tax amount of the voucher's item. Format must be **##.00** *(19.00)*.
+         */
+        taxAmount: number;
+        /**
+         * Tax rate as percentage value. See [the "Supported tax rates" FAQ](https://developers.lexoffice.io/docs/#faq-valid-tax-rates) for more information and a list of possible values. (*e.g. 19*).
+         */
+        taxRatePercent: number;
+        /**
+         * Booking category for this voucher's revenue or expenditure. Supported and appropriate categoryId's can be found [here](https://developers.lexoffice.io/docs/#vouchers-endpoint-list-of-categoryids).
+         */
+        categoryId: string;
+    }
+}
+"##;
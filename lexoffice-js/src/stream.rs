@@ -0,0 +1,51 @@
+use crate::to_js_err;
+use futures::lock::Mutex;
+use futures::stream::{Stream, StreamExt};
+use gloo_utils::format::JsValueSerdeExt;
+use js_sys::{Object, Promise, Reflect};
+use serde::Serialize;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::future_to_promise;
+
+#[wasm_bindgen(inline_js = "export function asyncIterator(next) {
+    return { next, [Symbol.asyncIterator]() { return this; } };
+}")]
+extern "C" {
+    #[wasm_bindgen(js_name = asyncIterator)]
+    fn async_iterator(next: JsValue) -> JsValue;
+}
+
+fn iterator_result(value: Option<JsValue>) -> JsValue {
+    let result = Object::new();
+    let done = JsValue::from_bool(value.is_none());
+    let value = value.unwrap_or(JsValue::UNDEFINED);
+    // Setting properties of a new object can't fail
+    Reflect::set(&result, &"done".into(), &done).unwrap_throw();
+    Reflect::set(&result, &"value".into(), &value).unwrap_throw();
+    result.into()
+}
+
+/// Wraps a `PageStream` into an object that implements the async iterator
+/// protocol of JavaScript. `I` is the typed iterator that is exposed to
+/// TypeScript.
+pub(crate) fn page_iterator<I, S, T>(stream: S) -> I
+where
+    I: JsCast,
+    S: Stream<Item = lexoffice::Result<T>> + 'static,
+    T: Serialize,
+{
+    let stream = stream
+        .map(|item| to_js_err(JsValue::from_serde(&to_js_err(item)?)))
+        .boxed_local();
+    let stream = Rc::new(Mutex::new(stream));
+    let next = Closure::wrap(Box::new(move || {
+        let stream = stream.clone();
+        future_to_promise(async move {
+            let item = stream.lock().await.next().await;
+            Ok(iterator_result(item.transpose()?))
+        })
+    }) as Box<dyn FnMut() -> Promise>);
+    async_iterator(next.into_js_value()).unchecked_into()
+}
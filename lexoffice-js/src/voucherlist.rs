@@ -0,0 +1,253 @@
+use crate::error::{parse_error, usage_error};
+use crate::stream::page_iterator;
+use crate::Client;
+use crate::{to_js_err, to_promise};
+use js_sys::Promise;
+use lexoffice::model::voucherlist::{VoucherStatus, VoucherType};
+use lexoffice::model::Voucherlist;
+use lexoffice::request::{
+    Request, RequestWithState, VoucherlistState, VoucherlistStateFinished,
+};
+use lexoffice::types::Date;
+use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "Promise<voucherlist.Voucherlist>")]
+    pub type VoucherlistGetPromise;
+
+    #[wasm_bindgen(typescript_type = "Promise<Page<voucherlist.Voucherlist>>")]
+    pub type VoucherlistPagePromise;
+
+    /// An async iterator over all entries of the voucherlist
+    #[wasm_bindgen(
+        typescript_type = "AsyncIterableIterator<voucherlist.Voucherlist>"
+    )]
+    pub type VoucherlistStream;
+}
+
+// JavaScript has no typestate, so the states of the rust request are tracked
+// at runtime instead.
+enum State {
+    Unstarted(Request<Voucherlist>),
+    Type(RequestWithState<Voucherlist, VoucherlistState<VoucherType, ()>>),
+    Status(RequestWithState<Voucherlist, VoucherlistState<(), VoucherStatus>>),
+    Finished(RequestWithState<Voucherlist, VoucherlistStateFinished>),
+}
+
+macro_rules! filter {
+    ( $self:ident, $x:ident => $e:expr ) => {{
+        let state =
+            match $self.state {
+                State::Unstarted(_) => return Err(usage_error(
+                    "voucherType or voucherStatus must be set before filtering",
+                )),
+                State::Type($x) => State::Finished($e),
+                State::Status($x) => State::Status($e),
+                State::Finished($x) => State::Finished($e),
+            };
+        Ok(Self { state })
+    }};
+}
+
+// Usage errors of requests that return a promise reject it instead of
+// throwing.
+fn rejected<P: JsCast>(err: JsValue) -> P {
+    Promise::reject(&err).unchecked_into()
+}
+
+fn parse_date(date: &str) -> Result<Date, JsValue> {
+    Date::parse_from_str(date, "%Y-%m-%d").map_err(parse_error)
+}
+
+#[wasm_bindgen]
+pub struct VoucherlistRequest {
+    state: State,
+}
+
+#[wasm_bindgen]
+impl Client {
+    pub fn voucherlist(&self) -> VoucherlistRequest {
+        VoucherlistRequest {
+            state: State::Unstarted(self.client.request::<Voucherlist>()),
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl VoucherlistRequest {
+    /// Sets the voucher type for this request. Either this or `status()` must
+    /// be called before filtering and setting it twice is an error.
+    #[wasm_bindgen(js_name = type)]
+    pub fn type_(
+        self,
+        voucher_type: &str,
+    ) -> Result<VoucherlistRequest, JsValue> {
+        let voucher_type =
+            VoucherType::from_str(voucher_type).map_err(parse_error)?;
+        let state = match self.state {
+            State::Unstarted(x) => State::Type(x.type_(&voucher_type)),
+            State::Status(x) => State::Finished(x.type_(&voucher_type)),
+            _ => return Err(usage_error("voucherType is already set")),
+        };
+        Ok(Self { state })
+    }
+
+    /// Sets the voucher status for this request. Either this or `type()` must
+    /// be called before filtering and setting it twice is an error.
+    pub fn status(
+        self,
+        voucher_status: &str,
+    ) -> Result<VoucherlistRequest, JsValue> {
+        let voucher_status =
+            VoucherStatus::from_str(voucher_status).map_err(parse_error)?;
+        let state = match self.state {
+            State::Unstarted(x) => State::Status(x.status(&voucher_status)),
+            State::Type(x) => State::Finished(x.status(&voucher_status)),
+            _ => return Err(usage_error("voucherStatus is already set")),
+        };
+        Ok(Self { state })
+    }
+
+    #[wasm_bindgen(js_name = voucherDateFrom)]
+    pub fn voucher_date_from(
+        self,
+        date: &str,
+    ) -> Result<VoucherlistRequest, JsValue> {
+        let date = parse_date(date)?;
+        filter!(self, x => x.voucher_date_from(&date))
+    }
+
+    #[wasm_bindgen(js_name = voucherDateTo)]
+    pub fn voucher_date_to(
+        self,
+        date: &str,
+    ) -> Result<VoucherlistRequest, JsValue> {
+        let date = parse_date(date)?;
+        filter!(self, x => x.voucher_date_to(&date))
+    }
+
+    #[wasm_bindgen(js_name = createdDateFrom)]
+    pub fn created_date_from(
+        self,
+        date: &str,
+    ) -> Result<VoucherlistRequest, JsValue> {
+        let date = parse_date(date)?;
+        filter!(self, x => x.created_date_from(&date))
+    }
+
+    #[wasm_bindgen(js_name = createdDateTo)]
+    pub fn created_date_to(
+        self,
+        date: &str,
+    ) -> Result<VoucherlistRequest, JsValue> {
+        let date = parse_date(date)?;
+        filter!(self, x => x.created_date_to(&date))
+    }
+
+    #[wasm_bindgen(js_name = updatedDateFrom)]
+    pub fn updated_date_from(
+        self,
+        date: &str,
+    ) -> Result<VoucherlistRequest, JsValue> {
+        let date = parse_date(date)?;
+        filter!(self, x => x.updated_date_from(&date))
+    }
+
+    #[wasm_bindgen(js_name = updatedDateTo)]
+    pub fn updated_date_to(
+        self,
+        date: &str,
+    ) -> Result<VoucherlistRequest, JsValue> {
+        let date = parse_date(date)?;
+        filter!(self, x => x.updated_date_to(&date))
+    }
+
+    #[wasm_bindgen(js_name = voucherNumber)]
+    pub fn voucher_number(
+        self,
+        voucher_number: &str,
+    ) -> Result<VoucherlistRequest, JsValue> {
+        filter!(self, x => x.voucher_number(voucher_number))
+    }
+
+    #[wasm_bindgen(js_name = contactId)]
+    pub fn contact_id(
+        self,
+        contact_id: &str,
+    ) -> Result<VoucherlistRequest, JsValue> {
+        let contact_id = to_js_err(uuid::Uuid::from_str(contact_id))?;
+        filter!(self, x => x.contact_id(&contact_id))
+    }
+
+    pub fn archived(
+        self,
+        archived: bool,
+    ) -> Result<VoucherlistRequest, JsValue> {
+        filter!(self, x => x.archived(archived))
+    }
+
+    /// Requests a single voucherlist entry. Only available before any filter
+    /// is set.
+    pub fn get(self, id: String) -> VoucherlistGetPromise {
+        let request = match self.state {
+            State::Unstarted(x) => x,
+            _ => {
+                return rejected(usage_error(
+                    "get() cannot be combined with filters",
+                ))
+            }
+        };
+        to_promise(async move { request.by_id_str(&id).await })
+    }
+
+    /// Requests a page of the voucherlist. Both `type()` and `status()` must
+    /// be set.
+    pub fn page(
+        self,
+        page: usize,
+        size: Option<usize>,
+    ) -> VoucherlistPagePromise {
+        let request = match self.state {
+            State::Finished(x) => x,
+            _ => {
+                return rejected(usage_error(
+                    "voucherType and voucherStatus must be set",
+                ))
+            }
+        };
+        to_promise(async move {
+            if let Some(size) = size {
+                request.page_size(page, size).await
+            } else {
+                request.page(page).await
+            }
+        })
+    }
+
+    /// Iterates over all pages of the voucherlist. Both `type()` and
+    /// `status()` must be set.
+    pub fn stream(
+        self,
+        page_size: Option<usize>,
+        prefetch: Option<usize>,
+    ) -> Result<VoucherlistStream, JsValue> {
+        let mut stream = match self.state {
+            State::Finished(x) => x.stream(),
+            _ => {
+                return Err(usage_error(
+                    "voucherType and voucherStatus must be set",
+                ))
+            }
+        };
+        if let Some(page_size) = page_size {
+            stream = stream.page_size(page_size);
+        }
+        if let Some(prefetch) = prefetch {
+            stream = stream.prefetch(prefetch);
+        }
+        Ok(page_iterator(stream))
+    }
+}
@@ -3,11 +3,156 @@
 #![cfg(target_arch = "wasm32")]
 
 extern crate wasm_bindgen_test;
+use js_sys::{Array, Promise, Reflect, JSON};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
 use wasm_bindgen_test::*;
 
 wasm_bindgen_test_configure!(run_in_browser);
 
+#[wasm_bindgen(inline_js = "export function mockFetch(responses) {
+    const requests = [];
+    globalThis.fetch = async (input, init) => {
+        const request = new Request(input, init);
+        requests.push({
+            method: request.method,
+            url: request.url,
+            authorization: request.headers.get('authorization'),
+            body: request.method === 'GET' ? null : await request.text(),
+        });
+        const [status, body] = responses.shift();
+        return new Response(JSON.stringify(body), {
+            status,
+            headers: { 'content-type': 'application/json' },
+        });
+    };
+    return requests;
+}")]
+extern "C" {
+    /// Replaces `fetch` with a function that answers with `responses`, a
+    /// list of `[status, body]` pairs, and returns the list of requests it
+    /// received.
+    #[wasm_bindgen(js_name = mockFetch)]
+    fn mock_fetch(responses: JsValue) -> Array;
+}
+
+const ID: &str = "eb46d328-e1dd-11eb-8c6c-37a7d0a2d8e3";
+
+fn json(value: &str) -> JsValue {
+    JSON::parse(value).unwrap()
+}
+
+fn get(value: &JsValue, key: &str) -> JsValue {
+    Reflect::get(value, &JsValue::from_str(key)).unwrap()
+}
+
+async fn resolve<P: JsCast>(promise: P) -> Result<JsValue, JsValue> {
+    JsFuture::from(promise.unchecked_into::<Promise>()).await
+}
+
+#[wasm_bindgen_test]
+async fn get_fetches_the_object_by_id() {
+    let requests = mock_fetch(json(&format!(
+        r#"[[200, {{ "id": "{}", "title": "Service", "version": 1 }}]]"#,
+        ID
+    )));
+
+    let article = resolve(lexoffice_js::client("key").article().get(ID.into()))
+        .await
+        .unwrap();
+
+    assert_eq!(get(&article, "id"), ID);
+    assert_eq!(get(&article, "title"), "Service");
+    assert_eq!(requests.length(), 1);
+    let request = requests.get(0);
+    assert_eq!(get(&request, "method"), "GET");
+    assert_eq!(
+        get(&request, "url"),
+        format!("https://api.lexoffice.io/v1/articles/{}", ID)
+    );
+    assert_eq!(get(&request, "authorization"), "Bearer key");
+}
+
 #[wasm_bindgen_test]
-fn pass() {
-    assert_eq!(1 + 1, 2);
+async fn page_applies_the_filters() {
+    let requests = mock_fetch(json(
+        r#"[[200, {
+            "content": [{ "version": 0, "roles": { "customer": { "number": 10001 } } }],
+            "first": true,
+            "last": true,
+            "totalPages": 1,
+            "totalElements": 1,
+            "numberOfElements": 1,
+            "size": 25,
+            "number": 0
+        }]]"#,
+    ));
+
+    let page = resolve(
+        lexoffice_js::client("key")
+            .contact()
+            .number(10001)
+            .customer(true)
+            .page(0, Some(25)),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(get(&page, "totalElements"), 1);
+    assert_eq!(Array::from(&get(&page, "content")).length(), 1);
+    let url = get(&requests.get(0), "url").as_string().unwrap();
+    assert!(
+        url.starts_with("https://api.lexoffice.io/v1/contacts?"),
+        "{}",
+        url
+    );
+    for param in &["number=10001", "customer=true", "page=0", "size=25"] {
+        assert!(url.contains(param), "{} missing in {}", param, url);
+    }
+}
+
+#[wasm_bindgen_test]
+async fn save_posts_the_object() {
+    let requests = mock_fetch(json(&format!(
+        r#"[[200, {{
+            "id": "{}",
+            "resourceUri": "https://api.lexoffice.io/v1/articles/{}",
+            "createdDate": "2023-02-10T09:00:00.000+01:00",
+            "updatedDate": "2023-02-10T09:00:00.000+01:00",
+            "version": 1
+        }}]]"#,
+        ID, ID
+    )));
+
+    let result = resolve(
+        lexoffice_js::client("key")
+            .article()
+            .save(json(r#"{ "title": "Service", "type": "SERVICE" }"#)),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(get(&result, "id"), ID);
+    assert_eq!(get(&result, "version"), 1);
+    let request = requests.get(0);
+    assert_eq!(get(&request, "method"), "POST");
+    assert_eq!(get(&request, "url"), "https://api.lexoffice.io/v1/articles");
+    let body = json(&get(&request, "body").as_string().unwrap());
+    assert_eq!(get(&body, "title"), "Service");
+    assert_eq!(get(&body, "type"), "SERVICE");
+}
+
+#[wasm_bindgen_test]
+async fn failed_requests_reject_with_a_lexoffice_error() {
+    mock_fetch(json(r#"[[404, { "message": "not found" }]]"#));
+
+    let error = resolve(lexoffice_js::client("key").article().get(ID.into()))
+        .await
+        .unwrap_err();
+
+    assert!(error.is_instance_of::<js_sys::Error>());
+    assert_eq!(get(&error, "name"), "LexofficeError");
+    assert_eq!(get(&error, "kind"), "lexoffice");
+    assert_eq!(get(&error, "status"), 404);
 }
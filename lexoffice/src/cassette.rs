@@ -0,0 +1,359 @@
+use crate::Error;
+use crate::Result;
+use reqwest::header::CONTENT_TYPE;
+use reqwest::{Response, StatusCode, Url};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
+/// The value that replaces redacted JSON values
+const REDACTED: &str = "REDACTED";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Mode {
+    Record,
+    Replay,
+}
+
+/// The body of a recorded request or response
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RecordedBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    json: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    base64: Option<String>,
+}
+
+impl RecordedBody {
+    fn new(bytes: &[u8], redact: &[String]) -> Self {
+        if bytes.is_empty() {
+            Self::default()
+        } else if let Ok(mut json) = serde_json::from_slice::<Value>(bytes) {
+            for pointer in redact {
+                redact_pointer(&mut json, pointer);
+            }
+            Self {
+                json: Some(json),
+                ..Self::default()
+            }
+        } else if let Ok(text) = std::str::from_utf8(bytes) {
+            Self {
+                text: Some(text.to_string()),
+                ..Self::default()
+            }
+        } else {
+            Self {
+                base64: Some(base64::encode(bytes)),
+                ..Self::default()
+            }
+        }
+    }
+
+    fn into_bytes(self) -> Vec<u8> {
+        if let Some(json) = self.json {
+            json.to_string().into_bytes()
+        } else if let Some(text) = self.text {
+            text.into_bytes()
+        } else if let Some(data) = self.base64 {
+            base64::decode(data).unwrap_or_default()
+        } else {
+            vec![]
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RecordedRequest {
+    method: String,
+    /// Path and query of the request, relative to the host
+    url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<RecordedBody>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RecordedResponse {
+    status: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_type: Option<String>,
+    body: RecordedBody,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Interaction {
+    request: RecordedRequest,
+    response: RecordedResponse,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Tape {
+    interactions: Vec<Interaction>,
+    #[serde(skip)]
+    played: Vec<bool>,
+}
+
+/// Records the requests sent by a `Client` together with their responses,
+/// or replays them without touching the network.
+///
+/// Recorded cassettes are JSON files that are written after every request.
+/// The `Authorization` header is never recorded. Additional values can be
+/// scrubbed from the JSON bodies with `Cassette::redact()`.
+///
+/// When replaying, every request must match a recorded interaction with the
+/// same method, path, query and redacted body. Each request gets the first
+/// matching interaction that hasn't been replayed yet, so identical requests
+/// are answered in the order they were recorded while different requests
+/// may be sent in any order. Requests without a match fail with
+/// `Error::UnmatchedRequest`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use lexoffice::{ApiKey, Cassette, Client};
+/// use lexoffice::model::Contact;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// // Record the interactions once against the real API
+/// let cassette = Cassette::record("tests/cassettes/contacts.json")
+///     .redact("/content/*/emailAddresses/*/*")
+///     .redact("/content/*/phoneNumbers/*/*");
+/// let client = Client::builder()
+///     .api_key(ApiKey::try_default().await?)
+///     .cassette(cassette)
+///     .build();
+/// client.request::<Contact>().page(0).await?;
+///
+/// // And replay them in CI
+/// let client = Client::builder()
+///     .api_key(ApiKey::from("unused"))
+///     .cassette(Cassette::replay("tests/cassettes/contacts.json")?)
+///     .build();
+/// let contacts = client.request::<Contact>().page(0).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Cassette {
+    mode: Mode,
+    path: PathBuf,
+    redact: Vec<String>,
+    tape: Arc<Mutex<Tape>>,
+    /// Serializes writes of the cassette file
+    file: Arc<futures::lock::Mutex<()>>,
+}
+
+impl Cassette {
+    /// Creates a cassette that records all interactions to `path`. An
+    /// existing file is overwritten.
+    pub fn record<P: AsRef<Path>>(path: P) -> Self {
+        Self {
+            mode: Mode::Record,
+            path: path.as_ref().to_path_buf(),
+            redact: vec![],
+            tape: Default::default(),
+            file: Default::default(),
+        }
+    }
+
+    /// Loads a cassette from `path` and replays its interactions.
+    pub fn replay<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let mut tape: Tape = serde_json::from_slice(&std::fs::read(&path)?)?;
+        tape.played = vec![false; tape.interactions.len()];
+        Ok(Self {
+            mode: Mode::Replay,
+            path,
+            redact: vec![],
+            tape: Arc::new(Mutex::new(tape)),
+            file: Default::default(),
+        })
+    }
+
+    /// Replaces the JSON value at `pointer` in all recorded request and
+    /// response bodies with `"REDACTED"`. `pointer` is a
+    /// [JSON pointer](https://tools.ietf.org/html/rfc6901) in which `*`
+    /// matches all elements of an array or all fields of an object.
+    ///
+    /// Request bodies are matched after redaction, so a replaying cassette
+    /// needs the same pointers as the one that recorded it.
+    pub fn redact(mut self, pointer: &str) -> Self {
+        self.redact.push(pointer.to_string());
+        self
+    }
+
+    /// Returns `true` if all recorded interactions have been replayed.
+    pub fn is_finished(&self) -> bool {
+        self.tape.lock().unwrap().played.iter().all(|x| *x)
+    }
+
+    /// Sends `request` with `execute` while recording, or replays a
+    /// recorded response.
+    pub(crate) async fn intercept<F, Fut>(
+        &self,
+        request: reqwest::Request,
+        execute: F,
+    ) -> Result<Response>
+    where
+        F: FnOnce(reqwest::Request) -> Fut,
+        Fut: std::future::Future<Output = Result<Response>>,
+    {
+        let recorded = RecordedRequest {
+            method: request.method().to_string(),
+            url: relative_url(request.url()),
+            body: request
+                .body()
+                .and_then(|x| x.as_bytes())
+                .map(|x| RecordedBody::new(x, &self.redact)),
+        };
+        match self.mode {
+            Mode::Replay => self.play(recorded),
+            Mode::Record => {
+                let response = execute(request).await?;
+                self.record_response(recorded, response).await
+            }
+        }
+    }
+
+    fn play(&self, request: RecordedRequest) -> Result<Response> {
+        let mut tape = self.tape.lock().unwrap();
+        let Tape {
+            interactions,
+            played,
+        } = &mut *tape;
+        let index = interactions
+            .iter()
+            .zip(played.iter())
+            .position(|(x, played)| {
+                !played
+                    && x.request.method == request.method
+                    && x.request.url == request.url
+                    && x.request.body == request.body
+            })
+            .ok_or_else(|| {
+                Error::UnmatchedRequest(format!(
+                    "{} {} not found in {}",
+                    request.method,
+                    request.url,
+                    self.path.display()
+                ))
+            })?;
+        played[index] = true;
+        let response = interactions[index].response.clone();
+        let status = StatusCode::from_u16(response.status)
+            .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        Ok(build_response(
+            status,
+            response.content_type,
+            response.body.into_bytes(),
+        ))
+    }
+
+    async fn record_response(
+        &self,
+        request: RecordedRequest,
+        response: Response,
+    ) -> Result<Response> {
+        let status = response.status();
+        let content_type = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|x| x.to_str().ok())
+            .map(str::to_string);
+        let bytes = response.bytes().await?;
+        let recorded = RecordedResponse {
+            status: status.as_u16(),
+            content_type,
+            body: RecordedBody::new(&bytes, &self.redact),
+        };
+
+        let _file = self.file.lock().await;
+        let data = {
+            let mut tape = self.tape.lock().unwrap();
+            tape.interactions.push(Interaction {
+                request,
+                response: recorded.clone(),
+            });
+            serde_json::to_vec_pretty(&*tape)?
+        };
+        cfg_if::cfg_if! {
+            if #[cfg(feature = "fs")] {
+                tokio::fs::write(&self.path, data).await?;
+            } else {
+                std::fs::write(&self.path, data)?;
+            }
+        }
+
+        Ok(build_response(
+            status,
+            recorded.content_type,
+            bytes.to_vec(),
+        ))
+    }
+}
+
+fn build_response(
+    status: StatusCode,
+    content_type: Option<String>,
+    body: Vec<u8>,
+) -> Response {
+    let mut response = http::Response::new(body);
+    *response.status_mut() = status;
+    if let Some(value) = content_type.and_then(|x| x.parse().ok()) {
+        response.headers_mut().insert(CONTENT_TYPE, value);
+    }
+    response.into()
+}
+
+fn relative_url(url: &Url) -> String {
+    match url.query() {
+        Some(query) => format!("{}?{}", url.path(), query),
+        None => url.path().to_string(),
+    }
+}
+
+fn redact_pointer(value: &mut Value, pointer: &str) {
+    let segments = pointer
+        .split('/')
+        .skip(1)
+        .map(|x| x.replace("~1", "/").replace("~0", "~"))
+        .collect::<Vec<_>>();
+    redact_segments(value, &segments);
+}
+
+fn redact_segments(value: &mut Value, segments: &[String]) {
+    let (first, rest) = match segments.split_first() {
+        Some(x) => x,
+        None => {
+            *value = Value::String(REDACTED.to_string());
+            return;
+        }
+    };
+    match value {
+        Value::Object(map) if first == "*" => {
+            map.values_mut().for_each(|x| redact_segments(x, rest))
+        }
+        Value::Object(map) => {
+            if let Some(x) = map.get_mut(first) {
+                redact_segments(x, rest)
+            }
+        }
+        Value::Array(list) if first == "*" => {
+            list.iter_mut().for_each(|x| redact_segments(x, rest))
+        }
+        Value::Array(list) => {
+            if let Some(x) =
+                usize::from_str(first).ok().and_then(|x| list.get_mut(x))
+            {
+                redact_segments(x, rest)
+            }
+        }
+        _ => (),
+    }
+}
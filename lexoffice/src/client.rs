@@ -1,11 +1,18 @@
+#[cfg(all(feature = "cassette", not(target_arch = "wasm32")))]
+use crate::cassette::Cassette;
+use crate::rate_limit::RateLimiter;
 use crate::request::Request;
+use crate::retry::RetryPolicy;
 use crate::Error;
 use crate::Result;
 use derive_more::{Display, From, FromStr};
+use reqwest::header::HeaderName;
 use reqwest::Method;
-use reqwest::RequestBuilder;
+use reqwest::Response;
 use reqwest::Url;
+use serde::Serialize;
 use typed_builder::TypedBuilder;
+use wasm_timer::Delay;
 
 static BASE_URL: &str = "https://api.lexoffice.io/v1";
 //static BASE_URL: &str = "http://127.0.0.1:8100";
@@ -23,7 +30,7 @@ impl ApiKey {
     }
 
     /// Loads the API key from a specified file.
-    #[cfg(feature = "fs")]
+    #[cfg(all(feature = "fs", not(target_arch = "wasm32")))]
     pub async fn from_file(file_name: &std::path::Path) -> Result<Self> {
         let contents = tokio::fs::read_to_string(file_name).await?;
         let contents = contents.trim();
@@ -31,7 +38,7 @@ impl ApiKey {
     }
 
     /// Loads the API key from the home folder containing a `~/.lexoffice` file
-    #[cfg(all(feature = "fs", feature = "env"))]
+    #[cfg(all(feature = "fs", feature = "env", not(target_arch = "wasm32")))]
     pub async fn from_home() -> Result<Self> {
         use std::env;
 
@@ -56,7 +63,11 @@ impl ApiKey {
         }
 
         cfg_if::cfg_if! {
-            if #[cfg(all(feature = "fs", feature = "env"))] {
+            if #[cfg(all(
+                feature = "fs",
+                feature = "env",
+                not(target_arch = "wasm32"),
+            ))] {
                 if let Ok(key) = Self::from_home().await {
                     return Ok(key);
                 }
@@ -104,6 +115,19 @@ pub struct Client {
 
     #[builder(default=Url::parse(BASE_URL).unwrap())]
     base_url: Url,
+
+    /// Limits the number of requests sent by this client and its clones
+    #[builder(default, setter(strip_option))]
+    rate_limiter: Option<RateLimiter>,
+
+    /// Retries requests that were throttled by LexOffice
+    #[builder(default, setter(strip_option))]
+    retry_policy: Option<RetryPolicy>,
+
+    /// Records or replays all requests sent by this client
+    #[cfg(all(feature = "cassette", not(target_arch = "wasm32")))]
+    #[builder(default, setter(strip_option))]
+    cassette: Option<Cassette>,
 }
 
 impl Client {
@@ -117,18 +141,19 @@ impl Client {
         Request::new(self.clone())
     }
 
-    pub(crate) fn http_builder(
-        &self,
-        method: Method,
-        url: Url,
-    ) -> RequestBuilder {
+    pub(crate) fn http_builder(&self, method: Method, url: Url) -> HttpRequest {
         // Not using Request::bearer_auth() here as it's not yet available for
         // wasm
         let bearer = format!("Bearer {}", &self.api_key);
 
-        self.http_client
+        let builder = self
+            .http_client
             .request(method, url)
-            .header(reqwest::header::AUTHORIZATION, bearer)
+            .header(reqwest::header::AUTHORIZATION, bearer);
+        HttpRequest {
+            client: self.clone(),
+            builder,
+        }
     }
 
     /// Returns the base Url used by this client
@@ -136,3 +161,78 @@ impl Client {
         &self.base_url
     }
 }
+
+/// A HTTP request that is sent with the rate limit and retry policy of the
+/// `Client` it was created from.
+pub(crate) struct HttpRequest {
+    client: Client,
+    builder: reqwest::RequestBuilder,
+}
+
+impl HttpRequest {
+    pub fn header(self, key: HeaderName, value: &str) -> Self {
+        Self {
+            client: self.client,
+            builder: self.builder.header(key, value),
+        }
+    }
+
+    pub fn json<T: Serialize + ?Sized>(self, json: &T) -> Self {
+        Self {
+            client: self.client,
+            builder: self.builder.json(json),
+        }
+    }
+
+    pub fn multipart(self, form: reqwest::multipart::Form) -> Self {
+        Self {
+            client: self.client,
+            builder: self.builder.multipart(form),
+        }
+    }
+
+    /// Sends the request. Throttled requests are retried according to the
+    /// `RetryPolicy` of the client.
+    pub async fn send(self) -> Result<Response> {
+        let client = self.client;
+        let request = self.builder.build()?;
+
+        #[cfg(all(feature = "cassette", not(target_arch = "wasm32")))]
+        if let Some(cassette) = &client.cassette {
+            return cassette
+                .intercept(request, |request| execute(&client, request))
+                .await;
+        }
+
+        execute(&client, request).await
+    }
+}
+
+async fn execute(
+    client: &Client,
+    request: reqwest::Request,
+) -> Result<Response> {
+    let policy = client.retry_policy.as_ref();
+    let max_retries = policy.map_or(0, |x| x.max_retries(request.method()));
+    let mut attempt = 0;
+
+    loop {
+        let retry = if attempt < max_retries {
+            request.try_clone()
+        } else {
+            None
+        };
+        if let Some(rate_limiter) = &client.rate_limiter {
+            rate_limiter.acquire().await?;
+        }
+        let response = match retry {
+            Some(retry) => client.http_client.execute(retry).await?,
+            None => return Ok(client.http_client.execute(request).await?),
+        };
+        match policy.and_then(|x| x.backoff(attempt, &response)) {
+            Some(backoff) => Delay::new(backoff).await?,
+            None => return Ok(response),
+        }
+        attempt += 1;
+    }
+}
@@ -1,19 +1,45 @@
 //! Error handling for the `lexoffice` crate
+use crate::types::DateTime;
 use derive_more::*;
 use reqwest::StatusCode;
+use reqwest::Url;
 use serde::Deserialize;
 use std::fmt;
+use uuid::Uuid;
 
 /// This enum represents error messages from LexOffice
 #[derive(Debug, Deserialize)]
-#[serde(deny_unknown_fields)]
+#[serde(from = "RawMessage")]
 pub enum Message {
     /// error messages from LexOffice
-    #[serde(rename = "message")]
     Message(String),
     /// legacy error messages from LexOffice
-    #[serde(rename = "IssueList")]
     IssueList(Vec<Issue>),
+    /// error messages from LexOffice containing details about the request
+    Detailed(DetailedMessage),
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawMessage {
+    Detailed(DetailedMessage),
+    Message {
+        message: String,
+    },
+    IssueList {
+        #[serde(rename = "IssueList")]
+        issue_list: Vec<Issue>,
+    },
+}
+
+impl From<RawMessage> for Message {
+    fn from(raw: RawMessage) -> Self {
+        match raw {
+            RawMessage::Detailed(x) => Self::Detailed(x),
+            RawMessage::Message { message } => Self::Message(message),
+            RawMessage::IssueList { issue_list } => Self::IssueList(issue_list),
+        }
+    }
 }
 
 impl fmt::Display for Message {
@@ -28,11 +54,12 @@ impl fmt::Display for Message {
                 for item in x {
                     if !first {
                         write!(f, ", ")?;
-                        first = false;
                     }
+                    first = false;
                     item.fmt(f)?;
                 }
             }
+            Self::Detailed(x) => x.fmt(f)?,
         }
         Ok(())
     }
@@ -49,12 +76,42 @@ pub struct Issue {
     args: Option<String>,
 }
 
+impl Issue {
+    /// The translation key describing the issue, e.g. `missing_entity`
+    pub fn i18n_key(&self) -> &str {
+        &self.i18n_key
+    }
+
+    /// The field or entity that caused the issue
+    pub fn source(&self) -> Option<&str> {
+        self.source.as_deref()
+    }
+
+    /// The type of the issue, e.g. `validation_failure`
+    pub fn type_(&self) -> &str {
+        &self._type
+    }
+
+    /// Additional data about the issue
+    pub fn additional_data(&self) -> Option<&str> {
+        self.additional_data.as_deref()
+    }
+
+    /// Arguments of the issue
+    pub fn args(&self) -> Option<&str> {
+        self.args.as_deref()
+    }
+}
+
 impl fmt::Display for Issue {
     fn fmt(
         &self,
         f: &mut std::fmt::Formatter<'_>,
     ) -> std::result::Result<(), std::fmt::Error> {
         write!(f, "{}: {}", self._type, self.i18n_key)?;
+        if let Some(source) = &self.source {
+            write!(f, " source: {}", source)?;
+        }
         if let Some(additional_data) = &self.additional_data {
             write!(f, " data: {}", additional_data)?;
         }
@@ -65,6 +122,117 @@ impl fmt::Display for Issue {
     }
 }
 
+/// Represents the error messages of LexOffice containing details about the
+/// failed request.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DetailedMessage {
+    timestamp: Option<DateTime>,
+    status: u16,
+    error: String,
+    path: Option<String>,
+    trace_id: Option<String>,
+    message: Option<String>,
+    #[serde(default)]
+    details: Vec<Detail>,
+}
+
+impl DetailedMessage {
+    /// The time the error occurred
+    pub fn timestamp(&self) -> Option<&DateTime> {
+        self.timestamp.as_ref()
+    }
+
+    /// The HTTP status code reported by LexOffice
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    /// The reason phrase of the status code, e.g. `Not Acceptable`
+    pub fn error(&self) -> &str {
+        &self.error
+    }
+
+    /// The path of the failed request
+    pub fn path(&self) -> Option<&str> {
+        self.path.as_deref()
+    }
+
+    /// The id LexOffice assigned to the failed request
+    pub fn trace_id(&self) -> Option<&str> {
+        self.trace_id.as_deref()
+    }
+
+    /// A human readable description of the error
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    /// The violations that caused the error
+    pub fn details(&self) -> &[Detail] {
+        &self.details
+    }
+}
+
+impl fmt::Display for DetailedMessage {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::result::Result<(), std::fmt::Error> {
+        match self.message.as_ref() {
+            Some(message) => write!(f, "{}", message)?,
+            None => write!(f, "{}", self.error)?,
+        }
+        for detail in &self.details {
+            write!(f, ", {}", detail)?;
+        }
+        Ok(())
+    }
+}
+
+/// Represents a single violation in a `DetailedMessage`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Detail {
+    violation: Option<String>,
+    field: Option<String>,
+    message: Option<String>,
+}
+
+impl Detail {
+    /// The kind of the violation, e.g. `NOTNULL`
+    pub fn violation(&self) -> Option<&str> {
+        self.violation.as_deref()
+    }
+
+    /// The field that caused the violation, e.g.
+    /// `lineItems[0].unitPrice.taxRatePercentage`
+    pub fn field(&self) -> Option<&str> {
+        self.field.as_deref()
+    }
+
+    /// A human readable description of the violation
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+}
+
+impl fmt::Display for Detail {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::result::Result<(), std::fmt::Error> {
+        let unknown = "unknown";
+        write!(
+            f,
+            "{}: {} {}",
+            self.violation.as_deref().unwrap_or(unknown),
+            self.field.as_deref().unwrap_or(unknown),
+            self.message.as_deref().unwrap_or_default(),
+        )
+    }
+}
+
 /// The Errors that may occur working with this crate.
 #[derive(Debug, Display, Error, From)]
 pub enum Error {
@@ -84,6 +252,13 @@ pub enum Error {
     /// Errors from the `uuid` crate.
     Uuid(uuid::Error),
 
+    /// Errors when parsing JSON
+    Json(serde_json::Error),
+
+    /// Errors from the `rust_decimal` crate, e.g. when a `f64` can't be
+    /// converted to an `Amount`
+    Decimal(rust_decimal::Error),
+
     /// Error regarding environment variables
     Env(std::env::VarError),
 
@@ -102,20 +277,140 @@ pub enum Error {
 
     /// When converting an object to its uuid
     NoUuid,
+
+    /// The object was changed on the server since it was fetched.
+    ///
+    /// See
+    /// [the official API docs](https://developers.lexoffice.io/docs/#optimistic-locking)
+    /// for more information.
+    VersionConflict(VersionConflict),
+
+    /// The signature of a webhook doesn't match its body.
+    InvalidSignature,
+
+    /// The public key used to verify webhooks couldn't be parsed.
+    InvalidPublicKey,
+
+    /// A `Cancellation` doesn't match the line items of the cancelled
+    /// invoice.
+    #[display(fmt = "Invalid cancellation: {}", _0)]
+    #[from(ignore)]
+    InvalidCancellation(#[error(not(source))] String),
+
+    /// A voucher was saved, but its receipt couldn't be attached.
+    ReceiptNotAttached(ReceiptNotAttached),
+
+    /// A request sent while replaying a `Cassette` wasn't recorded.
+    #[display(fmt = "Unmatched request: {}", _0)]
+    #[from(ignore)]
+    UnmatchedRequest(#[error(not(source))] String),
 }
 
 impl Unpin for Error {}
 
 /// An error returned from LexOffice
+///
+/// # Examples
+///
+/// ```
+/// use lexoffice::{ApiKey, Client, Error};
+/// use lexoffice::model::Contact;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(ApiKey::try_default().await?);
+/// let uuid = uuid::Uuid::parse_str("f4add52b-44e3-474a-b718-890885094d9a")?;
+/// match client.request::<Contact>().by_id(uuid).await {
+///     Ok(contact) => println!("{:#?}", contact),
+///     Err(Error::LexOffice(err)) if err.is_not_found() => {
+///         println!("no such contact")
+///     }
+///     Err(Error::LexOffice(err)) if err.is_validation() => {
+///         for issue in err.issues() {
+///             println!("{:?}: {}", issue.source(), issue.i18n_key());
+///         }
+///     }
+///     Err(err) => return Err(err.into()),
+/// }
+/// # Ok(())
+/// # }
+/// ```
 #[derive(Debug)]
 pub struct LexOfficeError {
     status: StatusCode,
-    message: Message,
+    message: Box<Message>,
+    url: Option<Url>,
 }
 
 impl LexOfficeError {
-    pub(crate) fn new(status: StatusCode, message: Message) -> Self {
-        Self { status, message }
+    /// Creates an error like it is returned for a failed request, e.g. to
+    /// test the error handling of an integration.
+    pub fn new(status: StatusCode, message: Message, url: Option<Url>) -> Self {
+        Self {
+            status,
+            message: Box::new(message),
+            url,
+        }
+    }
+
+    /// The HTTP status code of the response
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// The error message sent by LexOffice
+    pub fn message(&self) -> &Message {
+        &self.message
+    }
+
+    /// The URL of the failed request
+    pub fn url(&self) -> Option<&Url> {
+        self.url.as_ref()
+    }
+
+    /// The id LexOffice assigned to the failed request, if it was sent
+    pub fn trace_id(&self) -> Option<&str> {
+        match self.message.as_ref() {
+            Message::Detailed(x) => x.trace_id(),
+            _ => None,
+        }
+    }
+
+    /// The issues of a legacy error message. Empty for all other messages.
+    pub fn issues(&self) -> &[Issue] {
+        match self.message.as_ref() {
+            Message::IssueList(x) => x,
+            _ => &[],
+        }
+    }
+
+    /// The violations of a detailed error message. Empty for all other
+    /// messages.
+    pub fn details(&self) -> &[Detail] {
+        match self.message.as_ref() {
+            Message::Detailed(x) => x.details(),
+            _ => &[],
+        }
+    }
+
+    /// Returns `true` if the requested object does not exist
+    pub fn is_not_found(&self) -> bool {
+        self.status == StatusCode::NOT_FOUND
+    }
+
+    /// Returns `true` if LexOffice rejected the request because of invalid
+    /// content
+    pub fn is_validation(&self) -> bool {
+        matches!(
+            self.status,
+            StatusCode::BAD_REQUEST
+                | StatusCode::NOT_ACCEPTABLE
+                | StatusCode::UNPROCESSABLE_ENTITY
+        )
+    }
+
+    /// Returns `true` if LexOffice throttled the request
+    pub fn is_rate_limited(&self) -> bool {
+        self.status == StatusCode::TOO_MANY_REQUESTS
     }
 }
 
@@ -129,3 +424,258 @@ impl fmt::Display for LexOfficeError {
 }
 
 impl std::error::Error for LexOfficeError {}
+
+/// An error returned from LexOffice when an update was rejected because the
+/// object was changed in the meantime.
+#[derive(Debug)]
+pub struct VersionConflict {
+    current_version: Option<u64>,
+    error: LexOfficeError,
+}
+
+impl VersionConflict {
+    /// Creates a version conflict from the error returned by LexOffice and
+    /// the current version of the object, if it is known.
+    pub fn new(current_version: Option<u64>, error: LexOfficeError) -> Self {
+        Self {
+            current_version,
+            error,
+        }
+    }
+
+    /// The current version of the object on the server, if it could be
+    /// determined.
+    pub fn current_version(&self) -> Option<u64> {
+        self.current_version
+    }
+
+    /// The error returned from LexOffice
+    pub fn error(&self) -> &LexOfficeError {
+        &self.error
+    }
+}
+
+impl fmt::Display for VersionConflict {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::result::Result<(), std::fmt::Error> {
+        write!(f, "Version conflict")?;
+        if let Some(current_version) = self.current_version {
+            write!(f, " (current version {})", current_version)?;
+        }
+        write!(f, ": {}", self.error)
+    }
+}
+
+impl std::error::Error for VersionConflict {}
+
+/// Returned by `Request::<Voucher>::save_receipt()` when the voucher was
+/// saved but attaching the receipt failed. The voucher exists on the server
+/// without a file, the receipt can be attached to it again with
+/// `Request::<Voucher>::attach_path()`.
+#[derive(Debug)]
+pub struct ReceiptNotAttached {
+    voucher_id: Uuid,
+    error: Box<Error>,
+}
+
+impl ReceiptNotAttached {
+    #[cfg_attr(
+        not(all(feature = "fs", not(target_arch = "wasm32"))),
+        allow(dead_code)
+    )]
+    pub(crate) fn new(voucher_id: Uuid, error: Error) -> Self {
+        Self {
+            voucher_id,
+            error: Box::new(error),
+        }
+    }
+
+    /// The id of the saved voucher
+    pub fn voucher_id(&self) -> Uuid {
+        self.voucher_id
+    }
+
+    /// The error that occurred while attaching the receipt
+    pub fn error(&self) -> &Error {
+        &self.error
+    }
+}
+
+impl fmt::Display for ReceiptNotAttached {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::result::Result<(), std::fmt::Error> {
+        write!(
+            f,
+            "Voucher {} was saved, but the receipt couldn't be attached: {}",
+            self.voucher_id, self.error
+        )
+    }
+}
+
+impl std::error::Error for ReceiptNotAttached {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(self.error.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(status: StatusCode, body: &str) -> LexOfficeError {
+        let message = serde_json::from_str(body).unwrap();
+        LexOfficeError::new(status, message, None)
+    }
+
+    #[test]
+    fn legacy_issue_lists_are_parsed() {
+        let err = error(
+            StatusCode::BAD_REQUEST,
+            r#"{"IssueList": [
+                {"i18nKey": "missing_entity", "source": "company and person", "type": "validation_failure"},
+                {"i18nKey": "invalid_value", "source": "zip", "type": "validation_failure", "additionalData": "79112x", "args": "zip"}
+            ]}"#,
+        );
+
+        assert!(matches!(err.message(), Message::IssueList(_)));
+        assert_eq!(err.issues().len(), 2);
+        let issue = &err.issues()[0];
+        assert_eq!(issue.i18n_key(), "missing_entity");
+        assert_eq!(issue.source(), Some("company and person"));
+        assert_eq!(issue.type_(), "validation_failure");
+        assert_eq!(issue.additional_data(), None);
+        assert_eq!(issue.args(), None);
+        let issue = &err.issues()[1];
+        assert_eq!(issue.additional_data(), Some("79112x"));
+        assert_eq!(issue.args(), Some("zip"));
+        assert!(err.details().is_empty());
+        assert_eq!(err.trace_id(), None);
+        assert_eq!(
+            err.to_string(),
+            "HTTP Error 400 Bad Request: \
+             validation_failure: missing_entity source: company and person, \
+             validation_failure: invalid_value source: zip data: 79112x args(zip)"
+        );
+    }
+
+    #[test]
+    fn detailed_messages_are_parsed() {
+        let err = error(
+            StatusCode::NOT_ACCEPTABLE,
+            r#"{
+                "timestamp": "2017-05-11T17:12:31.233+02:00",
+                "status": 406,
+                "error": "Not Acceptable",
+                "path": "/v1/invoices",
+                "traceId": "90d78d0777be",
+                "message": "Validation failed for request. Please see details list for specific causes.",
+                "details": [{
+                    "violation": "NOTNULL",
+                    "field": "lineItems[0].unitPrice.taxRatePercentage",
+                    "message": "darf nicht leer sein"
+                }]
+            }"#,
+        );
+
+        let detailed = match err.message() {
+            Message::Detailed(x) => x,
+            x => panic!("expected a detailed message, got {:?}", x),
+        };
+        assert!(detailed.timestamp().is_some());
+        assert_eq!(detailed.status(), 406);
+        assert_eq!(detailed.error(), "Not Acceptable");
+        assert_eq!(detailed.path(), Some("/v1/invoices"));
+        assert_eq!(err.trace_id(), Some("90d78d0777be"));
+        assert!(err.issues().is_empty());
+        assert_eq!(err.details().len(), 1);
+        let detail = &err.details()[0];
+        assert_eq!(detail.violation(), Some("NOTNULL"));
+        assert_eq!(
+            detail.field(),
+            Some("lineItems[0].unitPrice.taxRatePercentage")
+        );
+        assert_eq!(detail.message(), Some("darf nicht leer sein"));
+        assert_eq!(
+            err.to_string(),
+            "HTTP Error 406 Not Acceptable: Validation failed for request. \
+             Please see details list for specific causes., \
+             NOTNULL: lineItems[0].unitPrice.taxRatePercentage \
+             darf nicht leer sein"
+        );
+    }
+
+    #[test]
+    fn detailed_messages_without_message_use_the_error() {
+        let err = error(
+            StatusCode::NOT_FOUND,
+            r#"{"status": 404, "error": "Not Found", "path": "/v1/contacts/x"}"#,
+        );
+
+        assert!(matches!(err.message(), Message::Detailed(_)));
+        assert!(err.details().is_empty());
+        assert_eq!(err.to_string(), "HTTP Error 404 Not Found: Not Found");
+    }
+
+    #[test]
+    fn plain_messages_are_parsed() {
+        let err =
+            error(StatusCode::UNAUTHORIZED, r#"{"message": "Unauthorized"}"#);
+
+        assert!(
+            matches!(err.message(), Message::Message(x) if x == "Unauthorized")
+        );
+        assert!(err.issues().is_empty());
+        assert!(err.details().is_empty());
+    }
+
+    async fn response_error(status: u16, body: &'static str) -> LexOfficeError {
+        let response =
+            http::Response::builder().status(status).body(body).unwrap();
+        match crate::util::error_for_lexoffice(response.into()).await {
+            Err(Error::LexOffice(x)) => x,
+            x => panic!("expected a LexOffice error, got {:?}", x),
+        }
+    }
+
+    #[tokio::test]
+    async fn unparseable_bodies_fall_back_to_messages() {
+        for body in &["<html>Bad Gateway</html>", "{}", r#"{"IssueList": 1}"#] {
+            assert!(serde_json::from_str::<Message>(body).is_err(), "{}", body);
+            let err = response_error(502, body).await;
+            assert_eq!(err.status(), StatusCode::BAD_GATEWAY);
+            assert!(matches!(err.message(), Message::Message(x) if x == body));
+            assert!(err.issues().is_empty());
+            assert!(err.details().is_empty());
+        }
+
+        let err = response_error(503, " ").await;
+        assert!(matches!(
+            err.message(),
+            Message::Message(x) if x == "Service Unavailable"
+        ));
+    }
+
+    #[test]
+    fn status_predicates() {
+        let err = |status| {
+            LexOfficeError::new(status, Message::Message(String::new()), None)
+        };
+
+        assert!(err(StatusCode::NOT_FOUND).is_not_found());
+        assert!(!err(StatusCode::GONE).is_not_found());
+        for status in &[
+            StatusCode::BAD_REQUEST,
+            StatusCode::NOT_ACCEPTABLE,
+            StatusCode::UNPROCESSABLE_ENTITY,
+        ] {
+            assert!(err(*status).is_validation(), "{}", status);
+        }
+        assert!(!err(StatusCode::CONFLICT).is_validation());
+        assert!(err(StatusCode::TOO_MANY_REQUESTS).is_rate_limited());
+        assert!(!err(StatusCode::SERVICE_UNAVAILABLE).is_rate_limited());
+    }
+}
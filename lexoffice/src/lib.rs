@@ -7,20 +7,32 @@
 pub mod error;
 pub mod model;
 pub mod request;
+#[cfg(all(feature = "testing", not(target_arch = "wasm32")))]
+pub mod testing;
 pub mod types;
+#[cfg(feature = "webhook")]
+pub mod webhook;
 
+#[cfg(all(feature = "cassette", not(target_arch = "wasm32")))]
+mod cassette;
 mod client;
-#[cfg(feature = "fs")]
+#[cfg(all(feature = "fs", not(target_arch = "wasm32")))]
 mod fs;
 mod marker;
 mod mime;
+mod rate_limit;
 mod result;
+mod retry;
 mod serde;
 mod util;
 
+#[cfg(all(feature = "cassette", not(target_arch = "wasm32")))]
+pub use cassette::Cassette;
 pub use client::ApiKey;
 pub use client::Client;
 pub use client::ClientBuilder;
 pub use error::Error;
 pub use marker::ReadOnly;
+pub use rate_limit::RateLimiter;
 pub use result::Result;
+pub use retry::RetryPolicy;
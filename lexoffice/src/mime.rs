@@ -1,3 +1,8 @@
+#![cfg_attr(
+    not(all(feature = "fs", not(target_arch = "wasm32"))),
+    allow(dead_code)
+)]
+
 use mime::Mime;
 use std::ffi::OsStr;
 use std::path::Path;
@@ -56,3 +61,9 @@ impl ExtensionExt for Mime {
         extension(self.as_ref())
     }
 }
+
+impl ExtensionExt for str {
+    fn extension(&self) -> &'static str {
+        extension(self)
+    }
+}
@@ -0,0 +1,178 @@
+#![doc = "This endpoint provides read and write access to articles (products and services). Articles can be referenced by the line items of sales vouchers. It is also possible to use filters on the articles collection."]
+use serde::{Deserialize, Serialize};
+use typed_builder::TypedBuilder;
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub enum LeadingPrice {
+    #[serde(rename = "GROSS")]
+    Gross,
+    #[serde(rename = "NET")]
+    Net,
+    #[doc = "A value that is not known to this crate"]
+    #[serde(skip)]
+    Unknown(String),
+}
+impl std::str::FromStr for LeadingPrice {
+    type Err = serde_plain::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_plain::from_str::<Self>(s)
+    }
+}
+impl Serialize for LeadingPrice {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Unknown(x) => serializer.serialize_str(x),
+            _ => Self::serialize(self, serializer),
+        }
+    }
+}
+impl<'de> Deserialize<'de> for LeadingPrice {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde::lenient_enum::deserialize(
+            deserializer,
+            Self::deserialize,
+            Self::Unknown,
+        )
+    }
+}
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub enum Type {
+    #[serde(rename = "PRODUCT")]
+    Product,
+    #[serde(rename = "SERVICE")]
+    Service,
+    #[doc = "A value that is not known to this crate"]
+    #[serde(skip)]
+    Unknown(String),
+}
+impl std::str::FromStr for Type {
+    type Err = serde_plain::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_plain::from_str::<Self>(s)
+    }
+}
+impl Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Unknown(x) => serializer.serialize_str(x),
+            _ => Self::serialize(self, serializer),
+        }
+    }
+}
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde::lenient_enum::deserialize(
+            deserializer,
+            Self::deserialize,
+            Self::Unknown,
+        )
+    }
+}
+#[doc = "An article describes a product or a service that can be sold."]
+#[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
+#[builder(doc)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct Article {
+    #[doc = "Unique id of the article generated on creation by lexoffice.  \n*Read-only.*"]
+    #[builder(default, setter(skip))]
+    pub id: crate::marker::ReadOnly<uuid::Uuid>,
+    #[doc = "Unique id of the organization the article belongs to.  \n*Read-only.*"]
+    #[builder(default, setter(skip))]
+    pub organization_id: crate::marker::ReadOnly<uuid::Uuid>,
+    #[doc = "The instant of time when the article was created by lexoffice in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2023-04-24T08:20:22.528+02:00*).  \n*Read-only.*"]
+    #[builder(default, setter(skip))]
+    pub created_date: crate::marker::ReadOnly<crate::types::DateTime>,
+    #[doc = "The instant of time when the article was updated by lexoffice in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2023-04-24T08:20:22.528+02:00*).  \n*Read-only.*"]
+    #[builder(default, setter(skip))]
+    pub updated_date: crate::marker::ReadOnly<crate::types::DateTime>,
+    #[doc = "Archived flag of the article.  \n*Read-only.*"]
+    #[builder(default, setter(skip))]
+    pub archived: crate::marker::ReadOnly<bool>,
+    #[doc = "Title of the article."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub title: Option<String>,
+    #[doc = "Description of the article."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub description: Option<String>,
+    #[doc = "The type of the article. Possible values are **PRODUCT** and **SERVICE**."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub _type: Option<Type>,
+    #[doc = "The article number as given by the user."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub article_number: Option<String>,
+    #[doc = "Global Trade Item Number (GTIN) of the article. If given, the value will be validated to match one of the formats GTIN-8, GTIN-12, GTIN-13, or GTIN-14."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub gtin: Option<String>,
+    #[doc = "Internal note for the article."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub note: Option<String>,
+    #[doc = "Unit name of the article. If the provided unit name is not known in lexoffice it will be created on the fly."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub unit_name: Option<String>,
+    #[doc = "Price of the article. For details see below."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub price: Option<Price>,
+    #[doc = "Version *(revision)* number which will be increased on each change to handle [optimistic locking](#optimistic-locking).  \n*Read-only.*"]
+    #[serde(default)]
+    #[builder(default, setter(skip))]
+    pub version: i64,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+impl crate::request::HasId for Article {
+    fn id(&self) -> &crate::marker::ReadOnly<uuid::Uuid> {
+        &self.id
+    }
+}
+#[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
+#[builder(doc)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct Price {
+    #[doc = "The net price of the article. The value can contain up to 4 decimals."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub net_price: Option<crate::types::Amount>,
+    #[doc = "The gross price of the article. The value can contain up to 4 decimals."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub gross_price: Option<crate::types::Amount>,
+    #[doc = "The price that is leading for calculations of the other price. Possible values are **NET** and **GROSS**."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub leading_price: Option<LeadingPrice>,
+    #[doc = "The tax rate of the article. See [the \"Supported tax rates\" FAQ](#faq-valid-tax-rates) for more information and a list of possible values."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub tax_rate: Option<f64>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
@@ -4,7 +4,8 @@ use typed_builder::TypedBuilder;
 #[doc = "```json\n{\n    \"id\": \"be9475f4-ef80-442b-8ab9-3ab8b1a2aeb9\",\n    \"organizationId\": \"aa93e8a8-2aa3-470b-b914-caad8a255dd8\",\n    \"version\": 1,\n    \"roles\": {\n        \"customer\": {\n            \"number\": 10307\n        },\n        \"vendor\": {\n            \"number\": 70303\n        }\n    },\n    \"company\": {\n        \"name\": \"Testfirma\",\n        \"taxNumber\": \"12345/12345\",\n        \"vatRegistrationId\": \"DE123456789\",\n        \"allowTaxFreeInvoices\": true,\n        \"contactPersons\": [\n            {\n                \"salutation\": \"Herr\",\n                \"firstName\": \"Max\",\n                \"lastName\": \"Mustermann\",\n                \"primary\": true,\n                \"emailAddress\": \"contactpersonmail@lexoffice.de\",\n                \"phoneNumber\": \"08000/11111\"\n            }\n        ]\n    },\n    \"addresses\": {\n        \"billing\": [\n            {\n                \"supplement\": \"Rechnungsadressenzusatz\",\n                \"street\": \"Hauptstr. 5\",\n                \"zip\": \"12345\",\n                \"city\": \"Musterort\",\n                \"countryCode\": \"DE\"\n            }\n        ],\n        \"shipping\": [\n            {\n                \"supplement\": \"Lieferadressenzusatz\",\n                \"street\": \"Schulstr. 13\",\n                \"zip\": \"76543\",\n                \"city\": \"MUsterstadt\",\n                \"countryCode\": \"DE\"\n            }\n        ]\n    },\n    \"xRechnung\": {\n        \"buyerReference\": \"04011000-1234512345-35\",\n        \"vendorNumberAtCustomer\": \"70123456\"\n    },\n    \"emailAddresses\": {\n        \"business\": [\n            \"business@lexoffice.de\"\n        ],\n        \"office\": [\n            \"office@lexoffice.de\"\n        ],\n        \"private\": [\n            \"private@lexoffice.de\"\n        ],\n        \"other\": [\n            \"other@lexoffice.de\"\n        ]\n    },\n    \"phoneNumbers\": {\n        \"business\": [\n            \"08000/1231\"\n        ],\n        \"office\": [\n            \"08000/1232\"\n        ],\n        \"mobile\": [\n            \"08000/1233\"\n        ],\n        \"private\": [\n            \"08000/1234\"\n        ],\n        \"fax\": [\n            \"08000/1235\"\n        ],\n        \"other\": [\n            \"08000/1236\"\n        ]\n    },\n    \"note\": \"Notizen\",\n    \"archived\": false\n}\n\n```"]
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct Contact {
     #[doc = "Unique id of the contact generated on creation by lexoffice."]
     #[builder(default, setter(skip))]
@@ -14,6 +15,7 @@ pub struct Contact {
     #[builder(default, setter(strip_option))]
     pub organization_id: Option<uuid::Uuid>,
     #[doc = "Version *(revision)* number which will be increased on each change to handle [optimistic locking](https://developers.lexoffice.io/docs/#optimistic-locking).  \n*Read-only.*"]
+    #[serde(default)]
     #[builder(default, setter(skip))]
     pub version: i64,
     #[doc = "Defines contact roles and supports further contact information. For object details see below."]
@@ -50,6 +52,11 @@ pub struct Contact {
     #[doc = "Archived flag of the contact.  \n*Read-only.*"]
     #[builder(default, setter(skip))]
     pub archived: crate::marker::ReadOnly<bool>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 impl crate::request::HasId for Contact {
     fn id(&self) -> &crate::marker::ReadOnly<uuid::Uuid> {
@@ -59,7 +66,8 @@ impl crate::request::HasId for Contact {
 #[doc = "Contains a customer and/or a vendor object. The presence of a role in the JSON implies that the contact will have this role. For example, if the customer object is present, the contact has the role customer.\nPlease note that each contact must have at least one role."]
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct Roles {
     #[doc = "May be present. If present the created contact has the role customer."]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -69,27 +77,45 @@ pub struct Roles {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
     pub vendor: Option<Vendor>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct Customer {
     #[doc = "Unique customer number within the current organization. This number is created by lexoffice for contacts with role Customer. It cannot be set during creation and cannot be changed.  \n*Read-only.*"]
     #[builder(default, setter(skip))]
     pub number: crate::marker::ReadOnly<i64>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct Vendor {
     #[doc = "Unique vendor number within the current organization. This number is created by lexoffice for contacts with role Vendor. It cannot be set during creation and cannot be changed.  \n*Read-only.*"]
     #[builder(default, setter(skip))]
     pub number: crate::marker::ReadOnly<i64>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 #[doc = "Use this object to provide information for a contact of type company."]
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct Company {
     #[doc = "Possible values are **true** or **false**."]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -110,11 +136,17 @@ pub struct Company {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
     pub contact_persons: Option<Vec<CompanyContactPerson>>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 #[doc = "Please note that it's only possible to create and change contacts with a maximum of one contact person. It's possible to retrieve contacts with more than one contact person, but it's not possible to update such a contact via the REST API."]
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct CompanyContactPerson {
     #[doc = "Salutation for the contact person with max length of 25 characters."]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -139,11 +171,17 @@ pub struct CompanyContactPerson {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
     pub phone_number: Option<String>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 #[doc = "```json\n{\n  \"id\": \"e9066f04-8cc7-4616-93f8-ac9ecc8479c8\",\n  \"organizationId\": \"aa93e8a8-2aa3-470b-b914-caad8a255dd8\",\n  \"version\": 0,\n  \"roles\": {\n    \"customer\": {\n      \"number\": 10308\n    }\n  },\n  \"person\": {\n    \"salutation\": \"Frau\",\n    \"firstName\": \"Inge\",\n    \"lastName\": \"Musterfrau\"\n  },\n  \"archived\": false\n}\n\n```\n\nUse this object to provide information for a contact of type private person."]
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct Person {
     #[doc = "Salutation for the individual person with max length of 25 characters."]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -156,11 +194,17 @@ pub struct Person {
     #[doc = "Last name of the person."]
     #[builder(setter(into))]
     pub last_name: String,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 #[doc = "Use this objects to provide billing and shipping information of a contact.\n\nPlease note that it's only possible to create and change contacts with a maximum of one billing and/or one shipping address. It's possible to retrieve contacts with more than one billing and shipping address, but it's not possible to update such a contact via the REST API."]
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct Addresses {
     #[doc = "A list of billing addresses. Each entry is an object of [address](https://developers.lexoffice.io/docs/#address-details)."]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -170,10 +214,16 @@ pub struct Addresses {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
     pub shipping: Option<Vec<Address>>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct Address {
     #[doc = "Additional address information."]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -194,11 +244,17 @@ pub struct Address {
     #[doc = "Country code in the format of [ISO 3166 alpha2](https://developers.lexoffice.io/docs/#faq-country-codes) (e.g. DE is used for germany)."]
     #[builder(setter(into))]
     pub country_code: crate::types::CountryCode,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 #[doc = "Contacts for German public authorities should be created with both of the following attributes set.\nThis results in the generation of invoice documents conforming to the German XRechnung standard when creating invoices in lexoffice.\n\nIf a customer's `buyerReference` is set, its `vendorNumberAtCustomer` needs to be set as well."]
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct XRechnung {
     #[doc = "Customer's *Leitweg-ID* conforming to the German XRechnung system"]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -208,11 +264,17 @@ pub struct XRechnung {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
     pub vendor_number_at_customer: Option<String>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 #[doc = "Please note that it's only possible to create and change contacts with a maximum of one entry in each of the below described lists. It's possible to retrieve contacts with more than one entry in the lists, but it's not possible to update such a contact via the REST API."]
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct EmailAddresses {
     #[doc = "A list of email addresses. Each entry is of type string and contains an email address."]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -230,11 +292,17 @@ pub struct EmailAddresses {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
     pub other: Option<Vec<String>>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 #[doc = "Please note that it's only possible to create and change contacts with a maximum of one entry in each of the below described lists. It's possible to retrieve contacts with more than one entry in the lists, but it's not possible to update such a contact via the REST API."]
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct PhoneNumbers {
     #[doc = "A list of phone numbers. Each entry is of type string and contains a phone number."]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -260,4 +328,9 @@ pub struct PhoneNumbers {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
     pub other: Option<Vec<String>>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
@@ -2,7 +2,7 @@
 use serde::{Deserialize, Serialize};
 use typed_builder::TypedBuilder;
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
+#[serde(remote = "Self")]
 pub enum TaxClassification {
     #[serde(rename = "de")]
     De,
@@ -10,6 +10,9 @@ pub enum TaxClassification {
     IntraCommunity,
     #[serde(rename = "thirdPartyCountry")]
     ThirdPartyCountry,
+    #[doc = "A value that is not known to this crate"]
+    #[serde(skip)]
+    Unknown(String),
 }
 impl std::str::FromStr for TaxClassification {
     type Err = serde_plain::Error;
@@ -17,9 +20,33 @@ impl std::str::FromStr for TaxClassification {
         serde_plain::from_str::<Self>(s)
     }
 }
+impl Serialize for TaxClassification {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Unknown(x) => serializer.serialize_str(x),
+            _ => Self::serialize(self, serializer),
+        }
+    }
+}
+impl<'de> Deserialize<'de> for TaxClassification {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde::lenient_enum::deserialize(
+            deserializer,
+            Self::deserialize,
+            Self::Unknown,
+        )
+    }
+}
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct Country {
     #[doc = "The country's code. See [our FAQ](https://developers.lexoffice.io/docs/#faq-country-codes) for specification."]
     #[builder(setter(into))]
@@ -35,4 +62,9 @@ pub struct Country {
     #[doc = "Tax classification. Possible values are **de** (*Germany*), **intraCommunity** (eligible for *Innergemeinschaftliche Lieferung*), and **thirdPartyCountry** (other). See [below](https://developers.lexoffice.io/docs/#countries-endpoint-country-tax-classification)"]
     #[builder(setter(into))]
     pub tax_classification: TaxClassification,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
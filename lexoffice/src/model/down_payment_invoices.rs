@@ -3,7 +3,7 @@ use crate::model::invoices::ShippingConditions;
 use serde::{Deserialize, Serialize};
 use typed_builder::TypedBuilder;
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
+#[serde(remote = "Self")]
 pub enum TaxType {
     #[serde(rename = "constructionService13b")]
     ConstructionService13b,
@@ -21,6 +21,9 @@ pub enum TaxType {
     ThirdPartyCountryService,
     #[serde(rename = "vatfree")]
     Vatfree,
+    #[doc = "A value that is not known to this crate"]
+    #[serde(skip)]
+    Unknown(String),
 }
 impl std::str::FromStr for TaxType {
     type Err = serde_plain::Error;
@@ -28,8 +31,31 @@ impl std::str::FromStr for TaxType {
         serde_plain::from_str::<Self>(s)
     }
 }
+impl Serialize for TaxType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Unknown(x) => serializer.serialize_str(x),
+            _ => Self::serialize(self, serializer),
+        }
+    }
+}
+impl<'de> Deserialize<'de> for TaxType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde::lenient_enum::deserialize(
+            deserializer,
+            Self::deserialize,
+            Self::Unknown,
+        )
+    }
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
+#[serde(remote = "Self")]
 pub enum Type {
     #[serde(rename = "custom")]
     Custom,
@@ -39,6 +65,9 @@ pub enum Type {
     Service,
     #[serde(rename = "text")]
     Text,
+    #[doc = "A value that is not known to this crate"]
+    #[serde(skip)]
+    Unknown(String),
 }
 impl std::str::FromStr for Type {
     type Err = serde_plain::Error;
@@ -46,13 +75,39 @@ impl std::str::FromStr for Type {
         serde_plain::from_str::<Self>(s)
     }
 }
+impl Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Unknown(x) => serializer.serialize_str(x),
+            _ => Self::serialize(self, serializer),
+        }
+    }
+}
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde::lenient_enum::deserialize(
+            deserializer,
+            Self::deserialize,
+            Self::Unknown,
+        )
+    }
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
+#[serde(remote = "Self")]
 pub enum TaxSubType {
     #[serde(rename = "distanceSales")]
     DistanceSales,
     #[serde(rename = "electronicServices")]
     ElectronicServices,
+    #[doc = "A value that is not known to this crate"]
+    #[serde(skip)]
+    Unknown(String),
 }
 impl std::str::FromStr for TaxSubType {
     type Err = serde_plain::Error;
@@ -60,8 +115,31 @@ impl std::str::FromStr for TaxSubType {
         serde_plain::from_str::<Self>(s)
     }
 }
+impl Serialize for TaxSubType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Unknown(x) => serializer.serialize_str(x),
+            _ => Self::serialize(self, serializer),
+        }
+    }
+}
+impl<'de> Deserialize<'de> for TaxSubType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde::lenient_enum::deserialize(
+            deserializer,
+            Self::deserialize,
+            Self::Unknown,
+        )
+    }
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
+#[serde(remote = "Self")]
 pub enum VoucherStatus {
     #[serde(rename = "draft")]
     Draft,
@@ -71,6 +149,9 @@ pub enum VoucherStatus {
     Paid,
     #[serde(rename = "voided")]
     Voided,
+    #[doc = "A value that is not known to this crate"]
+    #[serde(skip)]
+    Unknown(String),
 }
 impl std::str::FromStr for VoucherStatus {
     type Err = serde_plain::Error;
@@ -78,10 +159,34 @@ impl std::str::FromStr for VoucherStatus {
         serde_plain::from_str::<Self>(s)
     }
 }
+impl Serialize for VoucherStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Unknown(x) => serializer.serialize_str(x),
+            _ => Self::serialize(self, serializer),
+        }
+    }
+}
+impl<'de> Deserialize<'de> for VoucherStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde::lenient_enum::deserialize(
+            deserializer,
+            Self::deserialize,
+            Self::Unknown,
+        )
+    }
+}
 #[doc = "Most properties of down payment invoices are identical with the ones of regular invoices.\n\n```json\n{\n  \"id\": \"0333f0c7-2b89-4889-b64e-68b3ca3f167a\",\n  \"organizationId\": \"aa93e8a8-2aa3-470b-b914-caad8a255dd8\",\n  \"createdDate\": \"2021-01-20T10:26:40.956+01:00\",\n  \"updatedDate\": \"2021-01-21T13:34:13.228+01:00\",\n  \"version\": 3,\n  \"language\": \"de\",\n  \"archived\": false,\n  \"voucherStatus\": \"open\",\n  \"voucherNumber\": \"RE1129\",\n  \"voucherDate\": \"2021-01-20T10:26:26.565+01:00\",\n  \"dueDate\": \"2021-02-19T00:00:00.000+01:00\",\n  \"address\": {\n    \"name\": \"Bike & Ride GmbH & Co. KG\",\n    \"supplement\": \"Gebäude 10\",\n    \"street\": \"Musterstraße 42\",\n    \"city\": \"Freiburg\",\n    \"zip\": \"79112\",\n    \"countryCode\": \"DE\"\n  },\n  \"lineItems\": [\n    {\n      \"type\": \"custom\",\n      \"name\": \"Pauschaler Abschlag\",\n      \"quantity\": 1,\n      \"unitPrice\": {\n        \"currency\": \"EUR\",\n        \"netAmount\": 559.66,\n        \"grossAmount\": 666,\n        \"taxRatePercentage\": 19\n      },\n      \"lineItemAmount\": 666.00\n    }\n  ],\n  \"totalPrice\": {\n    \"currency\": \"EUR\",\n    \"totalNetAmount\": 559.66,\n    \"totalGrossAmount\": 666.00,\n    \"totalTaxAmount\": 106.34\n  },\n  \"taxAmounts\": [\n    {\n      \"taxRatePercentage\": 19,\n      \"taxAmount\": 106.34,\n      \"netAmount\": 559.66\n    }\n  ],\n  \"taxConditions\": {\n    \"taxType\": \"gross\"\n  },\n  \"paymentConditions\": {\n    \"paymentTermLabel\": \"10 Tage - 3 %, 30 Tage netto\",\n    \"paymentTermLabelTemplate\": \"{discountRange} Tage -{discount}, {paymentRange} Tage netto\",\n    \"paymentTermDuration\": 30,\n    \"paymentDiscountConditions\": {\n      \"discountPercentage\": 3,\n      \"discountRange\": 10\n    }\n  },\n  \"shippingConditions\": {\n    \"shippingType\": \"none\"\n  },\n  \"closingInvoiceId\": null,\n  \"relatedVouchers\": [],\n  \"introduction\": \"Wie vereinbart, erlauben wir uns folgenden pauschalen Abschlag in Rechnung zu stellen.\",\n  \"remark\": \"Vielen Dank für die gute Zusammenarbeit.\",\n  \"files\": {\n    \"documentFileId\": \"aa0388c5-20b5-49d7-96ce-0c08ac0482f4\"\n  },\n  \"title\": \"1. Abschlagsrechnung\"\n}\n\n```\n\nAll attributes are read-only, as down payment invoices cannot be created or modified by means of the API."]
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct DownPaymentInvoice {
     #[doc = "Unique id generated on creation by lexoffice."]
     #[builder(default, setter(skip))]
@@ -97,6 +202,7 @@ pub struct DownPaymentInvoice {
     #[builder(default, setter(skip))]
     pub updated_date: crate::marker::ReadOnly<crate::types::DateTime>,
     #[doc = "Version *(revision)* number which will be increased on each change to handle [optimistic locking](https://developers.lexoffice.io/docs/#optimistic-locking).  \n*Read-only.*"]
+    #[serde(default)]
     #[builder(default, setter(skip))]
     pub version: i64,
     #[doc = "Specifies the language of the down payment invoice which affects the print document but also set translated default text modules when no values are send (e.g. for introduction). Values accepted in ISO 639-1 code. Possible values are German **de** (default) and English **en**."]
@@ -173,6 +279,11 @@ pub struct DownPaymentInvoice {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
     pub files: Option<Files>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 impl crate::request::HasId for DownPaymentInvoice {
     fn id(&self) -> &crate::marker::ReadOnly<uuid::Uuid> {
@@ -182,7 +293,8 @@ impl crate::request::HasId for DownPaymentInvoice {
 #[doc = "There are two main options to address the recipient of a down payment invoice. First, using an existing lexoffice contact or second, creating a new address.\n\nFor **referencing an existing contact** it is only necessary to provide the UUID of that contact. Usually the billing address is used (for delivery notes, the shipping address will be preferred). Additionally, the referenced address can also be modified for this specific down payment invoice. This can be done by setting all required address fields and this deviated address will not be stored back to the lexoffice contacts.\n\nThe referenced contact needs to have the role customer. For more information please refer to the [contacts endpoint](https://developers.lexoffice.io/docs/#contacts-endpoint).\n\nOtherwise, a **new address** for the down payment invoice recipient can be created. That type of address is called a \"one-time address\". A one-time address will not create a new contact in lexoffice. For instance, this could be useful when it is not needed to create a contact in lexoffice for each new down payment invoice.\n\nPlease get in touch with us if you are not sure which option fits your use case best."]
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct Address {
     #[doc = "If the down payment invoice recipient is (optionally) registered as a contact in lexoffice, this field specifies the related id of the contact."]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -215,10 +327,16 @@ pub struct Address {
     #[doc = "The contact person selected while editing the voucher. The primary contact person will be used when creating vouchers via the API with a referenced `contactId`.  \n*Read-only.*"]
     #[builder(default, setter(skip))]
     pub contact_person: crate::marker::ReadOnly<String>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct LineItems {
     #[doc = "The field specifies the related id of the product/service.   \n*Read-only.*"]
     #[builder(default, setter(skip))]
@@ -253,7 +371,12 @@ pub struct LineItems {
     pub discount_percentage: Option<f64>,
     #[doc = "The total price of this line item. Depending by the selected *taxType* in *taxConditions*, the amount must be given either as net or gross. The value can contain up to 2 decimals.   \n*Read-only.*"]
     #[builder(default, setter(skip))]
-    pub line_item_amount: crate::marker::ReadOnly<f64>,
+    pub line_item_amount: crate::marker::ReadOnly<crate::types::Amount>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 impl crate::request::HasId for LineItems {
     fn id(&self) -> &crate::marker::ReadOnly<uuid::Uuid> {
@@ -262,7 +385,8 @@ impl crate::request::HasId for LineItems {
 }
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct UnitPrice {
     #[doc = "The currency of the price. Currently only **EUR** is supported."]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -271,19 +395,25 @@ pub struct UnitPrice {
     #[doc = "The net price of the unit price. The value can contain up to 4 decimals."]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
-    pub net_amount: Option<f64>,
+    pub net_amount: Option<crate::types::Amount>,
     #[doc = "The gross price of the unit price. The value can contain up to 4 decimals."]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
-    pub gross_amount: Option<f64>,
+    pub gross_amount: Option<crate::types::Amount>,
     #[doc = "The tax rate of the unit price. See [the \"Supported tax rates\" FAQ](https://developers.lexoffice.io/docs/#faq-valid-tax-rates) for more information and a list of possible values.. For vat-free sales vouchers the tax rate percentage must be **0**."]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
     pub tax_rate_percentage: Option<f64>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct TotalPrice {
     #[doc = "The currency of the total price. Currently only **EUR** is supported."]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -291,25 +421,31 @@ pub struct TotalPrice {
     pub currency: Option<crate::types::Currency>,
     #[doc = "The total net price over all line items. The value can contain up to 2 decimals.   \n*Read-only.*"]
     #[builder(default, setter(skip))]
-    pub total_net_amount: crate::marker::ReadOnly<f64>,
+    pub total_net_amount: crate::marker::ReadOnly<crate::types::Amount>,
     #[doc = "The total gross price over all line items. The value can contain up to 2 decimals.   \n*Read-only.*"]
     #[builder(default, setter(skip))]
-    pub total_gross_amount: crate::marker::ReadOnly<f64>,
+    pub total_gross_amount: crate::marker::ReadOnly<crate::types::Amount>,
     #[doc = "The total tax amount over all line items. The value can contain up to 2 decimals.   \n*Read-only.*"]
     #[builder(default, setter(skip))]
-    pub total_tax_amount: crate::marker::ReadOnly<f64>,
+    pub total_tax_amount: crate::marker::ReadOnly<crate::types::Amount>,
     #[doc = "(Optional) A total discount as absolute value. The value can contain up to 2 decimals."]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
-    pub total_discount_absolute: Option<f64>,
+    pub total_discount_absolute: Option<crate::types::Amount>,
     #[doc = "(Optional) A total discount relative to the gross amount or net amount dependent on the given tax conditions. A contact-specific default will be set if available and no total discount was send. The value can contain up to 2 decimals."]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
     pub total_discount_percentage: Option<f64>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct TaxAmounts {
     #[doc = "Tax rate as percentage value. See [the \"Supported tax rates\" FAQ](https://developers.lexoffice.io/docs/#faq-valid-tax-rates) for more information and a list of possible values.."]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -318,16 +454,22 @@ pub struct TaxAmounts {
     #[doc = "The total tax amount for this tax rate. The value can contain up to 2 decimals."]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
-    pub tax_amount: Option<f64>,
+    pub tax_amount: Option<crate::types::Amount>,
     #[doc = "The total net amount for this tax rate. The value can contain up to 2 decimals."]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
-    pub net_amount: Option<f64>,
+    pub net_amount: Option<crate::types::Amount>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 #[doc = "```json\n\"taxConditions\": {\n    \"taxType\": \"constructionService13b\",\n    \"taxTypeNote\": \"Steuerschuldnerschaft des Leistungsempfängers (Reverse Charge)\"\n}\n\n```"]
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct TaxConditions {
     #[doc = "The tax type for the down payment invoice. Possible values are **net**, **gross**, **vatfree** (*Steuerfrei*), **intraCommunitySupply** (*Innergemeinschaftliche Lieferung gem. §13b UStG*), **constructionService13b** (*Bauleistungen gem. §13b UStG*), **externalService13b** (*Fremdleistungen innerhalb der EU gem. §13b UStG*), **thirdPartyCountryService** (*Dienstleistungen an Drittländer*), and **thirdPartyCountryDelivery** (*Ausfuhrlieferungen an Drittländer*)"]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -341,11 +483,17 @@ pub struct TaxConditions {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
     pub tax_type_note: Option<String>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 #[doc = "The payment conditions are optional and the organization's or contact-specific defaults will be used if ommitted."]
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct PaymentConditions {
     #[doc = "A textual note regarding the payment conditions."]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -358,11 +506,17 @@ pub struct PaymentConditions {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
     pub payment_term_duration: Option<i64>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 #[doc = "The *relatedVouchers* property documents all existing voucher relations for the current sales voucher. If no related vouchers exist, an empty list will be returned."]
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct RelatedVouchers {
     #[doc = "The related sales voucher's unique id."]
     #[builder(default, setter(skip))]
@@ -374,6 +528,11 @@ pub struct RelatedVouchers {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
     pub voucher_type: Option<String>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 impl crate::request::HasId for RelatedVouchers {
     fn id(&self) -> &crate::marker::ReadOnly<uuid::Uuid> {
@@ -382,10 +541,16 @@ impl crate::request::HasId for RelatedVouchers {
 }
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct Files {
     #[doc = "The id of the down payment invoice PDF. The PDF will be created when the invoice turns from **draft** into status **open**. To download the invoice PDF file please use the files endpoint."]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
     pub document_file_id: Option<uuid::Uuid>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
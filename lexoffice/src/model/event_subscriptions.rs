@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use typed_builder::TypedBuilder;
 #[doc = "The following table lists all types of events you can subscribe to. The property *EventType* is the combined key of a resource and a event name. The *EventType* is handled in lower case."]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
+#[serde(remote = "Self")]
 pub enum EventType {
     #[doc = "A lexoffice contact has changed. You should get the updated contact details."]
     #[serde(rename = "contact.changed")]
@@ -119,6 +119,9 @@ pub enum EventType {
     #[doc = "The status of a voucher has changed. Get the updated voucher by calling the [vouchers endpoint](https://developers.lexoffice.io/docs/#vouchers-endpoint)."]
     #[serde(rename = "voucher.status.changed")]
     VoucherStatusChanged,
+    #[doc = "A value that is not known to this crate"]
+    #[serde(skip)]
+    Unknown(String),
 }
 impl std::str::FromStr for EventType {
     type Err = serde_plain::Error;
@@ -126,10 +129,34 @@ impl std::str::FromStr for EventType {
         serde_plain::from_str::<Self>(s)
     }
 }
+impl Serialize for EventType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Unknown(x) => serializer.serialize_str(x),
+            _ => Self::serialize(self, serializer),
+        }
+    }
+}
+impl<'de> Deserialize<'de> for EventType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde::lenient_enum::deserialize(
+            deserializer,
+            Self::deserialize,
+            Self::Unknown,
+        )
+    }
+}
 #[doc = "```json\n{\n  \"subscriptionId\": \"4d43ad14-671d-4e0c-fd4b-2fd8cc117eff\",\n  \"organizationId\": \"aa93e8a8-2aa3-470b-b914-caad8a255dd8\",\n  \"createdDate\": \"2018-04-11T12:15:00.000+02:00\",\n  \"eventType\": \"contact.changed\",\n  \"callbackUrl\": \"https://example.org/webhook\"\n}\n\n```"]
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct EventSubscription {
     #[doc = "Unique id of the event subscription generated on creation by lexoffice.   \n*Read-only.*"]
     #[builder(default, setter(skip))]
@@ -146,6 +173,11 @@ pub struct EventSubscription {
     #[doc = "When a resource entity triggers an event, the callback url is used to notify the subscriber about it. The payload of the callback is described in [Webhook Callback Properties](https://developers.lexoffice.io/docs/#event-subscriptions-endpoint-webhook-callback-properties)."]
     #[builder(setter(into))]
     pub callback_url: String,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 impl crate::request::HasId for EventSubscription {
     fn id(&self) -> &crate::marker::ReadOnly<uuid::Uuid> {
@@ -155,7 +187,8 @@ impl crate::request::HasId for EventSubscription {
 #[doc = "```json\n{\n  \"organizationId\": \"aa93e8a8-2aa3-470b-b914-caad8a255dd8\",\n  \"eventType\": \"contact.changed\",\n  \"resourceId\": \"4d43ad14-671d-4e0c-fd4b-2fd8cc117eff\",\n  \"eventDate\": \"2018-04-11T12:30:00.000+02:00\"\n}\n\n```\n\nSubscribed events will send a POST request to your given webhook url and contain the following JSON payload."]
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct WebhookCallback {
     #[doc = "The organization for which an event has been triggered."]
     #[builder(setter(into))]
@@ -169,4 +202,9 @@ pub struct WebhookCallback {
     #[doc = "The instant of time when the event was triggered in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*).  \n*Read-only.*"]
     #[builder(default, setter(skip))]
     pub event_date: crate::marker::ReadOnly<crate::types::DateTime>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
@@ -2,7 +2,7 @@
 use serde::{Deserialize, Serialize};
 use typed_builder::TypedBuilder;
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
+#[serde(remote = "Self")]
 pub enum TaxType {
     #[serde(rename = "constructionService13b")]
     ConstructionService13b,
@@ -20,6 +20,9 @@ pub enum TaxType {
     ThirdPartyCountryService,
     #[serde(rename = "vatfree")]
     Vatfree,
+    #[doc = "A value that is not known to this crate"]
+    #[serde(skip)]
+    Unknown(String),
 }
 impl std::str::FromStr for TaxType {
     type Err = serde_plain::Error;
@@ -27,8 +30,31 @@ impl std::str::FromStr for TaxType {
         serde_plain::from_str::<Self>(s)
     }
 }
+impl Serialize for TaxType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Unknown(x) => serializer.serialize_str(x),
+            _ => Self::serialize(self, serializer),
+        }
+    }
+}
+impl<'de> Deserialize<'de> for TaxType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde::lenient_enum::deserialize(
+            deserializer,
+            Self::deserialize,
+            Self::Unknown,
+        )
+    }
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
+#[serde(remote = "Self")]
 pub enum Type {
     #[serde(rename = "custom")]
     Custom,
@@ -38,6 +64,9 @@ pub enum Type {
     Service,
     #[serde(rename = "text")]
     Text,
+    #[doc = "A value that is not known to this crate"]
+    #[serde(skip)]
+    Unknown(String),
 }
 impl std::str::FromStr for Type {
     type Err = serde_plain::Error;
@@ -45,8 +74,31 @@ impl std::str::FromStr for Type {
         serde_plain::from_str::<Self>(s)
     }
 }
+impl Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Unknown(x) => serializer.serialize_str(x),
+            _ => Self::serialize(self, serializer),
+        }
+    }
+}
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde::lenient_enum::deserialize(
+            deserializer,
+            Self::deserialize,
+            Self::Unknown,
+        )
+    }
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
+#[serde(remote = "Self")]
 pub enum ShippingType {
     #[serde(rename = "delivery")]
     Delivery,
@@ -58,6 +110,9 @@ pub enum ShippingType {
     Service,
     #[serde(rename = "serviceperiod")]
     Serviceperiod,
+    #[doc = "A value that is not known to this crate"]
+    #[serde(skip)]
+    Unknown(String),
 }
 impl std::str::FromStr for ShippingType {
     type Err = serde_plain::Error;
@@ -65,13 +120,39 @@ impl std::str::FromStr for ShippingType {
         serde_plain::from_str::<Self>(s)
     }
 }
+impl Serialize for ShippingType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Unknown(x) => serializer.serialize_str(x),
+            _ => Self::serialize(self, serializer),
+        }
+    }
+}
+impl<'de> Deserialize<'de> for ShippingType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde::lenient_enum::deserialize(
+            deserializer,
+            Self::deserialize,
+            Self::Unknown,
+        )
+    }
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
+#[serde(remote = "Self")]
 pub enum TaxSubType {
     #[serde(rename = "distanceSales")]
     DistanceSales,
     #[serde(rename = "electronicServices")]
     ElectronicServices,
+    #[doc = "A value that is not known to this crate"]
+    #[serde(skip)]
+    Unknown(String),
 }
 impl std::str::FromStr for TaxSubType {
     type Err = serde_plain::Error;
@@ -79,8 +160,31 @@ impl std::str::FromStr for TaxSubType {
         serde_plain::from_str::<Self>(s)
     }
 }
+impl Serialize for TaxSubType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Unknown(x) => serializer.serialize_str(x),
+            _ => Self::serialize(self, serializer),
+        }
+    }
+}
+impl<'de> Deserialize<'de> for TaxSubType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde::lenient_enum::deserialize(
+            deserializer,
+            Self::deserialize,
+            Self::Unknown,
+        )
+    }
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
+#[serde(remote = "Self")]
 pub enum VoucherStatus {
     #[serde(rename = "draft")]
     Draft,
@@ -90,6 +194,9 @@ pub enum VoucherStatus {
     Paid,
     #[serde(rename = "voided")]
     Voided,
+    #[doc = "A value that is not known to this crate"]
+    #[serde(skip)]
+    Unknown(String),
 }
 impl std::str::FromStr for VoucherStatus {
     type Err = serde_plain::Error;
@@ -97,10 +204,34 @@ impl std::str::FromStr for VoucherStatus {
         serde_plain::from_str::<Self>(s)
     }
 }
+impl Serialize for VoucherStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Unknown(x) => serializer.serialize_str(x),
+            _ => Self::serialize(self, serializer),
+        }
+    }
+}
+impl<'de> Deserialize<'de> for VoucherStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde::lenient_enum::deserialize(
+            deserializer,
+            Self::deserialize,
+            Self::Unknown,
+        )
+    }
+}
 #[doc = "```json\n{\n   \"id\":\"e9066f04-8cc7-4616-93f8-ac9ecc8479c8\",\n   \"organizationId\":\"aa93e8a8-2aa3-470b-b914-caad8a255dd8\",\n   \"createdDate\":\"2017-04-24T08:20:22.528+02:00\",\n   \"updatedDate\":\"2017-04-24T08:20:22.528+02:00\",\n   \"version\":0,\n   \"language\":\"de\",\n   \"archived\":false,\n   \"voucherStatus\":\"draft\",\n   \"voucherNumber\":\"RE1019\",\n   \"voucherDate\":\"2017-02-22T00:00:00.000+01:00\",\n   \"dueDate\":null,\n   \"address\":{\n      \"contactId\":null,\n      \"name\":\"Bike & Ride GmbH & Co. KG\",\n      \"supplement\":\"Gebäude 10\",\n      \"street\":\"Musterstraße 42\",\n      \"city\":\"Freiburg\",\n      \"zip\":\"79112\",\n      \"countryCode\":\"DE\"\n   },\n   \"xRechnung\":null,\n   \"lineItems\":[\n      {\n         \"id\":\"97b98491-e953-4dc9-97a9-ae437a8052b4\",\n         \"type\":\"material\",\n         \"name\":\"Abus Kabelschloss Primo 590 \",\n         \"description\":\"· 9,5 mm starkes, smoke-mattes Spiralkabel mit integrierter Halterlösung zur Befestigung am Sattelklemmbolzen · bewährter Qualitäts-Schließzylinder mit praktischem Wendeschlüssel · KabelØ: 9,5 mm, Länge: 150 cm\",\n         \"quantity\":2,\n         \"unitName\":\"Stück\",\n         \"unitPrice\":{\n            \"currency\":\"EUR\",\n            \"netAmount\":13.4,\n            \"grossAmount\":15.95,\n            \"taxRatePercentage\":19\n         },\n         \"discountPercentage\":50,\n         \"lineItemAmount\":13.4\n      },\n      {\n         \"id\":\"dc4c805b-7df1-4310-a548-22be4499eb04\",\n         \"type\":\"service\",\n         \"name\":\"Aufwändige Montage\",\n         \"description\":\"Aufwand für arbeitsintensive Montagetätigkeit\",\n         \"quantity\":1,\n         \"unitName\":\"Stunde\",\n         \"unitPrice\":{\n            \"currency\":\"EUR\",\n            \"netAmount\":8.32,\n            \"grossAmount\":8.9,\n            \"taxRatePercentage\":7\n         },\n         \"discountPercentage\":0,\n         \"lineItemAmount\":8.32\n      },\n      {\n         \"id\":null,\n         \"type\":\"custom\",\n         \"name\":\"Energieriegel Testpaket\",\n         \"description\":null,\n         \"quantity\":1,\n         \"unitName\":\"Stück\",\n         \"unitPrice\":{\n            \"currency\":\"EUR\",\n            \"netAmount\":5,\n            \"grossAmount\":5,\n            \"taxRatePercentage\":0\n         },\n         \"discountPercentage\":0,\n         \"lineItemAmount\":5\n      },\n      {\n         \"type\":\"text\",\n         \"name\":\"Freitextposition\",\n         \"description\":\"This item type can contain either a name or a description or both.\"\n      }\n   ],\n   \"totalPrice\":{\n      \"currency\":\"EUR\",\n      \"totalNetAmount\":26.72,\n      \"totalGrossAmount\":29.85,\n      \"totalTaxAmount\":3.13,\n      \"totalDiscountAbsolute\":null,\n      \"totalDiscountPercentage\":null\n   },\n   \"taxAmounts\":[\n      {\n         \"taxRatePercentage\":0,\n         \"taxAmount\":0,\n         \"netAmount\":5\n      },\n      {\n         \"taxRatePercentage\":7,\n         \"taxAmount\":0.58,\n         \"netAmount\":8.32\n      },\n      {\n         \"taxRatePercentage\":19,\n         \"taxAmount\":2.55,\n         \"netAmount\":13.4\n      }\n   ],\n   \"taxConditions\":{\n      \"taxType\":\"net\",\n      \"taxTypeNote\":null\n   },\n   \"paymentConditions\":{\n      \"paymentTermLabel\":\"10 Tage - 3 %, 30 Tage netto\",\n      \"paymentTermLabelTemplate\":\"{discountRange} Tage -{discount}, {paymentRange} Tage netto\",\n      \"paymentTermDuration\":30,\n      \"paymentDiscountConditions\":{\n         \"discountPercentage\":3,\n         \"discountRange\":10\n      }\n   },\n   \"shippingConditions\":{\n      \"shippingDate\":\"2017-04-22T00:00:00.000+02:00\",\n      \"shippingEndDate\":null,\n      \"shippingType\":\"delivery\"\n   },\n   \"closingInvoice\":false,\n   \"claimedGrossAmount\":null,\n   \"downPaymentDeductions\":null,\n   \"recurringTemplateId\":null,\n   \"relatedVouchers\":[],\n   \"title\":\"Rechnung\",\n   \"introduction\":\"Ihre bestellten Positionen stellen wir Ihnen hiermit in Rechnung\",\n   \"remark\":\"Vielen Dank für Ihren Einkauf\",\n   \"files\":{\n      \"documentFileId\":\"75295db7-7e69-4630-befd-a7f4ddfdaa83\"\n   }\n}\n\n```"]
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct Invoice {
     #[doc = "Unique id generated on creation by lexoffice.   \n*Read-only.*"]
     #[builder(default, setter(skip))]
@@ -115,6 +246,7 @@ pub struct Invoice {
     #[builder(default, setter(skip))]
     pub updated_date: crate::marker::ReadOnly<crate::types::DateTime>,
     #[doc = "Version *(revision)* number which will be increased on each change to handle [optimistic locking](https://developers.lexoffice.io/docs/#optimistic-locking).  \n*Read-only.*"]
+    #[serde(default)]
     #[builder(default, setter(skip))]
     pub version: i64,
     #[doc = "Specifies the language of the invoice which affects the print document but also set translated default text modules when no values are send (e.g. for introduction). Values accepted in ISO 639-1 code. Possible values are German **de** (default) and English **en**."]
@@ -173,7 +305,7 @@ pub struct Invoice {
     pub closing_invoice: crate::marker::ReadOnly<bool>,
     #[doc = "The remaining gross amount (see [description below](https://developers.lexoffice.io/docs/#invoices-endpoint-closing-invoices))  \n*Read-only.*"]
     #[builder(default, setter(skip))]
-    pub claimed_gross_amount: crate::marker::ReadOnly<f64>,
+    pub claimed_gross_amount: crate::marker::ReadOnly<crate::types::Amount>,
     #[doc = "The down payments connected to this closing invoice.  \n*Read-only.*"]
     #[builder(default, setter(skip))]
     pub down_payment_deductions:
@@ -200,6 +332,11 @@ pub struct Invoice {
     #[doc = "The document id for the PDF version of the invoice. For details see below.   \n*Read-only.*"]
     #[builder(default, setter(skip))]
     pub files: crate::marker::ReadOnly<Files>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 impl crate::request::HasId for Invoice {
     fn id(&self) -> &crate::marker::ReadOnly<uuid::Uuid> {
@@ -209,7 +346,8 @@ impl crate::request::HasId for Invoice {
 #[doc = "There are two main options to address the recipient of an invoice. First, using an existing lexoffice contact or second, creating a new address.\n\nFor **referencing an existing contact** it is only necessary to provide the UUID of that contact. Usually the billing address is used (for delivery notes, the shipping address will be preferred). Additionally, the referenced address can also be modified for this specific invoice. This can be done by setting all required address fields and this deviated address will not be stored back to the lexoffice contacts.\n\nThe referenced contact needs to have the role customer. For more information please refer to the [contacts endpoint](https://developers.lexoffice.io/docs/#contacts-endpoint).\n\nOtherwise, a **new address** for the invoice recipient can be created. That type of address is called a \"one-time address\". A one-time address will not create a new contact in lexoffice. For instance, this could be useful when it is not needed to create a contact in lexoffice for each new invoice.\n\nPlease get in touch with us if you are not sure which option fits your use case best."]
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct Address {
     #[doc = "If the invoice recipient is (optionally) registered as a contact in lexoffice, this field specifies the related id of the contact."]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -242,10 +380,16 @@ pub struct Address {
     #[doc = "The contact person selected while editing the voucher. The primary contact person will be used when creating vouchers via the API with a referenced `contactId`.  \n*Read-only.*"]
     #[builder(default, setter(skip))]
     pub contact_person: crate::marker::ReadOnly<String>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct LineItems {
     #[doc = "The field specifies the related id of the product/service.   \n*Read-only.*"]
     #[builder(default, setter(skip))]
@@ -280,7 +424,12 @@ pub struct LineItems {
     pub discount_percentage: Option<f64>,
     #[doc = "The total price of this line item. Depending by the selected *taxType* in *taxConditions*, the amount must be given either as net or gross. The value can contain up to 2 decimals.   \n*Read-only.*"]
     #[builder(default, setter(skip))]
-    pub line_item_amount: crate::marker::ReadOnly<f64>,
+    pub line_item_amount: crate::marker::ReadOnly<crate::types::Amount>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 impl crate::request::HasId for LineItems {
     fn id(&self) -> &crate::marker::ReadOnly<uuid::Uuid> {
@@ -289,7 +438,8 @@ impl crate::request::HasId for LineItems {
 }
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct UnitPrice {
     #[doc = "The currency of the price. Currently only **EUR** is supported."]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -298,19 +448,25 @@ pub struct UnitPrice {
     #[doc = "The net price of the unit price. The value can contain up to 4 decimals."]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
-    pub net_amount: Option<f64>,
+    pub net_amount: Option<crate::types::Amount>,
     #[doc = "The gross price of the unit price. The value can contain up to 4 decimals."]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
-    pub gross_amount: Option<f64>,
+    pub gross_amount: Option<crate::types::Amount>,
     #[doc = "The tax rate of the unit price. See [the \"Supported tax rates\" FAQ](https://developers.lexoffice.io/docs/#faq-valid-tax-rates) for more information and a list of possible values.. For vat-free sales vouchers the tax rate percentage must be **0**."]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
     pub tax_rate_percentage: Option<f64>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct TotalPrice {
     #[doc = "The currency of the total price. Currently only **EUR** is supported."]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -318,25 +474,31 @@ pub struct TotalPrice {
     pub currency: Option<crate::types::Currency>,
     #[doc = "The total net price over all line items. The value can contain up to 2 decimals.   \n*Read-only.*"]
     #[builder(default, setter(skip))]
-    pub total_net_amount: crate::marker::ReadOnly<f64>,
+    pub total_net_amount: crate::marker::ReadOnly<crate::types::Amount>,
     #[doc = "The total gross price over all line items. The value can contain up to 2 decimals.   \n*Read-only.*"]
     #[builder(default, setter(skip))]
-    pub total_gross_amount: crate::marker::ReadOnly<f64>,
+    pub total_gross_amount: crate::marker::ReadOnly<crate::types::Amount>,
     #[doc = "The total tax amount over all line items. The value can contain up to 2 decimals.   \n*Read-only.*"]
     #[builder(default, setter(skip))]
-    pub total_tax_amount: crate::marker::ReadOnly<f64>,
+    pub total_tax_amount: crate::marker::ReadOnly<crate::types::Amount>,
     #[doc = "(Optional) A total discount as absolute value. The value can contain up to 2 decimals."]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
-    pub total_discount_absolute: Option<f64>,
+    pub total_discount_absolute: Option<crate::types::Amount>,
     #[doc = "(Optional) A total discount relative to the gross amount or net amount dependent on the given tax conditions. A contact-specific default will be set if available and no total discount was send. The value can contain up to 2 decimals."]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
     pub total_discount_percentage: Option<f64>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct TaxAmounts {
     #[doc = "Tax rate as percentage value. See [the \"Supported tax rates\" FAQ](https://developers.lexoffice.io/docs/#faq-valid-tax-rates) for more information and a list of possible values.."]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -345,16 +507,22 @@ pub struct TaxAmounts {
     #[doc = "The total tax amount for this tax rate. The value can contain up to 2 decimals."]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
-    pub tax_amount: Option<f64>,
+    pub tax_amount: Option<crate::types::Amount>,
     #[doc = "The total net amount for this tax rate. The value can contain up to 2 decimals."]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
-    pub net_amount: Option<f64>,
+    pub net_amount: Option<crate::types::Amount>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 #[doc = "```json\n\"taxConditions\": {\n    \"taxType\": \"constructionService13b\",\n    \"taxTypeNote\": \"Steuerschuldnerschaft des Leistungsempfängers (Reverse Charge)\"\n}\n\n```"]
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct TaxConditions {
     #[doc = "The tax type for the invoice. Possible values are **net**, **gross**, **vatfree** (*Steuerfrei*), **intraCommunitySupply** (*Innergemeinschaftliche Lieferung gem. §13b UStG*), **constructionService13b** (*Bauleistungen gem. §13b UStG*), **externalService13b** (*Fremdleistungen innerhalb der EU gem. §13b UStG*), **thirdPartyCountryService** (*Dienstleistungen an Drittländer*), and **thirdPartyCountryDelivery** (*Ausfuhrlieferungen an Drittländer*)"]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -368,11 +536,17 @@ pub struct TaxConditions {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
     pub tax_type_note: Option<String>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 #[doc = "The payment conditions are optional and the organization's or contact-specific defaults will be used if ommitted."]
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct PaymentConditions {
     #[doc = "A textual note regarding the payment conditions."]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -389,10 +563,16 @@ pub struct PaymentConditions {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
     pub payment_discount_conditions: Option<Vec<PaymentDiscountConditions>>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct PaymentDiscountConditions {
     #[doc = "The discount offered in return for payment within the **discountRange**. The value can contain up to 2 decimals."]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -402,10 +582,16 @@ pub struct PaymentDiscountConditions {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
     pub discount_range: Option<i64>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct ShippingConditions {
     #[doc = "The instant of time when the purchased items have to be shipped. Value in format `yyyy-MM-ddTHH:mm:ss.SSSXXX` as described in RFC 3339/ISO 8601 (e.g. *2020-02-21T00:00:00.000+01:00*)."]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -419,11 +605,17 @@ pub struct ShippingConditions {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
     pub shipping_type: Option<ShippingType>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 #[doc = "The *relatedVouchers* property documents all existing voucher relations for the current sales voucher. If no related vouchers exist, an empty list will be returned."]
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct RelatedVouchers {
     #[doc = "The related sales voucher's unique id."]
     #[builder(default, setter(skip))]
@@ -435,6 +627,11 @@ pub struct RelatedVouchers {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
     pub voucher_type: Option<String>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 impl crate::request::HasId for RelatedVouchers {
     fn id(&self) -> &crate::marker::ReadOnly<uuid::Uuid> {
@@ -444,7 +641,8 @@ impl crate::request::HasId for RelatedVouchers {
 #[doc = "Use the [Down Payment Invoices endpoint](https://developers.lexoffice.io/docs/#down-payment-invoices-endpoint) to retrieve details of a down payment invoice."]
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct DownPaymentDeductions {
     #[doc = "The down payment deduction's unique id."]
     #[builder(default, setter(skip))]
@@ -468,19 +666,24 @@ pub struct DownPaymentDeductions {
     #[doc = "The gross amount received for this down payment invoice"]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
-    pub received_gross_amount: Option<f64>,
+    pub received_gross_amount: Option<crate::types::Amount>,
     #[doc = "The net amount received for this down payment invoice"]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
-    pub received_net_amount: Option<f64>,
+    pub received_net_amount: Option<crate::types::Amount>,
     #[doc = "Tax received for this down payment invoice"]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
-    pub received_tax_amount: Option<f64>,
+    pub received_tax_amount: Option<crate::types::Amount>,
     #[doc = "The tax rate used for amount calculation in this down payment invoice"]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
     pub tax_rate_percentage: Option<f64>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 impl crate::request::HasId for DownPaymentDeductions {
     fn id(&self) -> &crate::marker::ReadOnly<uuid::Uuid> {
@@ -489,20 +692,32 @@ impl crate::request::HasId for DownPaymentDeductions {
 }
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct Files {
     #[doc = "The id of the invoice PDF. The PDF will be created when the invoice turns from **draft** into status **open**. To download the invoice PDF file please use the files endpoint."]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
     pub document_file_id: Option<uuid::Uuid>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 #[doc = "XRechnung properties are only relevant if an XRechnung enabled contact is referenced. In this case, if `xRechnung` is ommitted, the contact's buyer reference is used by default.\nIf `xRechnung` is present, `buyerReference` is a mandatory field.\n\nThe `buyerReference` (*Leitweg-ID*) stored in the referenced contact can be overwritten for a specific invoice by transmitting a different `buyerReference` during invoice creation.\nIf a buyer reference is specified, but the linked contact has no buyer reference and vendor number at the customer, request attempts are rejected with 406.\n\nIt is also possible to create a standard invoice for an XRechnung enabled contact. To do so, please set the *buyerReference* to an empty string."]
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct XRechnung {
     #[doc = "The customer's *Leitweg-ID* for XRechnung enabled invoices"]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
     pub buyer_reference: Option<String>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
@@ -3,6 +3,7 @@
 #![doc = r" documentation"]
 #![doc = r""]
 #![doc = r" See <https://developers.lexoffice.io/docs/> for more information"]
+pub mod articles;
 pub mod contacts;
 pub mod countries;
 pub mod credit_notes;
@@ -22,6 +23,7 @@ pub mod quotations;
 pub mod recurring_templates;
 pub mod voucherlist;
 pub mod vouchers;
+pub use articles::Article;
 pub use contacts::Contact;
 pub use countries::Country;
 pub use credit_notes::CreditNote;
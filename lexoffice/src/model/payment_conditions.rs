@@ -4,7 +4,8 @@ use typed_builder::TypedBuilder;
 #[doc = "The payment conditions are optional and the organization's or contact-specific defaults will be used if ommitted."]
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct PaymentCondition {
     #[doc = "The payment conditions' identifier"]
     #[builder(default, setter(skip))]
@@ -23,7 +24,12 @@ pub struct PaymentCondition {
     #[doc = "The payment discount conditions for the payment condition."]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
-    pub payment_discount_conditions: Option<Vec<PaymentDiscountConditions>>,
+    pub payment_discount_conditions: Option<PaymentDiscountConditions>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 impl crate::request::HasId for PaymentCondition {
     fn id(&self) -> &crate::marker::ReadOnly<uuid::Uuid> {
@@ -32,7 +38,8 @@ impl crate::request::HasId for PaymentCondition {
 }
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct PaymentDiscountConditions {
     #[doc = "The discount offered in return for payment within the **discountRange**. The value can contain up to 2 decimals."]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -42,4 +49,9 @@ pub struct PaymentDiscountConditions {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
     pub discount_range: Option<i64>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
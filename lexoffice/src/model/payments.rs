@@ -2,41 +2,201 @@
 use serde::{Deserialize, Serialize};
 use typed_builder::TypedBuilder;
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
-pub enum PaymentStatus {}
+#[serde(remote = "Self")]
+pub enum PaymentStatus {
+    #[serde(rename = "balanced")]
+    Balanced,
+    #[serde(rename = "openExpense")]
+    OpenExpense,
+    #[serde(rename = "openRevenue")]
+    OpenRevenue,
+    #[doc = "A value that is not known to this crate"]
+    #[serde(skip)]
+    Unknown(String),
+}
 impl std::str::FromStr for PaymentStatus {
     type Err = serde_plain::Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         serde_plain::from_str::<Self>(s)
     }
 }
+impl Serialize for PaymentStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Unknown(x) => serializer.serialize_str(x),
+            _ => Self::serialize(self, serializer),
+        }
+    }
+}
+impl<'de> Deserialize<'de> for PaymentStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde::lenient_enum::deserialize(
+            deserializer,
+            Self::deserialize,
+            Self::Unknown,
+        )
+    }
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
-pub enum VoucherStatus {}
+#[serde(remote = "Self")]
+pub enum VoucherStatus {
+    #[serde(rename = "open")]
+    Open,
+    #[serde(rename = "paid")]
+    Paid,
+    #[serde(rename = "paidoff")]
+    Paidoff,
+    #[serde(rename = "sepadebit")]
+    Sepadebit,
+    #[serde(rename = "transferred")]
+    Transferred,
+    #[serde(rename = "voided")]
+    Voided,
+    #[doc = "A value that is not known to this crate"]
+    #[serde(skip)]
+    Unknown(String),
+}
 impl std::str::FromStr for VoucherStatus {
     type Err = serde_plain::Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         serde_plain::from_str::<Self>(s)
     }
 }
+impl Serialize for VoucherStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Unknown(x) => serializer.serialize_str(x),
+            _ => Self::serialize(self, serializer),
+        }
+    }
+}
+impl<'de> Deserialize<'de> for VoucherStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde::lenient_enum::deserialize(
+            deserializer,
+            Self::deserialize,
+            Self::Unknown,
+        )
+    }
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
-pub enum VoucherType {}
+#[serde(remote = "Self")]
+pub enum VoucherType {
+    #[serde(rename = "creditnote")]
+    Creditnote,
+    #[serde(rename = "downpaymentinvoice")]
+    Downpaymentinvoice,
+    #[serde(rename = "invoice")]
+    Invoice,
+    #[serde(rename = "purchasecreditnote")]
+    Purchasecreditnote,
+    #[serde(rename = "purchaseinvoice")]
+    Purchaseinvoice,
+    #[serde(rename = "salescreditnote")]
+    Salescreditnote,
+    #[serde(rename = "salesinvoice")]
+    Salesinvoice,
+    #[doc = "A value that is not known to this crate"]
+    #[serde(skip)]
+    Unknown(String),
+}
 impl std::str::FromStr for VoucherType {
     type Err = serde_plain::Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         serde_plain::from_str::<Self>(s)
     }
 }
+impl Serialize for VoucherType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Unknown(x) => serializer.serialize_str(x),
+            _ => Self::serialize(self, serializer),
+        }
+    }
+}
+impl<'de> Deserialize<'de> for VoucherType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde::lenient_enum::deserialize(
+            deserializer,
+            Self::deserialize,
+            Self::Unknown,
+        )
+    }
+}
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub enum PaymentItemType {
+    #[serde(rename = "cashDiscount")]
+    CashDiscount,
+    #[serde(rename = "forexGain")]
+    ForexGain,
+    #[serde(rename = "forexLoss")]
+    ForexLoss,
+    #[serde(rename = "irrecoverableReceivable")]
+    IrrecoverableReceivable,
+    #[serde(rename = "manualPayment")]
+    ManualPayment,
+    #[doc = "A value that is not known to this crate"]
+    #[serde(skip)]
+    Unknown(String),
+}
+impl std::str::FromStr for PaymentItemType {
+    type Err = serde_plain::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_plain::from_str::<Self>(s)
+    }
+}
+impl Serialize for PaymentItemType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Unknown(x) => serializer.serialize_str(x),
+            _ => Self::serialize(self, serializer),
+        }
+    }
+}
+impl<'de> Deserialize<'de> for PaymentItemType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde::lenient_enum::deserialize(
+            deserializer,
+            Self::deserialize,
+            Self::Unknown,
+        )
+    }
+}
 #[doc = "```json\n{\n  \"openAmount\": 200.00,\n  \"currency\": \"EUR\",\n  \"paymentStatus\": \"openRevenue\",\n  \"voucherType\": \"invoice\",\n  \"voucherStatus\": \"open\"\n}\n\n{\n  \"openAmount\": 39.90,\n  \"paymentStatus\": \"openExpense\",\n  \"currency\": \"EUR\",\n  \"voucherType\": \"purchaseinvoice\",\n  \"voucherStatus\": \"open\"\n}\n\n{\n  \"openAmount\": 0,\n  \"currency\": \"EUR\",\n  \"paymentStatus\": \"balanced\",\n  \"voucherType\": \"purchasecreditnote\",\n  \"voucherStatus\": \"paidoff\"\n}\n\n```"]
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct Payment {
     #[doc = "Open amount. Positive value both for revenues and expenses"]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
-    pub open_amount: Option<f64>,
+    pub open_amount: Option<crate::types::Amount>,
     #[doc = "Always contains the value **EUR**, the only currently supported currency"]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
@@ -53,4 +213,44 @@ pub struct Payment {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
     pub voucher_status: Option<VoucherStatus>,
+    #[doc = "The date of the last payment. Only present if the voucher is balanced."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub paid_date: Option<crate::types::DateTime>,
+    #[doc = "The payments posted on the voucher. For details see below."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub payment_items: Option<Vec<PaymentItems>>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+#[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
+#[builder(doc)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentItems {
+    #[doc = "The type of the payment item. Possible values are **manualPayment**, **cashDiscount**, **forexGain**, **forexLoss** and **irrecoverableReceivable**"]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub payment_item_type: Option<PaymentItemType>,
+    #[doc = "The date the payment was posted"]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub posting_date: Option<crate::types::DateTime>,
+    #[doc = "The amount of the payment"]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub amount: Option<crate::types::Amount>,
+    #[doc = "The currency of the payment"]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub currency: Option<crate::types::Currency>,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
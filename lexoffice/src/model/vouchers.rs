@@ -2,12 +2,15 @@
 use serde::{Deserialize, Serialize};
 use typed_builder::TypedBuilder;
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
+#[serde(remote = "Self")]
 pub enum TaxType {
     #[serde(rename = "gross")]
     Gross,
     #[serde(rename = "net")]
     Net,
+    #[doc = "A value that is not known to this crate"]
+    #[serde(skip)]
+    Unknown(String),
 }
 impl std::str::FromStr for TaxType {
     type Err = serde_plain::Error;
@@ -15,8 +18,31 @@ impl std::str::FromStr for TaxType {
         serde_plain::from_str::<Self>(s)
     }
 }
+impl Serialize for TaxType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Unknown(x) => serializer.serialize_str(x),
+            _ => Self::serialize(self, serializer),
+        }
+    }
+}
+impl<'de> Deserialize<'de> for TaxType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde::lenient_enum::deserialize(
+            deserializer,
+            Self::deserialize,
+            Self::Unknown,
+        )
+    }
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
+#[serde(remote = "Self")]
 pub enum VoucherStatus {
     #[serde(rename = "open")]
     Open,
@@ -30,6 +56,9 @@ pub enum VoucherStatus {
     Transferred,
     #[serde(rename = "voided")]
     Voided,
+    #[doc = "A value that is not known to this crate"]
+    #[serde(skip)]
+    Unknown(String),
 }
 impl std::str::FromStr for VoucherStatus {
     type Err = serde_plain::Error;
@@ -37,8 +66,31 @@ impl std::str::FromStr for VoucherStatus {
         serde_plain::from_str::<Self>(s)
     }
 }
+impl Serialize for VoucherStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Unknown(x) => serializer.serialize_str(x),
+            _ => Self::serialize(self, serializer),
+        }
+    }
+}
+impl<'de> Deserialize<'de> for VoucherStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde::lenient_enum::deserialize(
+            deserializer,
+            Self::deserialize,
+            Self::Unknown,
+        )
+    }
+}
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
+#[serde(remote = "Self")]
 pub enum Type {
     #[serde(rename = "purchasecreditnote")]
     Purchasecreditnote,
@@ -48,6 +100,9 @@ pub enum Type {
     Salescreditnote,
     #[serde(rename = "salesinvoice")]
     Salesinvoice,
+    #[doc = "A value that is not known to this crate"]
+    #[serde(skip)]
+    Unknown(String),
 }
 impl std::str::FromStr for Type {
     type Err = serde_plain::Error;
@@ -55,10 +110,34 @@ impl std::str::FromStr for Type {
         serde_plain::from_str::<Self>(s)
     }
 }
+impl Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Unknown(x) => serializer.serialize_str(x),
+            _ => Self::serialize(self, serializer),
+        }
+    }
+}
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde::lenient_enum::deserialize(
+            deserializer,
+            Self::deserialize,
+            Self::Unknown,
+        )
+    }
+}
 #[doc = "```json\n{\n    \"id\": \"a8691b5d-2393-4317-888d-bcd5d564f7d1\",\n    \"organizationId\": \"aa93e8a8-2aa3-470b-b914-caad8a255dd8\",\n    \"type\": \"salesinvoice\",\n    \"voucherStatus\": \"open\",\n    \"voucherNumber\": \"2016-000321\",\n    \"voucherDate\": \"2016-06-30T00:00:00.000+02:00\",\n    \"shippingDate\": \"2016-07-02T00:00:00.000+02:00\",\n    \"dueDate\": \"2016-07-07T00:00:00.000+02:00\",\n    \"totalGrossAmount\": 326.00,\n    \"totalTaxAmount\": 26.00,\n    \"taxType\": \"gross\",\n    \"useCollectiveContact\": true,\n    \"remark\": \"Bestellung von Max Mustermann.\",\n    \"voucherItems\": [\n        {\n            \"amount\": 119.00,\n            \"taxAmount\": 19.00,\n            \"taxRatePercent\": 19.00,\n            \"categoryId\": \"8f8664a8-fd86-11e1-a21f-0800200c9a66\"\n        },\n        {\n            \"amount\": 107.00,\n            \"taxAmount\": 7.00,\n            \"taxRatePercent\": 7.00,\n            \"categoryId\": \"8f8664a8-fd86-11e1-a21f-0800200c9a66\"\n        },\n        {\n            \"amount\": 100.00,\n            \"taxAmount\": 0,\n            \"taxRatePercent\": 0.00,\n            \"categoryId\": \"8f8664a8-fd86-11e1-a21f-0800200c9a66\"\n        }\n    ],\n    \"files\": [],\n    \"createdDate\": \"2016-06-30T13:28:51.012+02:00\",\n    \"updatedDate\": \"2016-06-30T13:28:51.012+02:00\",\n    \"version\": 2\n}\n\n```"]
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct Voucher {
     #[doc = "Unique id of the voucher generated on creation by lexoffice."]
     #[builder(default, setter(skip))]
@@ -81,21 +160,21 @@ pub struct Voucher {
     #[builder(setter(into))]
     pub voucher_date: crate::types::Date,
     #[doc = "Date when the purchased item/service has to be shipped/supplied. If it is a period of time, the end date must be given. Format must be `yyyy-MM-dd` (e.g. *2016-07-02*). Please note: ShippingDate can only be specified for voucher types **salesinvoice** and **salescreditnote**."]
-    #[serde(with = "crate::serde::optional_date")]
+    #[serde(default, with = "crate::serde::optional_date")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
     pub shipping_date: Option<crate::types::Date>,
     #[doc = "Date when the voucher's payment has to be settled. Format must be `yyyy-MM-dd` (e.g. *2016-06-28*)."]
-    #[serde(with = "crate::serde::optional_date")]
+    #[serde(default, with = "crate::serde::optional_date")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
     pub due_date: Option<crate::types::Date>,
     #[doc = "Total gross amount of the voucher. Must match the sum of all positions with added/calculated tax amounts. Format must be **##.00** *(119.00)*."]
     #[builder(setter(into))]
-    pub total_gross_amount: f64,
+    pub total_gross_amount: crate::types::Amount,
     #[doc = "Total tax amount of the voucher. Must match the sum of all positions' tax amounts. Format must be **##.00** *(19.00)*."]
     #[builder(setter(into))]
-    pub total_tax_amount: f64,
+    pub total_tax_amount: crate::types::Amount,
     #[doc = "Tax type of the order. Possible values are **net** (positions amount will be provided net and taxes have to be calculated on top), **gross** (positions amount will be provided gross and tax is already included)."]
     #[builder(setter(into))]
     pub tax_type: TaxType,
@@ -125,8 +204,14 @@ pub struct Voucher {
     #[builder(default, setter(skip))]
     pub updated_date: crate::marker::ReadOnly<crate::types::DateTime>,
     #[doc = "Version *(revision)* number which will be increased on each change to handle [optimistic locking](https://developers.lexoffice.io/docs/#optimistic-locking). Set to **0 for initial POST**, for **PUT get latest version from lexoffice** *(via GET)* and merge with your changes. **Please note: If the version did not match the version stored in your system, the user must be informed about losing changes from lexoffice.**"]
+    #[serde(default)]
     #[builder(default, setter(skip))]
     pub version: i64,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 impl crate::request::HasId for Voucher {
     fn id(&self) -> &crate::marker::ReadOnly<uuid::Uuid> {
@@ -135,18 +220,24 @@ impl crate::request::HasId for Voucher {
 }
 #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
 #[builder(doc)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
 pub struct VoucherItems {
     #[doc = "Amount of the position. Net or gross amount, according to the voucher's taxType. Format must be **##.00** *(119.00)*."]
     #[builder(setter(into))]
-    pub amount: f64,
+    pub amount: crate::types::Amount,
     #[doc = "Tax amount of the voucher's item. Format must be **##.00** *(19.00)*."]
     #[builder(setter(into))]
-    pub tax_amount: f64,
+    pub tax_amount: crate::types::Amount,
     #[doc = "Tax rate as percentage value. See [the \"Supported tax rates\" FAQ](https://developers.lexoffice.io/docs/#faq-valid-tax-rates) for more information and a list of possible values. (*e.g. 19*)."]
     #[builder(setter(into))]
     pub tax_rate_percent: f64,
     #[doc = "Booking category for this voucher's revenue or expenditure. Supported and appropriate categoryId's can be found [here](https://developers.lexoffice.io/docs/#vouchers-endpoint-list-of-categoryids)."]
     #[builder(setter(into))]
     pub category_id: uuid::Uuid,
+    #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+    #[cfg_attr(not(feature = "strict"), serde(flatten))]
+    #[cfg_attr(feature = "strict", serde(skip))]
+    #[builder(default, setter(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
@@ -0,0 +1,79 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use wasm_timer::{Delay, Instant};
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+/// A token bucket that limits the number of requests a `Client` sends.
+///
+/// The bucket is shared between all clones of a `RateLimiter` and therefore
+/// between all clones of the `Client` it was passed to.
+///
+/// # Examples
+///
+/// ```
+/// use lexoffice::{ApiKey, Client, RateLimiter};
+/// use std::time::Duration;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::builder()
+///     .api_key(ApiKey::try_default().await?)
+///     .rate_limiter(RateLimiter::new(2, Duration::from_secs(1)))
+///     .build();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    capacity: f64,
+    tokens_per_second: f64,
+    bucket: Arc<Mutex<Bucket>>,
+}
+
+impl RateLimiter {
+    /// Creates a new `RateLimiter` that allows `requests` requests per
+    /// `period`. Up to `requests` requests may be sent in a burst.
+    pub fn new(requests: u32, period: Duration) -> Self {
+        let capacity = f64::from(requests.max(1));
+        let bucket = Bucket {
+            tokens: capacity,
+            updated: Instant::now(),
+        };
+        Self {
+            capacity,
+            tokens_per_second: capacity / period.as_secs_f64(),
+            bucket: Arc::new(Mutex::new(bucket)),
+        }
+    }
+
+    /// Waits until a token is available and takes it from the bucket.
+    pub(crate) async fn acquire(&self) -> std::io::Result<()> {
+        while let Some(wait) = self.try_acquire() {
+            Delay::new(wait).await?;
+        }
+        Ok(())
+    }
+
+    /// Takes a token from the bucket. If the bucket is empty, the time
+    /// until the next token is available is returned instead.
+    fn try_acquire(&self) -> Option<Duration> {
+        let mut bucket = self.bucket.lock().unwrap();
+        let now = Instant::now();
+        let elapsed = now.duration_since(bucket.updated).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.tokens_per_second)
+            .min(self.capacity);
+        bucket.updated = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            None
+        } else {
+            let missing = 1.0 - bucket.tokens;
+            Some(Duration::from_secs_f64(missing / self.tokens_per_second))
+        }
+    }
+}
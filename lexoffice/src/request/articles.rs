@@ -0,0 +1,216 @@
+use crate::model::articles::{LeadingPrice, Type};
+use crate::model::invoices;
+use crate::model::Article;
+use crate::request::impls::ById;
+use crate::request::impls::Deletable;
+use crate::request::impls::Paginated;
+use crate::request::impls::Storable;
+use crate::request::impls::Updatable;
+use crate::request::Endpoint;
+use crate::request::Request;
+use crate::types::Currency;
+
+impl Endpoint for Request<Article> {
+    const ENDPOINT: &'static str = "articles";
+}
+
+/// Filters for the articles endpoint. All filters can be combined.
+///
+/// # Examples
+///
+/// ```
+/// use lexoffice::{ApiKey, Client};
+/// use lexoffice::model::Article;
+/// use lexoffice::model::articles::Type;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(ApiKey::try_default().await?);
+/// let articles = client
+///     .request::<Article>()
+///     .type_(&Type::Service)
+///     .article_number("LXW-BUHA-2024-001")
+///     .page(0)
+///     .await?;
+/// println!("{:#?}", articles);
+/// # Ok(())
+/// # }
+/// ```
+impl Request<Article> {
+    /// Filter by the article number
+    pub fn article_number(mut self, article_number: &str) -> Self {
+        self.url
+            .query_pairs_mut()
+            .append_pair("articleNumber", article_number);
+        self
+    }
+
+    /// Filter by the Global Trade Item Number
+    pub fn gtin(mut self, gtin: &str) -> Self {
+        self.url.query_pairs_mut().append_pair("gtin", gtin);
+        self
+    }
+
+    /// Filter by the type of the article
+    pub fn type_(mut self, type_: &Type) -> Self {
+        self.url
+            .query_pairs_mut()
+            .append_pair("type", &serde_plain::to_string(type_).unwrap());
+        self
+    }
+}
+
+impl Article {
+    /// Creates an invoice line item that references this article. The unit
+    /// price is given in `currency`, which must match the currency of the
+    /// invoice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lexoffice::{ApiKey, Client};
+    /// use lexoffice::model::Article;
+    /// use lexoffice::types::Currency;
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new(ApiKey::try_default().await?);
+    /// let uuid = uuid::Uuid::parse_str("f4add52b-44e3-474a-b718-890885094d9a")?;
+    /// let article = client.request::<Article>().by_id(uuid).await?;
+    /// let line_item = article.line_item(2.0, Currency::EUR);
+    /// println!("{:#?}", line_item);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn line_item(
+        &self,
+        quantity: f64,
+        currency: Currency,
+    ) -> invoices::LineItems {
+        let type_ = match self._type {
+            Some(Type::Product) => invoices::Type::Material,
+            Some(Type::Service) => invoices::Type::Service,
+            _ => invoices::Type::Custom,
+        };
+        let mut unit_price =
+            invoices::UnitPrice::builder().currency(currency).build();
+        if let Some(price) = &self.price {
+            // Only send the leading price so LexOffice calculates the other
+            // one with the tax rate of the invoice.
+            match price.leading_price {
+                Some(LeadingPrice::Gross) => {
+                    unit_price.gross_amount = price.gross_price
+                }
+                _ => unit_price.net_amount = price.net_price,
+            }
+            unit_price.tax_rate_percentage = price.tax_rate;
+        }
+
+        let mut line_item = invoices::LineItems::builder()
+            ._type(type_)
+            .quantity(quantity)
+            .unit_price(unit_price)
+            .build();
+        line_item.id = self.id;
+        line_item.name = self.title.clone();
+        line_item.description = self.description.clone();
+        line_item.unit_name = self.unit_name.clone();
+        line_item
+    }
+}
+
+/// # Examples
+///
+/// ```
+/// use lexoffice::{ApiKey, Client};
+/// use lexoffice::model::Article;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(ApiKey::try_default().await?);
+/// let uuid = uuid::Uuid::parse_str("f4add52b-44e3-474a-b718-890885094d9a")?;
+/// let article = client.request::<Article>().by_id(uuid).await?;
+/// println!("{:#?}", article);
+/// # Ok(())
+/// # }
+/// ```
+///
+impl ById for Request<Article> {}
+
+/// # Examples
+///
+/// ```
+/// use lexoffice::{ApiKey, Client};
+/// use lexoffice::model::Article;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(ApiKey::try_default().await?);
+/// let articles = client.request::<Article>().page(0).await?;
+/// println!("{:#?}", articles);
+/// # Ok(())
+/// # }
+/// ```
+///
+impl Paginated for Request<Article> {}
+
+/// # Examples
+///
+/// ```
+/// use lexoffice::{ApiKey, Client};
+/// use lexoffice::model::Article;
+/// use lexoffice::model::articles::*;
+/// use lexoffice::types::Amount;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(ApiKey::try_default().await?);
+/// let article = Article::builder()
+///     .title("Energieriegel Testpaket".to_string())
+///     ._type(Type::Product)
+///     .unit_name("Stück".to_string())
+///     .price(
+///         Price::builder()
+///             .net_price(Amount::new(5, 0))
+///             .leading_price(LeadingPrice::Net)
+///             .tax_rate(19.0)
+///             .build(),
+///     )
+///     .build();
+/// let result = client.request::<Article>().save(article).await?;
+/// println!("{:#?}", result);
+/// # Ok(())
+/// # }
+/// ```
+///
+impl Storable for Request<Article> {}
+
+/// # Examples
+///
+/// ```
+/// use lexoffice::{ApiKey, Client};
+/// use lexoffice::model::Article;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(ApiKey::try_default().await?);
+/// let uuid = uuid::Uuid::parse_str("f4add52b-44e3-474a-b718-890885094d9a")?;
+/// let mut article = client.request::<Article>().by_id(uuid).await?;
+/// article.note = Some("discontinued".to_string());
+/// let result = client.request::<Article>().update(article).await?;
+/// println!("{:#?}", result);
+/// # Ok(())
+/// # }
+/// ```
+///
+impl Updatable for Request<Article> {}
+
+/// # Examples
+///
+/// ```
+/// use lexoffice::{ApiKey, Client};
+/// use lexoffice::model::Article;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(ApiKey::try_default().await?);
+/// let uuid = uuid::Uuid::parse_str("f4add52b-44e3-474a-b718-890885094d9a")?;
+/// client.request::<Article>().delete(uuid).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+impl Deletable for Request<Article> {}
@@ -10,6 +10,70 @@ impl Endpoint for Request<Contact> {
     const ENDPOINT: &'static str = "contacts";
 }
 
+/// Filters for the contacts endpoint. All filters can be combined.
+///
+/// # Examples
+///
+/// ```
+/// use lexoffice::{ApiKey, Client};
+/// use lexoffice::model::Contact;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(ApiKey::try_default().await?);
+/// let contacts = client
+///     .request::<Contact>()
+///     .email("info@example.com")
+///     .customer(true)
+///     .page(0)
+///     .await?;
+/// if contacts.content.is_empty() {
+///     println!("no such customer");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+impl Request<Contact> {
+    /// Filter by email address. Matches all email addresses of a contact and
+    /// requires at least 3 characters. `%` and `_` can be used as wildcards.
+    pub fn email(mut self, email: &str) -> Self {
+        self.url.query_pairs_mut().append_pair("email", email);
+        self
+    }
+
+    /// Filter by name. Matches the company name or the first and last name
+    /// of a contact and requires at least 3 characters. `%` and `_` can be
+    /// used as wildcards.
+    pub fn name(mut self, name: &str) -> Self {
+        self.url.query_pairs_mut().append_pair("name", name);
+        self
+    }
+
+    /// Filter by the customer or vendor number
+    pub fn number(mut self, number: i64) -> Self {
+        self.url
+            .query_pairs_mut()
+            .append_pair("number", &number.to_string());
+        self
+    }
+
+    /// Filter by the customer role
+    pub fn customer(mut self, customer: bool) -> Self {
+        self.url.query_pairs_mut().append_pair(
+            "customer",
+            &serde_plain::to_string(&customer).unwrap(),
+        );
+        self
+    }
+
+    /// Filter by the vendor role
+    pub fn vendor(mut self, vendor: bool) -> Self {
+        self.url
+            .query_pairs_mut()
+            .append_pair("vendor", &serde_plain::to_string(&vendor).unwrap());
+        self
+    }
+}
+
 /// # Examples
 ///
 /// ```
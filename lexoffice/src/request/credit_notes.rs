@@ -1,12 +1,370 @@
+use crate::model::credit_notes::*;
+use crate::model::invoices;
 use crate::model::CreditNote;
+use crate::model::Invoice;
 use crate::request::impls::ById;
+use crate::request::impls::Finalizable;
+use crate::request::impls::Finalized;
+use crate::request::impls::Pursuable;
+use crate::request::impls::Pursued;
+use crate::request::impls::Renderable;
+use crate::request::impls::Storable;
 use crate::request::Endpoint;
 use crate::request::Request;
+use crate::request::RequestWithState;
+use crate::request::ResultInfo;
+use crate::result::Result;
+use crate::types::{Amount, Currency};
+use crate::Error;
+use rust_decimal::Decimal;
+use serde::Serialize;
+use std::convert::TryFrom;
+use std::str::FromStr;
+use uuid::Uuid;
 
-impl Endpoint for Request<CreditNote> {
+impl<S: Clone> Endpoint for RequestWithState<CreditNote, S> {
     const ENDPOINT: &'static str = "credit-notes";
 }
 
+total_price_money!(crate::model::credit_notes::TotalPrice);
+
+/// The part of an invoice that is refunded by a credit note. See
+/// `CreditNote::partial_cancellation()`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Cancellation {
+    /// Refunds the line items at the given positions of the invoice. The
+    /// positions must exist and must not repeat.
+    LineItems(Vec<usize>),
+    /// Refunds `amount` in a single line item. The amount is net or gross
+    /// depending on the tax type of the invoice.
+    Amount {
+        /// The refunded amount
+        amount: Amount,
+        /// The tax rate of the refunded amount
+        tax_rate_percentage: f64,
+    },
+}
+
+impl CreditNote {
+    /// Builds a credit note that refunds `invoice` completely. The address,
+    /// line items, total discounts and tax conditions of the invoice are
+    /// mirrored and the invoice number is mentioned in the introduction.
+    ///
+    /// Discounts on line items are deducted from their unit prices, as
+    /// credit notes don't support them. If a quantity or discount isn't a
+    /// finite number, `Error::Decimal` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lexoffice::{ApiKey, Client};
+    /// use lexoffice::model::{CreditNote, Invoice};
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new(ApiKey::try_default().await?);
+    /// let uuid = uuid::Uuid::parse_str("f4add52b-44e3-474a-b718-890885094d9a")?;
+    /// let invoice = client.request::<Invoice>().by_id(uuid).await?;
+    /// let credit_note = CreditNote::cancellation(&invoice)?;
+    /// let result = client
+    ///     .request::<CreditNote>()
+    ///     .pursue(uuid)
+    ///     .save(credit_note)
+    ///     .await?;
+    /// println!("{:#?}", result);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cancellation(invoice: &Invoice) -> Result<Self> {
+        let line_items = invoice.line_items.iter().flatten().map(line_item);
+        let mut credit_note =
+            from_invoice(invoice, line_items.collect::<Result<_>>()?);
+        if let Some(total_price) = &invoice.total_price {
+            credit_note.total_price.total_discount_absolute =
+                total_price.total_discount_absolute;
+        }
+        Ok(credit_note)
+    }
+
+    /// Builds a credit note that refunds the part of `invoice` described by
+    /// `cancellation`. Percental total discounts of the invoice are applied
+    /// to refunded line items as well.
+    ///
+    /// `Error::InvalidCancellation` is returned if `cancellation` selects no
+    /// line items, a position that doesn't exist or a position twice. See
+    /// `CreditNote::cancellation()` for how line items are refunded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lexoffice::{ApiKey, Client};
+    /// use lexoffice::model::{CreditNote, Invoice};
+    /// use lexoffice::request::Cancellation;
+    /// use lexoffice::types::Amount;
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new(ApiKey::try_default().await?);
+    /// let uuid = uuid::Uuid::parse_str("f4add52b-44e3-474a-b718-890885094d9a")?;
+    /// let invoice = client.request::<Invoice>().by_id(uuid).await?;
+    /// let cancellation = Cancellation::Amount {
+    ///     amount: Amount::new(1000, 2),
+    ///     tax_rate_percentage: 19.0,
+    /// };
+    /// let credit_note = CreditNote::partial_cancellation(&invoice, &cancellation)?;
+    /// println!("{:#?}", credit_note);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn partial_cancellation(
+        invoice: &Invoice,
+        cancellation: &Cancellation,
+    ) -> Result<Self> {
+        let line_items = match cancellation {
+            Cancellation::LineItems(positions) => {
+                let line_items = invoice.line_items.as_deref().unwrap_or(&[]);
+                if positions.is_empty() {
+                    return Err(Error::InvalidCancellation(
+                        "no line items selected".to_string(),
+                    ));
+                }
+                let mut selected = vec![false; line_items.len()];
+                for position in positions {
+                    match selected.get_mut(*position) {
+                        Some(x) if !*x => *x = true,
+                        Some(_) => {
+                            return Err(Error::InvalidCancellation(format!(
+                                "line item {} is selected twice",
+                                position
+                            )))
+                        }
+                        None => {
+                            return Err(Error::InvalidCancellation(format!(
+                                "invoice has no line item {}",
+                                position
+                            )))
+                        }
+                    }
+                }
+                positions
+                    .iter()
+                    .map(|x| line_item(&line_items[*x]))
+                    .collect::<Result<_>>()?
+            }
+            Cancellation::Amount {
+                amount,
+                tax_rate_percentage,
+            } => {
+                let mut unit_price = UnitPrice::builder()
+                    .currency(currency(invoice))
+                    .tax_rate_percentage(*tax_rate_percentage)
+                    .build();
+                match invoice.tax_conditions.as_ref().map(|x| &x.tax_type) {
+                    Some(Some(invoices::TaxType::Gross)) => {
+                        unit_price.gross_amount = Some(*amount)
+                    }
+                    _ => unit_price.net_amount = Some(*amount),
+                }
+                vec![LineItems::builder()
+                    ._type(Type::Custom)
+                    .name("Gutschrift".to_string())
+                    .quantity(1.0)
+                    .unit_price(unit_price)
+                    .build()]
+            }
+        };
+        Ok(from_invoice(invoice, line_items))
+    }
+}
+
+/// Converts between the enums of different endpoints which share the same
+/// values.
+fn convert<A: Serialize, B: FromStr>(value: &A) -> Option<B> {
+    serde_plain::to_string(value)
+        .ok()
+        .and_then(|x| B::from_str(&x).ok())
+}
+
+fn currency(invoice: &Invoice) -> Currency {
+    invoice
+        .total_price
+        .as_ref()
+        .and_then(|x| x.currency.clone())
+        .unwrap_or(Currency::EUR)
+}
+
+fn from_invoice(invoice: &Invoice, line_items: Vec<LineItems>) -> CreditNote {
+    let mut credit_note = CreditNote::builder()
+        .voucher_date(chrono::Utc::now())
+        .address(Address::builder().build())
+        .line_items(line_items)
+        .total_price(TotalPrice::builder().currency(currency(invoice)).build())
+        .tax_conditions(TaxConditions::builder().build())
+        .build();
+    credit_note.language = invoice.language.clone();
+
+    if let Some(address) = &invoice.address {
+        credit_note.address.contact_id = address.contact_id;
+        credit_note.address.name = address.name.clone();
+        credit_note.address.supplement = address.supplement.clone();
+        credit_note.address.street = address.street.clone();
+        credit_note.address.city = address.city.clone();
+        credit_note.address.zip = address.zip.clone();
+        credit_note.address.country_code = address.country_code.clone();
+    }
+    if let Some(total_price) = &invoice.total_price {
+        credit_note.total_price.total_discount_percentage =
+            total_price.total_discount_percentage;
+    }
+    if let Some(tax_conditions) = &invoice.tax_conditions {
+        credit_note.tax_conditions.tax_type =
+            tax_conditions.tax_type.as_ref().and_then(convert);
+        credit_note.tax_conditions.tax_sub_type =
+            tax_conditions.tax_sub_type.as_ref().and_then(convert);
+        credit_note.tax_conditions.tax_type_note =
+            tax_conditions.tax_type_note.clone();
+    }
+    if let Some(voucher_number) = &invoice.voucher_number {
+        credit_note.introduction = Some(format!(
+            "Rechnungskorrektur zur Rechnung {}",
+            voucher_number
+        ));
+    }
+    credit_note
+}
+
+/// The number of decimal places LexOffice accepts for unit prices
+const UNIT_PRICE_SCALE: u32 = 4;
+
+fn line_item(item: &invoices::LineItems) -> Result<LineItems> {
+    let type_ = item
+        ._type
+        .as_ref()
+        .and_then(convert)
+        .unwrap_or(Type::Custom);
+    let mut line_item = LineItems::builder()
+        ._type(type_)
+        .name(item.name.clone().unwrap_or_default())
+        .build();
+    line_item.id = item.id;
+    line_item.description = item.description.clone();
+    line_item.quantity = item.quantity;
+    line_item.unit_name = item.unit_name.clone();
+    let price = match &item.unit_price {
+        Some(price) => price,
+        None => return Ok(line_item),
+    };
+    let mut unit_price = UnitPrice::builder()
+        .currency(price.currency.clone().unwrap_or(Currency::EUR))
+        .tax_rate_percentage(price.tax_rate_percentage.unwrap_or_default())
+        .build();
+    unit_price.net_amount = price.net_amount;
+    unit_price.gross_amount = price.gross_amount;
+
+    // Credit notes don't support discounts on line items, so the discount
+    // is deducted from the unit price instead. LexOffice rounds the
+    // discounted line total, so if the discounted unit price needs more
+    // decimal places than allowed, the rounded line total is refunded as a
+    // single unit.
+    let percentage = item.discount_percentage.unwrap_or_default();
+    if percentage != 0.0 {
+        let factor = (Decimal::from(100)
+            - Amount::try_from(percentage)?.as_decimal())
+            / Decimal::from(100);
+        let discount = |x: Option<Amount>| x.map(|x| x.as_decimal() * factor);
+        let net = discount(price.net_amount);
+        let gross = discount(price.gross_amount);
+        let exact = net
+            .iter()
+            .chain(gross.iter())
+            .all(|x| x.normalize().scale() <= UNIT_PRICE_SCALE);
+        if exact {
+            unit_price.net_amount = net.map(Amount::from);
+            unit_price.gross_amount = gross.map(Amount::from);
+        } else {
+            let quantity =
+                Amount::try_from(item.quantity.unwrap_or(1.0))?.as_decimal();
+            let total = |x: Decimal| Amount::from(x * quantity).round_cents();
+            line_item.quantity = Some(1.0);
+            unit_price.net_amount = net.map(total);
+            unit_price.gross_amount = gross.map(total);
+        }
+    }
+    line_item.unit_price = Some(unit_price);
+    Ok(line_item)
+}
+
+impl Request<CreditNote> {
+    /// Refunds the invoice with the id `invoice_id` completely. The invoice
+    /// is fetched and a finalized credit note that pursues it is created
+    /// with `CreditNote::cancellation()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lexoffice::{ApiKey, Client};
+    /// use lexoffice::model::CreditNote;
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new(ApiKey::try_default().await?);
+    /// let uuid = uuid::Uuid::parse_str("f4add52b-44e3-474a-b718-890885094d9a")?;
+    /// let result = client.request::<CreditNote>().cancel_invoice(uuid).await?;
+    /// println!("{:#?}", result);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn cancel_invoice<I>(
+        self,
+        invoice_id: I,
+    ) -> Result<ResultInfo<CreditNote>>
+    where
+        I: Into<Uuid>,
+    {
+        let invoice_id = invoice_id.into();
+        let invoice =
+            self.client.request::<Invoice>().by_id(invoice_id).await?;
+        let credit_note = CreditNote::cancellation(&invoice)?;
+        self.pursue(invoice_id).finalize().save(credit_note).await
+    }
+
+    /// Refunds a part of the invoice with the id `invoice_id`. The invoice
+    /// is fetched and a finalized credit note that pursues it is created
+    /// with `CreditNote::partial_cancellation()`. Nothing is saved if
+    /// `cancellation` doesn't match the line items of the invoice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lexoffice::{ApiKey, Client};
+    /// use lexoffice::model::CreditNote;
+    /// use lexoffice::request::Cancellation;
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new(ApiKey::try_default().await?);
+    /// let uuid = uuid::Uuid::parse_str("f4add52b-44e3-474a-b718-890885094d9a")?;
+    /// let result = client
+    ///     .request::<CreditNote>()
+    ///     .cancel_invoice_partially(uuid, Cancellation::LineItems(vec![0]))
+    ///     .await?;
+    /// println!("{:#?}", result);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn cancel_invoice_partially<I>(
+        self,
+        invoice_id: I,
+        cancellation: Cancellation,
+    ) -> Result<ResultInfo<CreditNote>>
+    where
+        I: Into<Uuid>,
+    {
+        let invoice_id = invoice_id.into();
+        let invoice =
+            self.client.request::<Invoice>().by_id(invoice_id).await?;
+        let credit_note =
+            CreditNote::partial_cancellation(&invoice, &cancellation)?;
+        self.pursue(invoice_id).finalize().save(credit_note).await
+    }
+}
+
 /// # Examples
 ///
 /// ```
@@ -23,3 +381,351 @@ impl Endpoint for Request<CreditNote> {
 /// ```
 ///
 impl ById for Request<CreditNote> {}
+
+/// # Examples
+///
+/// ```
+/// use lexoffice::{ApiKey, Client};
+/// use lexoffice::model::CreditNote;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(ApiKey::try_default().await?);
+/// let uuid = uuid::Uuid::parse_str("f4add52b-44e3-474a-b718-890885094d9a")?;
+/// let file_id = client.request::<CreditNote>().document(uuid).await?;
+/// println!("{}", file_id);
+/// # Ok(())
+/// # }
+/// ```
+///
+impl Renderable for Request<CreditNote> {}
+
+/// # Examples
+///
+/// ```
+/// use lexoffice::{ApiKey, Client};
+/// use lexoffice::model::CreditNote;
+/// use lexoffice::model::credit_notes::*;
+/// use lexoffice::types::{Amount, Currency};
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(ApiKey::try_default().await?);
+/// let credit_note = CreditNote::builder()
+///     .voucher_date(chrono::Utc::now())
+///     .address(
+///         Address::builder()
+///             .name("Bike & Ride GmbH & Co. KG".to_string())
+///             .country_code("DE".to_string())
+///             .build(),
+///     )
+///     .line_items(vec![LineItems::builder()
+///         ._type(Type::Custom)
+///         .name("Energieriegel Testpaket".to_string())
+///         .quantity(1.0)
+///         .unit_name("Stück".to_string())
+///         .unit_price(
+///             UnitPrice::builder()
+///                 .currency(Currency::EUR)
+///                 .net_amount(Amount::new(5, 0))
+///                 .tax_rate_percentage(19.0)
+///                 .build(),
+///         )
+///         .build()])
+///     .total_price(TotalPrice::builder().currency(Currency::EUR).build())
+///     .tax_conditions(TaxConditions::builder().tax_type(TaxType::Net).build())
+///     .build();
+/// let result = client.request::<CreditNote>().save(credit_note).await?;
+/// println!("{:#?}", result);
+/// # Ok(())
+/// # }
+/// ```
+///
+impl Storable for Request<CreditNote> {}
+
+/// # Examples
+///
+/// ```
+/// use lexoffice::{ApiKey, Client};
+/// use lexoffice::model::CreditNote;
+///
+/// # async fn run(credit_note: CreditNote) -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(ApiKey::try_default().await?);
+/// let result = client
+///     .request::<CreditNote>()
+///     .finalize()
+///     .save(credit_note)
+///     .await?;
+/// println!("{:#?}", result);
+/// # Ok(())
+/// # }
+/// ```
+///
+impl Finalizable for Request<CreditNote> {}
+
+/// Saves credit notes in status **open**. See `Request::finalize()`.
+impl Storable for RequestWithState<CreditNote, Finalized> {}
+
+/// # Examples
+///
+/// ```
+/// use lexoffice::{ApiKey, Client};
+/// use lexoffice::model::CreditNote;
+///
+/// # async fn run(credit_note: CreditNote) -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(ApiKey::try_default().await?);
+/// let invoice_id = uuid::Uuid::parse_str("f4add52b-44e3-474a-b718-890885094d9a")?;
+/// let result = client
+///     .request::<CreditNote>()
+///     .pursue(invoice_id)
+///     .finalize()
+///     .save(credit_note)
+///     .await?;
+/// println!("{:#?}", result);
+/// # Ok(())
+/// # }
+/// ```
+///
+impl Pursuable for Request<CreditNote> {}
+
+/// Saves credit notes that refund an invoice. See `Request::pursue()`.
+impl Storable for RequestWithState<CreditNote, Pursued> {}
+
+/// Allows to finalize credit notes that pursue an invoice. See
+/// `Request::finalize()`.
+impl Finalizable for RequestWithState<CreditNote, Pursued> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn invoice(tax_type: &str) -> Invoice {
+        serde_json::from_value(json!({
+            "id": "e9066f04-8cc7-4616-93f8-ac9ecc8479c8",
+            "version": 1,
+            "language": "en",
+            "voucherStatus": "open",
+            "voucherNumber": "RE1019",
+            "voucherDate": "2021-02-22T00:00:00.000+01:00",
+            "address": {
+                "contactId": "be9475f4-ef80-442b-8ab9-3ab8b1a2aeb9",
+                "name": "Bike & Ride GmbH & Co. KG",
+                "street": "Musterstraße 42",
+                "city": "Freiburg",
+                "zip": "79112",
+                "countryCode": "DE"
+            },
+            "lineItems": [
+                {
+                    "type": "material",
+                    "name": "Abus Kabelschloss Primo 590",
+                    "quantity": 2,
+                    "unitName": "Stück",
+                    "unitPrice": {
+                        "currency": "EUR",
+                        "netAmount": 13.4,
+                        "grossAmount": 15.95,
+                        "taxRatePercentage": 19
+                    },
+                    "discountPercentage": 50
+                },
+                {
+                    "type": "service",
+                    "name": "Aufwändige Montage",
+                    "quantity": 1,
+                    "unitName": "Stunde",
+                    "unitPrice": {
+                        "currency": "EUR",
+                        "netAmount": 8.32,
+                        "taxRatePercentage": 7
+                    },
+                    "discountPercentage": 0
+                },
+                {
+                    "type": "custom",
+                    "name": "Energieriegel Testpaket",
+                    "quantity": 100,
+                    "unitName": "Stück",
+                    "unitPrice": {
+                        "currency": "EUR",
+                        "netAmount": 0.99,
+                        "taxRatePercentage": 7
+                    },
+                    "discountPercentage": 10
+                }
+            ],
+            "totalPrice": {
+                "currency": "EUR",
+                "totalDiscountAbsolute": 5,
+                "totalDiscountPercentage": 2.5
+            },
+            "taxConditions": {
+                "taxType": tax_type,
+                "taxTypeNote": "Steuerfreie innergemeinschaftliche Lieferung"
+            }
+        }))
+        .unwrap()
+    }
+
+    fn unit_price(line_item: &LineItems) -> (Option<Amount>, Option<Amount>) {
+        let price = line_item.unit_price.as_ref().unwrap();
+        (price.net_amount, price.gross_amount)
+    }
+
+    #[test]
+    fn cancellation_mirrors_the_invoice() {
+        let invoice = invoice("net");
+        let credit_note = CreditNote::cancellation(&invoice).unwrap();
+
+        let address = &credit_note.address;
+        let expected = invoice.address.as_ref().unwrap();
+        assert_eq!(address.contact_id, expected.contact_id);
+        assert_eq!(address.name, expected.name);
+        assert_eq!(address.street, expected.street);
+        assert_eq!(address.city, expected.city);
+        assert_eq!(address.zip, expected.zip);
+        assert_eq!(address.country_code, expected.country_code);
+
+        let tax_conditions = &credit_note.tax_conditions;
+        assert_eq!(tax_conditions.tax_type, Some(TaxType::Net));
+        assert_eq!(
+            tax_conditions.tax_type_note.as_deref(),
+            Some("Steuerfreie innergemeinschaftliche Lieferung")
+        );
+        assert_eq!(credit_note.language.as_deref(), Some("en"));
+        assert_eq!(
+            credit_note.introduction.as_deref(),
+            Some("Rechnungskorrektur zur Rechnung RE1019")
+        );
+
+        let total_price = &credit_note.total_price;
+        assert_eq!(total_price.currency, Currency::EUR);
+        assert_eq!(
+            total_price.total_discount_absolute,
+            Some(Amount::new(5, 0))
+        );
+        assert_eq!(total_price.total_discount_percentage, Some(2.5));
+
+        let names = credit_note
+            .line_items
+            .iter()
+            .map(|x| (x._type.clone(), x.name.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            [
+                (Type::Material, "Abus Kabelschloss Primo 590"),
+                (Type::Service, "Aufwändige Montage"),
+                (Type::Custom, "Energieriegel Testpaket"),
+            ]
+        );
+    }
+
+    #[test]
+    fn line_item_discounts_are_deducted_from_the_unit_price() {
+        let credit_note = CreditNote::cancellation(&invoice("net")).unwrap();
+        let line_items = &credit_note.line_items;
+
+        assert_eq!(line_items[0].quantity, Some(2.0));
+        assert_eq!(
+            unit_price(&line_items[0]),
+            (Some(Amount::new(670, 2)), Some(Amount::new(7975, 3)))
+        );
+        assert_eq!(line_items[1].quantity, Some(1.0));
+        assert_eq!(
+            unit_price(&line_items[1]),
+            (Some(Amount::new(832, 2)), None)
+        );
+        // 100 × 0.891 = 89.10, exactly the discounted line total
+        assert_eq!(line_items[2].quantity, Some(100.0));
+        assert_eq!(
+            unit_price(&line_items[2]),
+            (Some(Amount::new(891, 3)), None)
+        );
+    }
+
+    #[test]
+    fn inexact_discounts_refund_the_rounded_line_total() {
+        let mut invoice = invoice("net");
+        let line_item = &mut invoice.line_items.as_mut().unwrap()[2];
+        line_item.quantity = Some(3.0);
+        line_item.unit_price.as_mut().unwrap().net_amount =
+            Some(Amount::new(12345, 4));
+        line_item.discount_percentage = Some(12.5);
+
+        let credit_note = CreditNote::cancellation(&invoice).unwrap();
+        // 3 × 1.2345 × 0.875 = 3.2405625
+        let line_item = &credit_note.line_items[2];
+        assert_eq!(line_item.quantity, Some(1.0));
+        assert_eq!(unit_price(line_item), (Some(Amount::new(324, 2)), None));
+    }
+
+    #[test]
+    fn non_finite_discounts_are_rejected() {
+        let mut invoice = invoice("net");
+        invoice.line_items.as_mut().unwrap()[0].discount_percentage =
+            Some(f64::NAN);
+        assert!(matches!(
+            CreditNote::cancellation(&invoice),
+            Err(Error::Decimal(_))
+        ));
+    }
+
+    #[test]
+    fn amounts_are_net_or_gross_by_tax_type() {
+        let cancellation = Cancellation::Amount {
+            amount: Amount::new(1000, 2),
+            tax_rate_percentage: 19.0,
+        };
+        let amount = Some(Amount::new(1000, 2));
+
+        let net =
+            CreditNote::partial_cancellation(&invoice("net"), &cancellation)
+                .unwrap();
+        assert_eq!(net.line_items.len(), 1);
+        assert_eq!(unit_price(&net.line_items[0]), (amount, None));
+
+        let gross =
+            CreditNote::partial_cancellation(&invoice("gross"), &cancellation)
+                .unwrap();
+        assert_eq!(gross.tax_conditions.tax_type, Some(TaxType::Gross));
+        assert_eq!(unit_price(&gross.line_items[0]), (None, amount));
+        let price = gross.line_items[0].unit_price.as_ref().unwrap();
+        assert_eq!(price.tax_rate_percentage, 19.0);
+    }
+
+    #[test]
+    fn partial_cancellations_keep_percental_total_discounts() {
+        let cancellation = Cancellation::LineItems(vec![1]);
+        let credit_note =
+            CreditNote::partial_cancellation(&invoice("net"), &cancellation)
+                .unwrap();
+        let total_price = &credit_note.total_price;
+        assert_eq!(total_price.total_discount_percentage, Some(2.5));
+        assert_eq!(total_price.total_discount_absolute, None);
+    }
+
+    #[test]
+    fn line_items_are_selected_by_position() {
+        let invoice = invoice("net");
+        let cancellation = Cancellation::LineItems(vec![2, 0]);
+        let credit_note =
+            CreditNote::partial_cancellation(&invoice, &cancellation).unwrap();
+        let names = credit_note
+            .line_items
+            .iter()
+            .map(|x| x.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            ["Energieriegel Testpaket", "Abus Kabelschloss Primo 590"]
+        );
+
+        for positions in [vec![], vec![3], vec![1, 1]] {
+            let cancellation = Cancellation::LineItems(positions);
+            assert!(matches!(
+                CreditNote::partial_cancellation(&invoice, &cancellation),
+                Err(Error::InvalidCancellation(_))
+            ));
+        }
+    }
+}
@@ -1,42 +1,16 @@
-use std::marker::PhantomData;
-
 use crate::model::DeliveryNote;
 use crate::request::impls::ById;
 use crate::request::impls::Paginated;
+use crate::request::impls::Pursuable;
+use crate::request::impls::Pursued;
+use crate::request::impls::Renderable;
 use crate::request::impls::Storable;
 use crate::request::impls::Updatable;
 use crate::request::Endpoint;
 use crate::request::Request;
-use uuid::Uuid;
-
-use super::RequestWithState;
-
-impl Request<DeliveryNote> {
-    /// To be able to pursue a sales voucher to a delivery note, the optional
-    /// query parameter precedingSalesVoucherId needs to be set. The id value
-    /// `id` refers to the preceding sales voucher which is going to be
-    /// pursued.
-    pub async fn pursue<U>(
-        mut self,
-        id: U,
-    ) -> RequestWithState<DeliveryNote, Uuid>
-    where
-        U: Into<Uuid>,
-    {
-        let id = id.into().to_string();
-        self.url
-            .query_pairs_mut()
-            .append_pair("precedingSalesVoucherId", &id);
-        RequestWithState {
-            client: self.client,
-            url: self.url,
-            target: self.target,
-            state: PhantomData,
-        }
-    }
-}
+use crate::request::RequestWithState;
 
-impl Endpoint for Request<DeliveryNote> {
+impl<S: Clone> Endpoint for RequestWithState<DeliveryNote, S> {
     const ENDPOINT: &'static str = "delivery-notes";
 }
 
@@ -57,6 +31,23 @@ impl Endpoint for Request<DeliveryNote> {
 ///
 impl ById for Request<DeliveryNote> {}
 
+/// # Examples
+///
+/// ```
+/// use lexoffice::{ApiKey, Client};
+/// use lexoffice::model::DeliveryNote;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(ApiKey::try_default().await?);
+/// let uuid = uuid::Uuid::parse_str("1195100a-7c44-40f0-972f-cef2844bc2ef")?;
+/// let file_id = client.request::<DeliveryNote>().document(uuid).await?;
+/// println!("{}", file_id);
+/// # Ok(())
+/// # }
+/// ```
+///
+impl Renderable for Request<DeliveryNote> {}
+
 /// # Examples
 ///
 /// ```
@@ -76,5 +67,30 @@ impl Paginated for Request<DeliveryNote> {}
 /// TODO doc
 impl Storable for Request<DeliveryNote> {}
 
+/// # Examples
+///
+/// ```
+/// use lexoffice::{ApiKey, Client};
+/// use lexoffice::model::DeliveryNote;
+///
+/// # async fn run(delivery_note: DeliveryNote) -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(ApiKey::try_default().await?);
+/// let invoice_id = uuid::Uuid::parse_str("f4add52b-44e3-474a-b718-890885094d9a")?;
+/// let result = client
+///     .request::<DeliveryNote>()
+///     .pursue(invoice_id)
+///     .save(delivery_note)
+///     .await?;
+/// println!("{:#?}", result);
+/// # Ok(())
+/// # }
+/// ```
+///
+impl Pursuable for Request<DeliveryNote> {}
+
+/// Saves delivery notes as successors of another sales voucher. See
+/// `Request::pursue()`.
+impl Storable for RequestWithState<DeliveryNote, Pursued> {}
+
 /// TODO doc
 impl Updatable for Request<DeliveryNote> {}
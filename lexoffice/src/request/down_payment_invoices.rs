@@ -1,5 +1,6 @@
 use crate::model::DownPaymentInvoice;
 use crate::request::impls::ById;
+use crate::request::impls::Renderable;
 use crate::request::Endpoint;
 use crate::request::Request;
 
@@ -7,6 +8,8 @@ impl Endpoint for Request<DownPaymentInvoice> {
     const ENDPOINT: &'static str = "down-payment-invoices";
 }
 
+total_price_money!(crate::model::down_payment_invoices::TotalPrice);
+
 /// # Examples
 ///
 /// ```
@@ -23,3 +26,20 @@ impl Endpoint for Request<DownPaymentInvoice> {
 /// ```
 ///
 impl ById for Request<DownPaymentInvoice> {}
+
+/// # Examples
+///
+/// ```
+/// use lexoffice::{ApiKey, Client};
+/// use lexoffice::model::DownPaymentInvoice;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(ApiKey::try_default().await?);
+/// let uuid = uuid::Uuid::parse_str("f4add52b-44e3-474a-b718-890885094d9a")?;
+/// let file_id = client.request::<DownPaymentInvoice>().document(uuid).await?;
+/// println!("{}", file_id);
+/// # Ok(())
+/// # }
+/// ```
+///
+impl Renderable for Request<DownPaymentInvoice> {}
@@ -1,40 +1,19 @@
-use std::marker::PhantomData;
-
 use crate::model::Dunning;
 use crate::request::impls::ById;
 use crate::request::impls::Paginated;
+use crate::request::impls::Pursuable;
+use crate::request::impls::Pursued;
+use crate::request::impls::Renderable;
 use crate::request::impls::Storable;
 use crate::request::impls::Updatable;
 use crate::request::Endpoint;
 use crate::request::Request;
 use crate::request::RequestWithState;
-use uuid::Uuid;
 
-impl Endpoint for Request<Dunning> {
+impl<S: Clone> Endpoint for RequestWithState<Dunning, S> {
     const ENDPOINT: &'static str = "dunnings";
 }
 
-impl Request<Dunning> {
-    /// To be able to pursue a sales voucher to a dunning, the optional query
-    /// parameter precedingSalesVoucherId needs to be set. The id value `id`
-    /// refers to the preceding sales voucher which is going to be pursued.
-    pub async fn pursue<U>(mut self, uuid: U) -> RequestWithState<Dunning, Uuid>
-    where
-        U: Into<Uuid>,
-    {
-        let uuid = uuid.into().to_string();
-        self.url
-            .query_pairs_mut()
-            .append_pair("precedingSalesVoucherId", &uuid);
-        RequestWithState {
-            client: self.client,
-            url: self.url,
-            target: self.target,
-            state: PhantomData,
-        }
-    }
-}
-
 /// # Examples
 ///
 /// ```
@@ -52,6 +31,23 @@ impl Request<Dunning> {
 ///
 impl ById for Request<Dunning> {}
 
+/// # Examples
+///
+/// ```
+/// use lexoffice::{ApiKey, Client};
+/// use lexoffice::model::Dunning;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(ApiKey::try_default().await?);
+/// let uuid = uuid::Uuid::parse_str("1195100a-7c44-40f0-972f-cef2844bc2ef")?;
+/// let file_id = client.request::<Dunning>().document(uuid).await?;
+/// println!("{}", file_id);
+/// # Ok(())
+/// # }
+/// ```
+///
+impl Renderable for Request<Dunning> {}
+
 /// # Examples
 ///
 /// ```
@@ -68,8 +64,29 @@ impl ById for Request<Dunning> {}
 ///
 impl Paginated for Request<Dunning> {}
 
-/// TODO doc
-impl Storable for RequestWithState<Dunning, Uuid> {}
+/// # Examples
+///
+/// ```
+/// use lexoffice::{ApiKey, Client};
+/// use lexoffice::model::Dunning;
+///
+/// # async fn run(dunning: Dunning) -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(ApiKey::try_default().await?);
+/// let invoice_id = uuid::Uuid::parse_str("f4add52b-44e3-474a-b718-890885094d9a")?;
+/// let result = client
+///     .request::<Dunning>()
+///     .pursue(invoice_id)
+///     .save(dunning)
+///     .await?;
+/// println!("{:#?}", result);
+/// # Ok(())
+/// # }
+/// ```
+///
+impl Pursuable for Request<Dunning> {}
+
+/// Saves dunnings for the invoice they pursue. See `Request::pursue()`.
+impl Storable for RequestWithState<Dunning, Pursued> {}
 
 /// TODO doc
 impl Storable for Request<Dunning> {}
@@ -1,10 +1,13 @@
 use crate::error::Error;
+use crate::mime::ExtensionExt;
 use crate::model::File;
 use crate::request::Endpoint;
 use crate::request::Request;
 use crate::result::Result;
 use crate::util::error_for_lexoffice;
 use crate::util::to_json_response;
+use bytes::Bytes;
+use reqwest::header::CONTENT_TYPE;
 use reqwest::multipart::{Form, Part};
 use reqwest::Method;
 use reqwest::Response;
@@ -18,10 +21,29 @@ impl Endpoint for Request<File> {
 }
 
 #[derive(Deserialize, Debug)]
-struct FileResponse {
+pub(crate) struct FileResponse {
     pub id: Uuid,
 }
 
+/// Creates a multipart `Part` from `bytes` with the content type `mime`.
+pub(crate) fn file_part<B>(mime: &str, bytes: B) -> Result<Part>
+where
+    B: Into<Cow<'static, [u8]>>,
+{
+    Ok(Part::bytes(bytes)
+        .mime_str(mime)?
+        .file_name(format!("document{}", mime.extension())))
+}
+
+/// The content of a file downloaded from lexoffice
+#[derive(Debug, Clone)]
+pub struct FileContent {
+    /// The content type sent by lexoffice
+    pub mime: mime::Mime,
+    /// The data of the file
+    pub bytes: Bytes,
+}
+
 impl Request<File> {
     /// This method creates an `Url` that is used to address the object
     /// identified by `uuid`.
@@ -55,10 +77,27 @@ impl Request<File> {
         .await
     }
 
+    /// Downloads a file identified by `uuid` from lexoffice. Unlike
+    /// `download_path()` this is available on all targets.
+    pub async fn download<I>(self, uuid: I) -> Result<FileContent>
+    where
+        I: Into<Uuid> + Send + Sync,
+    {
+        let response = self.by_id(uuid).await?;
+        let mime = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|x| x.to_str().ok())
+            .and_then(|x| x.parse().ok())
+            .unwrap_or(mime::APPLICATION_OCTET_STREAM);
+        let bytes = response.bytes().await?;
+        Ok(FileContent { mime, bytes })
+    }
+
     /// Uploads a file to lexoffice
     pub async fn upload<P>(self, file_part: P) -> Result<Uuid>
     where
-        P: Into<Part> + Send + Sync,
+        P: Into<Part>,
     {
         let file_part = file_part.into();
         let url = self.url();
@@ -81,17 +120,26 @@ impl Request<File> {
     where
         B: Into<Cow<'static, [u8]>>,
     {
-        let file_part = Part::bytes(bytes).mime_str(mime.as_ref())?;
+        self.upload_bytes_str(mime.as_ref(), bytes).await
+    }
+
+    /// Uploads arbitrary data as file to lexoffice. `mime` is the content
+    /// type of `bytes`, e.g. `image/jpeg`.
+    pub async fn upload_bytes_str<B>(self, mime: &str, bytes: B) -> Result<Uuid>
+    where
+        B: Into<Cow<'static, [u8]>>,
+    {
+        let file_part = file_part(mime, bytes)?;
         self.upload(file_part).await
     }
 
     /// Uploads a file from a path to lexoffice
-    #[cfg(feature = "fs")]
+    #[cfg(all(feature = "fs", not(target_arch = "wasm32")))]
     pub async fn upload_path<P>(self, path: P) -> Result<Uuid>
     where
         P: AsRef<std::path::Path> + Send + Sync,
     {
-        use crate::mime::*;
+        use crate::mime::MimeExt;
         use reqwest::Body;
 
         let path = path.as_ref();
@@ -100,7 +148,28 @@ impl Request<File> {
         let mime = path.mime();
         let part = Part::stream(Body::wrap_stream(stream))
             .mime_str(mime.as_ref())?
-            .file_name(format!("document.{}", mime.extension()));
+            .file_name(format!("document{}", mime.extension()));
         self.upload(part).await
     }
+
+    /// Downloads a file identified by `uuid` from lexoffice and writes it
+    /// to a path
+    #[cfg(all(feature = "fs", not(target_arch = "wasm32")))]
+    pub async fn download_path<I, P>(self, uuid: I, path: P) -> Result<()>
+    where
+        I: Into<Uuid> + Send + Sync,
+        P: AsRef<std::path::Path> + Send + Sync,
+    {
+        use futures::StreamExt;
+        use tokio::io::AsyncWriteExt;
+
+        let response = self.by_id(uuid).await?;
+        let mut file = tokio::fs::File::create(path).await?;
+        let mut stream = response.bytes_stream();
+        while let Some(bytes) = stream.next().await {
+            file.write_all(&bytes?).await?;
+        }
+        file.flush().await?;
+        Ok(())
+    }
 }
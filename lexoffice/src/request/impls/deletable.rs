@@ -3,6 +3,7 @@ use crate::request::Endpoint;
 use crate::request::HasId;
 use crate::request::RequestWithState;
 use crate::result::Result;
+use crate::util::error_for_lexoffice;
 use reqwest::Method;
 use serde::{de::DeserializeOwned, Serialize};
 use std::str::FromStr;
@@ -33,11 +34,13 @@ where
         I: Into<Uuid> + Send,
     {
         let url = self.by_id_url(uuid)?;
-        self.client()
-            .http_builder(Method::DELETE, url)
-            .send()
-            .await?
-            .error_for_status()?;
+        error_for_lexoffice(
+            self.client()
+                .http_builder(Method::DELETE, url)
+                .send()
+                .await?,
+        )
+        .await?;
         Ok(())
     }
 }
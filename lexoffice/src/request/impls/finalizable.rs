@@ -0,0 +1,33 @@
+use crate::request::RequestWithState;
+use std::marker::PhantomData;
+
+/// This trait marks a `Request` as `Finalizable` and unlocks the
+/// `Request::finalize()` method.
+pub trait Finalizable {}
+
+/// This type represents the state of a Request that creates finalized
+/// vouchers instead of drafts.
+#[derive(Clone, Debug)]
+pub struct Finalized;
+
+impl<T, S> RequestWithState<T, S>
+where
+    Self: Finalizable,
+    T: Clone,
+    S: Clone,
+{
+    /// Sets the optional query parameter `finalize`. Vouchers saved with
+    /// the returned request are created in status **open** instead of
+    /// **draft**.
+    /// `RequestWithState<T, S>` must implement the `Finalizable` trait in
+    /// order to make this function available.
+    pub fn finalize(mut self) -> RequestWithState<T, Finalized> {
+        self.url.query_pairs_mut().append_pair("finalize", "true");
+        RequestWithState {
+            client: self.client,
+            url: self.url,
+            target: self.target,
+            state: PhantomData,
+        }
+    }
+}
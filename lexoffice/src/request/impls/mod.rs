@@ -1,11 +1,17 @@
 mod by_id;
 mod deletable;
+mod finalizable;
 mod paginated;
+mod pursuable;
+mod renderable;
 mod storable;
 mod updatable;
 
 pub use by_id::*;
 pub use deletable::*;
+pub use finalizable::*;
 pub use paginated::*;
+pub use pursuable::*;
+pub use renderable::*;
 pub use storable::*;
 pub use updatable::*;
@@ -1,5 +1,5 @@
 use crate::model::Page;
-use crate::request::stream::PageStream;
+use crate::request::stream::{MaybeSendSync, PageStream};
 use crate::request::Endpoint;
 use crate::request::RequestWithState;
 use crate::result::Result;
@@ -16,9 +16,9 @@ pub trait Paginated {}
 
 impl<T, S> RequestWithState<T, S>
 where
-    Self: Endpoint + Paginated + Sync + Send + Clone,
-    T: DeserializeOwned + Sync + Send + Clone + 'static,
-    S: Sync + Send + Clone,
+    Self: Endpoint + Paginated + MaybeSendSync + Clone,
+    T: DeserializeOwned + MaybeSendSync + Clone + 'static,
+    S: MaybeSendSync + Clone,
 {
     /// This method gets a page that contains items of type `T` from the API.
     /// It also allows to define a number of items to request per page.
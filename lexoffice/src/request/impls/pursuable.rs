@@ -0,0 +1,41 @@
+use crate::request::RequestWithState;
+use std::marker::PhantomData;
+use uuid::Uuid;
+
+/// This trait marks a `Request` as `Pursuable` and unlocks the
+/// `Request::pursue()` method.
+pub trait Pursuable {}
+
+/// This type represents the state of a Request that creates a sales voucher
+/// as the successor of another sales voucher.
+#[derive(Clone, Debug)]
+pub struct Pursued;
+
+impl<T> RequestWithState<T, ()>
+where
+    Self: Pursuable,
+    T: Clone,
+{
+    /// To be able to pursue a sales voucher, the optional query parameter
+    /// `precedingSalesVoucherId` needs to be set. The id value `id` refers
+    /// to the preceding sales voucher which is going to be pursued. The
+    /// vouchers are listed in the `related_vouchers` of each other
+    /// afterwards.
+    /// `Request<T>` must implement the `Pursuable` trait in order to make
+    /// this function available.
+    pub fn pursue<U>(mut self, id: U) -> RequestWithState<T, Pursued>
+    where
+        U: Into<Uuid>,
+    {
+        let id = id.into().to_string();
+        self.url
+            .query_pairs_mut()
+            .append_pair("precedingSalesVoucherId", &id);
+        RequestWithState {
+            client: self.client,
+            url: self.url,
+            target: self.target,
+            state: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use super::ById;
+use crate::request::Endpoint;
+use crate::request::RequestWithState;
+use crate::result::Result;
+use crate::util::to_json_response;
+use reqwest::Method;
+use reqwest::Url;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// This trait marks a `Request` as `Renderable` and unlocks the
+/// `Request::document()` and `Request::download_document()` methods.
+///
+/// For further reading please see
+/// [the official API docs](https://developers.lexoffice.io/docs/#invoices-endpoint-render-an-invoice-document-pdf)
+pub trait Renderable {}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct DocumentResponse {
+    document_file_id: Uuid,
+}
+
+impl<T, S> RequestWithState<T, S>
+where
+    Self: Endpoint + Renderable + ById,
+    T: DeserializeOwned + Clone,
+    S: Clone,
+{
+    /// This method creates an `Url` that is used to render the document of
+    /// the object identified by `uuid`.
+    /// `Request<T>` must implement the `Renderable` trait in order to make
+    /// this function available.
+    pub fn document_url<I>(&self, uuid: I) -> Result<Url>
+    where
+        I: Into<Uuid>,
+    {
+        let mut url = self.by_id_url(uuid)?;
+        url.path_segments_mut()
+            .map_err(|_| crate::Error::UrlCannotBeBase)?
+            .push("document");
+        Ok(url)
+    }
+
+    /// This method renders the document of the object identified by `uuid`
+    /// and returns the id of the resulting file.
+    /// `Request<T>` must implement the `Renderable` trait in order to make
+    /// this function available.
+    pub async fn document_str(self, uuid: &str) -> Result<Uuid> {
+        self.document(Uuid::from_str(uuid)?).await
+    }
+
+    /// This method renders the document of the object identified by `uuid`
+    /// and returns the id of the resulting file. The file can be downloaded
+    /// using `Request<File>::by_id()`.
+    /// `Request<T>` must implement the `Renderable` trait in order to make
+    /// this function available.
+    pub async fn document<I>(self, uuid: I) -> Result<Uuid>
+    where
+        I: Into<Uuid> + Send,
+    {
+        let url = self.document_url(uuid)?;
+        to_json_response::<DocumentResponse>(
+            self.client().http_builder(Method::GET, url),
+        )
+        .await
+        .map(|x| x.document_file_id)
+    }
+
+    /// This method renders the document of the object identified by `uuid`
+    /// and writes the resulting file to `path`. The id of the file is
+    /// returned.
+    /// `Request<T>` must implement the `Renderable` trait in order to make
+    /// this function available.
+    #[cfg(all(feature = "fs", not(target_arch = "wasm32")))]
+    pub async fn download_document<I, P>(self, uuid: I, path: P) -> Result<Uuid>
+    where
+        I: Into<Uuid> + Send,
+        P: AsRef<std::path::Path> + Send + Sync,
+    {
+        use crate::model::File;
+        use crate::request::Request;
+
+        let files = Request::<File>::new(self.client().clone());
+        let file_id = self.document(uuid).await?;
+        files.download_path(file_id, path).await?;
+        Ok(file_id)
+    }
+}
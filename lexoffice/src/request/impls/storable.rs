@@ -26,9 +26,9 @@ where
     {
         let object = object.into();
         let url = self.url();
-        Ok(to_json_response::<ResultInfo<T>>(
+        to_json_response::<ResultInfo<T>>(
             self.client().http_builder(Method::POST, url).json(&object),
         )
-        .await?)
+        .await
     }
 }
@@ -1,3 +1,5 @@
+use super::ById;
+use crate::error::VersionConflict;
 use crate::request::Endpoint;
 use crate::request::HasId;
 use crate::request::RequestWithState;
@@ -8,9 +10,16 @@ use crate::Error;
 use mime::APPLICATION_JSON;
 use reqwest::header::CONTENT_TYPE;
 use reqwest::Method;
+use reqwest::StatusCode;
+use reqwest::Url;
 use serde::{de::DeserializeOwned, Serialize};
+use std::str::FromStr;
 use uuid::Uuid;
 
+/// The number of times `Request::update_with()` retries an update after a
+/// version conflict.
+const DEFAULT_CONFLICT_RETRIES: usize = 3;
+
 /// This trait marks a `Request` as `Updatable` and unlocks the
 /// `Request::update()` method.
 pub trait Updatable {}
@@ -35,6 +44,11 @@ where
     /// This method allows to update an existing model object. Please note, that
     /// `Request<T>` must implement the `Updatable` trait in order to make
     /// this function available.
+    ///
+    /// If the object was changed on the server since it was fetched,
+    /// `Error::VersionConflict` is returned. To report the current version
+    /// in `VersionConflict::current_version()`, the object is fetched with
+    /// an additional GET request in this case.
     pub async fn update_with_id<I, U>(
         self,
         uuid: U,
@@ -44,17 +58,139 @@ where
         I: Into<T> + Send + HasId,
         U: Into<Uuid>,
     {
-        let object = object.into();
-        let mut url = self.url().clone();
+        let url = self.object_url(uuid.into())?;
+        match self.put(url.clone(), &object.into()).await {
+            Err(Error::LexOffice(error))
+                if error.status() == StatusCode::CONFLICT =>
+            {
+                let current_version = self
+                    .get_value(url)
+                    .await
+                    .ok()
+                    .and_then(|x| x["version"].as_u64());
+                Err(VersionConflict::new(current_version, error).into())
+            }
+            result => result,
+        }
+    }
+
+    fn object_url(&self, uuid: Uuid) -> Result<Url> {
+        let mut url = self.url();
         url.path_segments_mut()
-            .unwrap()
-            .push(&uuid.into().to_string());
-        Ok(to_json_response::<ResultInfo<T>>(
+            .map_err(|_| Error::UrlCannotBeBase)?
+            .push(&uuid.to_string());
+        Ok(url)
+    }
+
+    /// Sends the update without any handling of version conflicts.
+    async fn put(&self, url: Url, object: &T) -> Result<ResultInfo<T>> {
+        to_json_response(
             self.client()
                 .http_builder(Method::PUT, url)
                 .header(CONTENT_TYPE, APPLICATION_JSON.as_ref())
-                .json(&object),
+                .json(object),
         )
-        .await?)
+        .await
+    }
+
+    async fn get_value(&self, url: Url) -> Result<serde_json::Value> {
+        to_json_response(self.client().http_builder(Method::GET, url)).await
+    }
+}
+
+impl<T, S> RequestWithState<T, S>
+where
+    Self: Endpoint + Updatable + ById,
+    T: Serialize + DeserializeOwned + HasId + Send + Clone,
+    S: Clone,
+{
+    /// This method fetches the object identified by `uuid`, applies `f` to it
+    /// and updates it. If the object was changed on the server in the
+    /// meantime, it is fetched again and `f` is reapplied.
+    /// `Request<T>` must implement the `Updatable` and `ById` traits in order
+    /// to make this function available.
+    pub async fn update_with_str<F>(
+        self,
+        uuid: &str,
+        f: F,
+    ) -> Result<ResultInfo<T>>
+    where
+        F: FnMut(&mut T) -> Result<()>,
+    {
+        self.update_with(Uuid::from_str(uuid)?, f).await
+    }
+
+    /// This method fetches the object identified by `uuid`, applies `f` to it
+    /// and updates it. If the object was changed on the server in the
+    /// meantime, it is fetched again and `f` is reapplied.
+    /// `Request<T>` must implement the `Updatable` and `ById` traits in order
+    /// to make this function available.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lexoffice::{ApiKey, Client};
+    /// use lexoffice::model::Contact;
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new(ApiKey::try_default().await?);
+    /// let uuid = uuid::Uuid::parse_str("f4add52b-44e3-474a-b718-890885094d9a")?;
+    /// let result = client
+    ///     .request::<Contact>()
+    ///     .update_with(uuid, |contact| {
+    ///         contact.note = Some("Key account".to_string());
+    ///         Ok(())
+    ///     })
+    ///     .await?;
+    /// println!("{:#?}", result);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_with<U, F>(self, uuid: U, f: F) -> Result<ResultInfo<T>>
+    where
+        U: Into<Uuid>,
+        F: FnMut(&mut T) -> Result<()>,
+    {
+        self.update_with_retries(uuid, DEFAULT_CONFLICT_RETRIES, f)
+            .await
+    }
+
+    /// Like `Request::update_with()`, but allows to set the number of times
+    /// the update is retried after a version conflict.
+    pub async fn update_with_retries<U, F>(
+        self,
+        uuid: U,
+        retries: usize,
+        mut f: F,
+    ) -> Result<ResultInfo<T>>
+    where
+        U: Into<Uuid>,
+        F: FnMut(&mut T) -> Result<()>,
+    {
+        let url = self.object_url(uuid.into())?;
+        let mut current = self.get_value(url.clone()).await?;
+        let mut retries_left = retries;
+        loop {
+            let mut object: T = serde_json::from_value(current)?;
+            f(&mut object)?;
+            let error = match self.put(url.clone(), &object).await {
+                Err(Error::LexOffice(error))
+                    if error.status() == StatusCode::CONFLICT =>
+                {
+                    error
+                }
+                result => return result,
+            };
+            // The object is fetched once per conflict: to retry the update
+            // or to report the current version if no retries are left.
+            let fetched = self.get_value(url.clone()).await;
+            if retries_left == 0 {
+                let current_version =
+                    fetched.ok().and_then(|x| x["version"].as_u64());
+                return Err(VersionConflict::new(current_version, error).into());
+            }
+            retries_left -= 1;
+            current = fetched?;
+        }
     }
 }
@@ -1,12 +1,21 @@
 use crate::model::Invoice;
 use crate::request::impls::ById;
+use crate::request::impls::Finalizable;
+use crate::request::impls::Finalized;
+use crate::request::impls::Pursuable;
+use crate::request::impls::Pursued;
+use crate::request::impls::Renderable;
+use crate::request::impls::Storable;
 use crate::request::Endpoint;
 use crate::request::Request;
+use crate::request::RequestWithState;
 
-impl Endpoint for Request<Invoice> {
+impl<S: Clone> Endpoint for RequestWithState<Invoice, S> {
     const ENDPOINT: &'static str = "invoices";
 }
 
+total_price_money!(crate::model::invoices::TotalPrice);
+
 /// # Examples
 ///
 /// ```
@@ -23,3 +32,145 @@ impl Endpoint for Request<Invoice> {
 /// ```
 ///
 impl ById for Request<Invoice> {}
+
+/// # Examples
+///
+/// ```
+/// use lexoffice::{ApiKey, Client};
+/// use lexoffice::model::Invoice;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(ApiKey::try_default().await?);
+/// let uuid = uuid::Uuid::parse_str("f4add52b-44e3-474a-b718-890885094d9a")?;
+/// let file_id = client.request::<Invoice>().document(uuid).await?;
+/// println!("{}", file_id);
+/// # Ok(())
+/// # }
+/// ```
+///
+impl Renderable for Request<Invoice> {}
+
+/// # Examples
+///
+/// ```
+/// use lexoffice::{ApiKey, Client};
+/// use lexoffice::model::Invoice;
+/// use lexoffice::model::invoices::*;
+/// use lexoffice::types::{Amount, Currency};
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(ApiKey::try_default().await?);
+/// let invoice = Invoice::builder()
+///     .voucher_date(chrono::Utc::now())
+///     .address(
+///         Address::builder()
+///             .name("Bike & Ride GmbH & Co. KG".to_string())
+///             .country_code("DE".to_string())
+///             .build(),
+///     )
+///     .line_items(vec![LineItems::builder()
+///         ._type(Type::Custom)
+///         .name("Energieriegel Testpaket".to_string())
+///         .quantity(1.0)
+///         .unit_name("Stück".to_string())
+///         .unit_price(
+///             UnitPrice::builder()
+///                 .currency(Currency::EUR)
+///                 .net_amount(Amount::new(5, 0))
+///                 .tax_rate_percentage(19.0)
+///                 .build(),
+///         )
+///         .build()])
+///     .total_price(TotalPrice::builder().currency(Currency::EUR).build())
+///     .tax_conditions(TaxConditions::builder().tax_type(TaxType::Net).build())
+///     .shipping_conditions(
+///         ShippingConditions::builder()
+///             .shipping_type(ShippingType::None)
+///             .build(),
+///     )
+///     .build();
+/// let result = client.request::<Invoice>().save(invoice).await?;
+/// println!("{:#?}", result);
+/// # Ok(())
+/// # }
+/// ```
+///
+impl Storable for Request<Invoice> {}
+
+/// # Examples
+///
+/// ```
+/// use lexoffice::{ApiKey, Client};
+/// use lexoffice::model::Invoice;
+///
+/// # async fn run(invoice: Invoice) -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(ApiKey::try_default().await?);
+/// let result = client.request::<Invoice>().finalize().save(invoice).await?;
+/// println!("{:#?}", result);
+/// # Ok(())
+/// # }
+/// ```
+///
+impl Finalizable for Request<Invoice> {}
+
+/// Saves invoices in status **open**. See `Request::finalize()`.
+impl Storable for RequestWithState<Invoice, Finalized> {}
+
+/// # Examples
+///
+/// ```
+/// use lexoffice::{ApiKey, Client};
+/// use lexoffice::model::Invoice;
+///
+/// # async fn run(invoice: Invoice) -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(ApiKey::try_default().await?);
+/// let order_confirmation_id =
+///     uuid::Uuid::parse_str("f4add52b-44e3-474a-b718-890885094d9a")?;
+/// let result = client
+///     .request::<Invoice>()
+///     .pursue(order_confirmation_id)
+///     .finalize()
+///     .save(invoice)
+///     .await?;
+/// println!("{:#?}", result);
+/// # Ok(())
+/// # }
+/// ```
+///
+impl Pursuable for Request<Invoice> {}
+
+/// Saves invoices as successors of another sales voucher. See
+/// `Request::pursue()`.
+impl Storable for RequestWithState<Invoice, Pursued> {}
+
+/// Allows to finalize invoices that pursue another sales voucher. See
+/// `Request::finalize()`.
+impl Finalizable for RequestWithState<Invoice, Pursued> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::invoices::TotalPrice;
+    use crate::types::{Amount, Currency, Money};
+
+    #[test]
+    fn total_price_as_money() {
+        let total: TotalPrice = serde_json::from_str(
+            r#"{
+                "currency": "EUR",
+                "totalNetAmount": 26.72,
+                "totalGrossAmount": 29.85,
+                "totalTaxAmount": null
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            total.total_net(),
+            Some(Money::new(Amount::new(2672, 2), Currency::EUR))
+        );
+        assert_eq!(
+            total.total_gross(),
+            Some(Money::new(Amount::new(2985, 2), Currency::EUR))
+        );
+        assert_eq!(total.total_tax(), None);
+    }
+}
@@ -1,4 +1,40 @@
 //! module that handles requests to the Lexoffice API
+
+/// Implements accessors that return the totals of a `TotalPrice` as `Money`
+macro_rules! total_price_money {
+    ( $x:path ) => {
+        impl $x {
+            /// Returns the total net amount, or `None` if the amount or the
+            /// currency is unset.
+            pub fn total_net(&self) -> Option<crate::types::Money> {
+                self.money(self.total_net_amount)
+            }
+
+            /// Returns the total gross amount, or `None` if the amount or the
+            /// currency is unset.
+            pub fn total_gross(&self) -> Option<crate::types::Money> {
+                self.money(self.total_gross_amount)
+            }
+
+            /// Returns the total tax amount, or `None` if the amount or the
+            /// currency is unset.
+            pub fn total_tax(&self) -> Option<crate::types::Money> {
+                self.money(self.total_tax_amount)
+            }
+
+            fn money(
+                &self,
+                amount: Option<crate::types::Amount>,
+            ) -> Option<crate::types::Money> {
+                let currency: Option<crate::types::Currency> =
+                    self.currency.clone().into();
+                Some(crate::types::Money::new(amount?, currency?))
+            }
+        }
+    };
+}
+
+mod articles;
 mod contacts;
 mod countries;
 mod credit_notes;
@@ -6,20 +42,23 @@ mod delivery_notes;
 mod down_payment_invoices;
 mod dunnings;
 mod event_subscriptions;
-#[cfg(not(target_arch = "wasm32"))]
 mod files;
 mod invoices;
 mod order_confirmations;
+mod payment_conditions;
 mod payments;
 mod posting_categories;
 mod profile;
 mod quotations;
+mod recurring_templates;
 mod voucherlist;
 mod vouchers;
 
 pub mod stream;
 
 mod impls;
+pub use credit_notes::Cancellation;
+pub use files::FileContent;
 pub use impls::*;
 pub use voucherlist::*;
 
@@ -1,12 +1,19 @@
 use crate::model::OrderConfirmation;
 use crate::request::impls::ById;
+use crate::request::impls::Pursuable;
+use crate::request::impls::Pursued;
+use crate::request::impls::Renderable;
+use crate::request::impls::Storable;
 use crate::request::Endpoint;
 use crate::request::Request;
+use crate::request::RequestWithState;
 
-impl Endpoint for Request<OrderConfirmation> {
+impl<S: Clone> Endpoint for RequestWithState<OrderConfirmation, S> {
     const ENDPOINT: &'static str = "order-confirmations";
 }
 
+total_price_money!(crate::model::order_confirmations::TotalPrice);
+
 /// # Examples
 ///
 /// ```
@@ -23,3 +30,67 @@ impl Endpoint for Request<OrderConfirmation> {
 /// ```
 ///
 impl ById for Request<OrderConfirmation> {}
+
+/// # Examples
+///
+/// ```
+/// use lexoffice::{ApiKey, Client};
+/// use lexoffice::model::OrderConfirmation;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(ApiKey::try_default().await?);
+/// let uuid = uuid::Uuid::parse_str("f4add52b-44e3-474a-b718-890885094d9a")?;
+/// let file_id = client.request::<OrderConfirmation>().document(uuid).await?;
+/// println!("{}", file_id);
+/// # Ok(())
+/// # }
+/// ```
+///
+impl Renderable for Request<OrderConfirmation> {}
+
+/// Order confirmations are always created in status **draft** and can't be
+/// finalized via the API.
+///
+/// # Examples
+///
+/// ```
+/// use lexoffice::{ApiKey, Client};
+/// use lexoffice::model::OrderConfirmation;
+///
+/// # async fn run(order_confirmation: OrderConfirmation) -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(ApiKey::try_default().await?);
+/// let result = client
+///     .request::<OrderConfirmation>()
+///     .save(order_confirmation)
+///     .await?;
+/// println!("{:#?}", result);
+/// # Ok(())
+/// # }
+/// ```
+///
+impl Storable for Request<OrderConfirmation> {}
+
+/// # Examples
+///
+/// ```
+/// use lexoffice::{ApiKey, Client};
+/// use lexoffice::model::OrderConfirmation;
+///
+/// # async fn run(order_confirmation: OrderConfirmation) -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(ApiKey::try_default().await?);
+/// let quotation_id = uuid::Uuid::parse_str("f4add52b-44e3-474a-b718-890885094d9a")?;
+/// let result = client
+///     .request::<OrderConfirmation>()
+///     .pursue(quotation_id)
+///     .save(order_confirmation)
+///     .await?;
+/// println!("{:#?}", result);
+/// # Ok(())
+/// # }
+/// ```
+///
+impl Pursuable for Request<OrderConfirmation> {}
+
+/// Saves order confirmations as successors of another sales voucher. See
+/// `Request::pursue()`.
+impl Storable for RequestWithState<OrderConfirmation, Pursued> {}
@@ -0,0 +1,60 @@
+use crate::model::PaymentCondition;
+use crate::request::Endpoint;
+use crate::request::Request;
+use crate::util::to_json_response;
+use crate::Result;
+use reqwest::Method;
+
+impl Endpoint for Request<PaymentCondition> {
+    const ENDPOINT: &'static str = "payment-conditions";
+}
+
+/// # Examples
+///
+/// ```
+/// use lexoffice::{ApiKey, Client};
+/// use lexoffice::model::PaymentCondition;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(ApiKey::try_default().await?);
+/// let payment_conditions = client.request::<PaymentCondition>().get().await?;
+/// println!("{:#?}", payment_conditions);
+/// # Ok(())
+/// # }
+/// ```
+///
+impl Request<PaymentCondition> {
+    /// executes the request
+    pub async fn get(self) -> Result<Vec<PaymentCondition>> {
+        let url = self.url();
+        to_json_response(self.client().http_builder(Method::GET, url)).await
+    }
+
+    /// Returns the payment condition the user selected as the default of
+    /// the organization, if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lexoffice::{ApiKey, Client};
+    /// use lexoffice::model::PaymentCondition;
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new(ApiKey::try_default().await?);
+    /// let payment_condition = client
+    ///     .request::<PaymentCondition>()
+    ///     .organization_default()
+    ///     .await?;
+    /// println!("{:#?}", payment_condition);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn organization_default(
+        self,
+    ) -> Result<Option<PaymentCondition>> {
+        let payment_conditions = self.get().await?;
+        Ok(payment_conditions
+            .into_iter()
+            .find(|x| x.organization_default == Some(true)))
+    }
+}
@@ -1,7 +1,9 @@
+use crate::model::payments::PaymentStatus;
 use crate::model::Payment;
 use crate::request::impls::ById;
 use crate::request::Endpoint;
 use crate::request::Request;
+use crate::types::{Amount, Currency, Money};
 
 impl Endpoint for Request<Payment> {
     const ENDPOINT: &'static str = "payments";
@@ -23,3 +25,169 @@ impl Endpoint for Request<Payment> {
 /// ```
 ///
 impl ById for Request<Payment> {}
+
+impl Payment {
+    /// Returns `true` if nothing is left to be paid on the voucher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lexoffice::{ApiKey, Client};
+    /// use lexoffice::model::Payment;
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new(ApiKey::try_default().await?);
+    /// let uuid = uuid::Uuid::parse_str("a886c776-c1b2-427d-999e-a687f688da08")?;
+    /// let payment = client.request::<Payment>().by_id(uuid).await?;
+    /// if !payment.is_settled() {
+    ///     if let Some(remaining) = payment.remaining() {
+    ///         println!("{} are still open", remaining);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_settled(&self) -> bool {
+        match self.payment_status {
+            Some(PaymentStatus::Balanced) => true,
+            Some(_) => false,
+            None => self.open_amount == Some(Amount::default()),
+        }
+    }
+
+    /// Returns the amount that is still open on the voucher, or `None` if
+    /// LexOffice didn't send it.
+    pub fn remaining(&self) -> Option<Money> {
+        Some(Money::new(self.open_amount?, self.currency.clone()?))
+    }
+
+    /// Returns the sum of all payments posted on the voucher in `currency`,
+    /// or `None` if a payment is in a different currency.
+    pub fn paid(&self, currency: Currency) -> Option<Money> {
+        let items = self.payment_items.iter().flatten().map(|item| {
+            Money::new(
+                item.amount.unwrap_or_default(),
+                item.currency.clone().unwrap_or_else(|| currency.clone()),
+            )
+        });
+        Money::sum(currency.clone(), items)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn payment(json: &str) -> Payment {
+        serde_json::from_str(json).unwrap()
+    }
+
+    fn eur(num: i64) -> Money {
+        Money::new(Amount::new(num, 2), Currency::EUR)
+    }
+
+    #[test]
+    fn open_payment() {
+        let payment = payment(
+            r#"{
+                "openAmount": 119.00,
+                "currency": "EUR",
+                "paymentStatus": "openRevenue",
+                "voucherType": "invoice",
+                "voucherStatus": "open",
+                "paymentItems": []
+            }"#,
+        );
+        assert!(!payment.is_settled());
+        assert_eq!(payment.remaining(), Some(eur(11900)));
+        assert_eq!(payment.paid(Currency::EUR), Some(eur(0)));
+    }
+
+    #[test]
+    fn partly_paid_payment() {
+        let payment = payment(
+            r#"{
+                "openAmount": 19.00,
+                "currency": "EUR",
+                "paymentStatus": "openRevenue",
+                "voucherType": "invoice",
+                "voucherStatus": "open",
+                "paymentItems": [
+                    {
+                        "paymentItemType": "manualPayment",
+                        "postingDate": "2023-03-01T00:00:00.000+01:00",
+                        "amount": 60.00,
+                        "currency": "EUR"
+                    },
+                    {
+                        "paymentItemType": "manualPayment",
+                        "postingDate": "2023-03-08T00:00:00.000+01:00",
+                        "amount": 40.00,
+                        "currency": "EUR"
+                    }
+                ]
+            }"#,
+        );
+        assert!(!payment.is_settled());
+        assert_eq!(payment.remaining(), Some(eur(1900)));
+        assert_eq!(payment.paid(Currency::EUR), Some(eur(10000)));
+    }
+
+    #[test]
+    fn fully_paid_payment() {
+        let payment = payment(
+            r#"{
+                "openAmount": 0.00,
+                "currency": "EUR",
+                "paymentStatus": "balanced",
+                "voucherType": "invoice",
+                "voucherStatus": "paid",
+                "paidDate": "2023-03-08T00:00:00.000+01:00",
+                "paymentItems": [
+                    {
+                        "paymentItemType": "manualPayment",
+                        "postingDate": "2023-03-08T00:00:00.000+01:00",
+                        "amount": 119.00,
+                        "currency": "EUR"
+                    }
+                ]
+            }"#,
+        );
+        assert!(payment.is_settled());
+        assert_eq!(payment.remaining(), Some(eur(0)));
+        assert_eq!(payment.paid(Currency::EUR), Some(eur(11900)));
+    }
+
+    #[test]
+    fn settled_without_status() {
+        assert!(payment(r#"{ "openAmount": 0 }"#).is_settled());
+        assert!(!payment(r#"{ "openAmount": 1.50 }"#).is_settled());
+        assert!(!payment("{}").is_settled());
+        assert_eq!(payment(r#"{ "openAmount": 1.50 }"#).remaining(), None);
+    }
+
+    #[test]
+    fn paid_in_a_different_currency() {
+        let payment = payment(
+            r#"{
+                "openAmount": 0.00,
+                "currency": "EUR",
+                "paymentStatus": "balanced",
+                "paymentItems": [
+                    {
+                        "paymentItemType": "manualPayment",
+                        "amount": 100.00,
+                        "currency": "EUR"
+                    },
+                    {
+                        "paymentItemType": "forexGain",
+                        "amount": 19.00,
+                        "currency": "USD"
+                    }
+                ]
+            }"#,
+        );
+        assert_eq!(payment.paid(Currency::EUR), None);
+        assert_eq!(payment.paid(Currency::USD), None);
+    }
+}
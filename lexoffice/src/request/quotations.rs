@@ -1,13 +1,20 @@
 use crate::model::Quotation;
 use crate::request::impls::ById;
+use crate::request::impls::Finalizable;
+use crate::request::impls::Finalized;
 use crate::request::impls::Paginated;
+use crate::request::impls::Renderable;
+use crate::request::impls::Storable;
 use crate::request::Endpoint;
 use crate::request::Request;
+use crate::request::RequestWithState;
 
-impl Endpoint for Request<Quotation> {
+impl<S: Clone> Endpoint for RequestWithState<Quotation, S> {
     const ENDPOINT: &'static str = "quotations";
 }
 
+total_price_money!(crate::model::quotations::TotalPrice);
+
 /// # Examples
 ///
 /// ```
@@ -25,6 +32,23 @@ impl Endpoint for Request<Quotation> {
 ///
 impl ById for Request<Quotation> {}
 
+/// # Examples
+///
+/// ```
+/// use lexoffice::{ApiKey, Client};
+/// use lexoffice::model::Quotation;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(ApiKey::try_default().await?);
+/// let uuid = uuid::Uuid::parse_str("f4add52b-44e3-474a-b718-890885094d9a")?;
+/// let file_id = client.request::<Quotation>().document(uuid).await?;
+/// println!("{}", file_id);
+/// # Ok(())
+/// # }
+/// ```
+///
+impl Renderable for Request<Quotation> {}
+
 /// # Examples
 ///
 /// ```
@@ -40,3 +64,38 @@ impl ById for Request<Quotation> {}
 /// ```
 ///
 impl Paginated for Request<Quotation> {}
+
+/// # Examples
+///
+/// ```
+/// use lexoffice::{ApiKey, Client};
+/// use lexoffice::model::Quotation;
+///
+/// # async fn run(quotation: Quotation) -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(ApiKey::try_default().await?);
+/// let result = client.request::<Quotation>().save(quotation).await?;
+/// println!("{:#?}", result);
+/// # Ok(())
+/// # }
+/// ```
+///
+impl Storable for Request<Quotation> {}
+
+/// # Examples
+///
+/// ```
+/// use lexoffice::{ApiKey, Client};
+/// use lexoffice::model::Quotation;
+///
+/// # async fn run(quotation: Quotation) -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(ApiKey::try_default().await?);
+/// let result = client.request::<Quotation>().finalize().save(quotation).await?;
+/// println!("{:#?}", result);
+/// # Ok(())
+/// # }
+/// ```
+///
+impl Finalizable for Request<Quotation> {}
+
+/// Saves quotations in status **open**. See `Request::finalize()`.
+impl Storable for RequestWithState<Quotation, Finalized> {}
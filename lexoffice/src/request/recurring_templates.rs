@@ -1,12 +1,122 @@
+use crate::model::recurring_templates::*;
 use crate::model::RecurringTemplate;
 use crate::request::impls::ById;
+use crate::request::impls::Paginated;
 use crate::request::Endpoint;
 use crate::request::Request;
+use crate::types::Date;
+use chrono::{Datelike, Duration};
 
 impl Endpoint for Request<RecurringTemplate> {
     const ENDPOINT: &'static str = "recurring-templates";
 }
 
+total_price_money!(crate::model::recurring_templates::TotalPrice);
+
+/// Returns `date` moved by `months` months. The day is clamped to the last
+/// day of the resulting month, e.g. January 31st plus one month is February
+/// 28th or 29th.
+fn add_months(date: Date, months: u32) -> Option<Date> {
+    let months = date.month0() + months;
+    let year = date.year() + (months / 12) as i32;
+    let month = months % 12 + 1;
+    (1..=date.day())
+        .rev()
+        .find_map(|day| Date::from_ymd_opt(year, month, day))
+}
+
+impl RecurringTemplateSettings {
+    /// Returns up to `n` dates on which the recurring template is executed
+    /// from today on. See
+    /// `RecurringTemplateSettings::upcoming_executions_from()`.
+    pub fn upcoming_executions(&self, n: usize) -> Vec<Date> {
+        self.upcoming_executions_from(chrono::Utc::now().naive_utc().date(), n)
+    }
+
+    /// Returns up to `n` dates on or after `from` on which the recurring
+    /// template is executed.
+    ///
+    /// The dates are computed from `start_date` in steps of
+    /// `execution_interval` and end at `end_date`. Dates before
+    /// `next_execution_date` are skipped. Paused and ended templates as well
+    /// as templates with an unknown interval have no upcoming executions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use lexoffice::model::recurring_templates::*;
+    ///
+    /// let settings = RecurringTemplateSettings::builder()
+    ///     .start_date(NaiveDate::from_ymd_opt(2021, 1, 31).unwrap())
+    ///     .end_date(NaiveDate::from_ymd_opt(2021, 6, 30).unwrap())
+    ///     .execution_interval(ExecutionInterval::Monthly)
+    ///     .build();
+    /// let from = NaiveDate::from_ymd_opt(2021, 3, 1).unwrap();
+    /// let dates = settings
+    ///     .upcoming_executions_from(from, 10)
+    ///     .iter()
+    ///     .map(|x| x.to_string())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(dates, ["2021-03-31", "2021-04-30", "2021-05-31", "2021-06-30"]);
+    /// ```
+    pub fn upcoming_executions_from(&self, from: Date, n: usize) -> Vec<Date> {
+        self.executions_from(from).take(n).collect()
+    }
+
+    /// Returns all dates from `from` up to and including `until` on which
+    /// the recurring template is executed. See
+    /// `RecurringTemplateSettings::upcoming_executions_from()`.
+    pub fn upcoming_executions_until(
+        &self,
+        from: Date,
+        until: Date,
+    ) -> Vec<Date> {
+        self.executions_from(from)
+            .take_while(|x| *x <= until)
+            .collect()
+    }
+
+    /// Returns an iterator over the execution dates on or after `from`.
+    fn executions_from(&self, from: Date) -> impl Iterator<Item = Date> + '_ {
+        let start = match self.execution_status {
+            Some(ExecutionStatus::Paused) | Some(ExecutionStatus::Ended) => {
+                None
+            }
+            _ => self.start_date.or(self.next_execution_date),
+        };
+        let step = move |k: u32| {
+            let start = start?;
+            match self.execution_interval.as_ref()? {
+                ExecutionInterval::Weekly => {
+                    start.checked_add_signed(Duration::weeks(k as i64))
+                }
+                ExecutionInterval::Biweekly => {
+                    start.checked_add_signed(Duration::weeks(2 * k as i64))
+                }
+                ExecutionInterval::Monthly => add_months(start, k),
+                ExecutionInterval::Quarterly => add_months(start, 3 * k),
+                ExecutionInterval::Biannually => add_months(start, 6 * k),
+                ExecutionInterval::Annually => add_months(start, 12 * k),
+                ExecutionInterval::Unknown(_) => None,
+            }
+        };
+        let from = match self.next_execution_date {
+            Some(next) => from.max(next),
+            None => from,
+        };
+        (0..)
+            .map(step)
+            .take_while(move |x| match (x, self.end_date) {
+                (Some(date), Some(end_date)) => *date <= end_date,
+                (Some(_), None) => true,
+                (None, _) => false,
+            })
+            .flatten()
+            .filter(move |x| *x >= from)
+    }
+}
+
 /// # Examples
 ///
 /// ```
@@ -23,3 +133,146 @@ impl Endpoint for Request<RecurringTemplate> {
 /// ```
 ///
 impl ById for Request<RecurringTemplate> {}
+
+/// The pages only contain the contact, the total price, the payment
+/// conditions and the settings of the recurring templates.
+///
+/// # Examples
+///
+/// ```
+/// use futures::TryStreamExt;
+/// use lexoffice::{ApiKey, Client};
+/// use lexoffice::model::RecurringTemplate;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(ApiKey::try_default().await?);
+/// let recurring_templates = client
+///     .request::<RecurringTemplate>()
+///     .stream()
+///     .try_collect::<Vec<_>>()
+///     .await?;
+/// for template in recurring_templates {
+///     if let Some(settings) = template.recurring_template_settings {
+///         println!("{:?}: {:?}", template.title, settings.upcoming_executions(3));
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+impl Paginated for Request<RecurringTemplate> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> Date {
+        s.parse().unwrap()
+    }
+
+    fn settings(
+        start_date: &str,
+        execution_interval: ExecutionInterval,
+    ) -> RecurringTemplateSettings {
+        RecurringTemplateSettings::builder()
+            .start_date(date(start_date))
+            .execution_interval(execution_interval)
+            .build()
+    }
+
+    fn executions(
+        settings: &RecurringTemplateSettings,
+        from: &str,
+        n: usize,
+    ) -> Vec<String> {
+        settings
+            .upcoming_executions_from(date(from), n)
+            .iter()
+            .map(|x| x.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn weekly_and_biweekly() {
+        let weekly = settings("2021-03-01", ExecutionInterval::Weekly);
+        assert_eq!(
+            executions(&weekly, "2021-03-10", 3),
+            ["2021-03-15", "2021-03-22", "2021-03-29"]
+        );
+        let biweekly = settings("2021-03-01", ExecutionInterval::Biweekly);
+        assert_eq!(
+            executions(&biweekly, "2021-03-01", 3),
+            ["2021-03-01", "2021-03-15", "2021-03-29"]
+        );
+    }
+
+    #[test]
+    fn quarterly_and_biannually() {
+        let quarterly = settings("2021-01-31", ExecutionInterval::Quarterly);
+        assert_eq!(
+            executions(&quarterly, "2021-02-01", 3),
+            ["2021-04-30", "2021-07-31", "2021-10-31"]
+        );
+        let biannually = settings("2021-08-31", ExecutionInterval::Biannually);
+        assert_eq!(
+            executions(&biannually, "2021-01-01", 3),
+            ["2021-08-31", "2022-02-28", "2022-08-31"]
+        );
+    }
+
+    #[test]
+    fn annually_from_leap_day() {
+        let annually = settings("2020-02-29", ExecutionInterval::Annually);
+        assert_eq!(
+            executions(&annually, "2020-03-01", 4),
+            ["2021-02-28", "2022-02-28", "2023-02-28", "2024-02-29"]
+        );
+    }
+
+    #[test]
+    fn executions_end_at_end_date() {
+        let mut monthly = settings("2021-01-15", ExecutionInterval::Monthly);
+        monthly.end_date = Some(date("2021-03-15"));
+        assert_eq!(
+            executions(&monthly, "2021-01-01", 10),
+            ["2021-01-15", "2021-02-15", "2021-03-15"]
+        );
+        monthly.end_date = Some(date("2021-03-14"));
+        assert_eq!(
+            executions(&monthly, "2021-01-01", 10),
+            ["2021-01-15", "2021-02-15"]
+        );
+    }
+
+    #[test]
+    fn paused_and_ended_templates_have_no_executions() {
+        let mut monthly = settings("2021-01-15", ExecutionInterval::Monthly);
+        monthly.execution_status = Some(ExecutionStatus::Paused);
+        assert!(executions(&monthly, "2021-01-01", 3).is_empty());
+        monthly.execution_status = Some(ExecutionStatus::Ended);
+        assert!(executions(&monthly, "2021-01-01", 3).is_empty());
+        monthly.execution_status = Some(ExecutionStatus::Active);
+        assert_eq!(executions(&monthly, "2021-01-01", 3).len(), 3);
+    }
+
+    #[test]
+    fn executions_start_at_next_execution_date() {
+        let mut monthly = settings("2021-01-01", ExecutionInterval::Monthly);
+        monthly.next_execution_date = Some(date("2021-04-01"));
+        assert_eq!(
+            executions(&monthly, "2021-02-01", 2),
+            ["2021-04-01", "2021-05-01"]
+        );
+    }
+
+    #[test]
+    fn executions_until_stop_after_the_bound() {
+        let weekly = settings("2021-03-01", ExecutionInterval::Weekly);
+        let dates = weekly
+            .upcoming_executions_until(date("2021-03-02"), date("2021-03-22"));
+        assert_eq!(
+            dates,
+            [date("2021-03-08"), date("2021-03-15"), date("2021-03-22")]
+        );
+    }
+}
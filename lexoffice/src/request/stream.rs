@@ -4,111 +4,237 @@ use crate::error::Error;
 use crate::model::Page;
 use crate::request::impls::Paginated;
 use crate::request::Endpoint;
+use crate::request::HasId;
 use crate::request::RequestWithState;
 use crate::result::Result;
-use futures::stream::Stream;
+use futures::stream::{FuturesOrdered, Stream, StreamExt};
 use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashSet, VecDeque};
 use std::future::Future;
-use std::ops::Range;
 use std::pin::Pin;
 use std::task::Context;
 use std::task::Poll;
 use std::vec::IntoIter;
+use uuid::Uuid;
 
 #[cfg(target_arch = "wasm32")]
 type FutureType<T> = dyn Future<Output = Result<Page<T>>>;
 #[cfg(not(target_arch = "wasm32"))]
 type FutureType<T> = dyn Future<Output = Result<Page<T>>> + Send;
 
+/// Requires `Send + Sync` on native targets. Futures on `wasm32` are
+/// single-threaded, so there this trait is implemented for all types.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait MaybeSendSync: Send + Sync {}
+#[cfg(not(target_arch = "wasm32"))]
+impl<T: Send + Sync> MaybeSendSync for T {}
+/// Requires `Send + Sync` on native targets. Futures on `wasm32` are
+/// single-threaded, so there this trait is implemented for all types.
+#[cfg(target_arch = "wasm32")]
+pub trait MaybeSendSync {}
+#[cfg(target_arch = "wasm32")]
+impl<T> MaybeSendSync for T {}
+
+/// The position of a `PageStream`. A cursor can be serialized to resume an
+/// interrupted stream later on with `PageStream::resume()`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Cursor {
+    /// The page that contains the next item
+    pub page: usize,
+    /// The number of items of `page` that were already returned
+    pub offset: usize,
+    /// The page size requested from LexOffice
+    pub page_size: Option<usize>,
+    /// The filters of the request
+    pub query: Option<String>,
+}
+
 /// Stream that allows to view multiple pages as contiguous stream of Page items `T`.
+///
+/// Once the first page was received, up to `prefetch` further pages are
+/// requested concurrently. Items that moved between pages while streaming are
+/// returned only once.
+///
+/// # Examples
+///
+/// ```
+/// use futures::stream::TryStreamExt;
+/// use lexoffice::{ApiKey, Client};
+/// use lexoffice::model::Voucherlist;
+/// use lexoffice::model::voucherlist::{VoucherStatus, VoucherType};
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(ApiKey::try_default().await?);
+/// let mut stream = client
+///     .request::<Voucherlist>()
+///     .type_(&VoucherType::Invoice)
+///     .status(&VoucherStatus::Open)
+///     .stream()
+///     .page_size(250)
+///     .prefetch(4);
+/// while let Some(voucher) = stream.try_next().await? {
+///     println!("{:?}", voucher.voucher_number);
+///     // Store the cursor to resume the export with `PageStream::resume()`
+///     let cursor = serde_json::to_string(&stream.cursor())?;
+///     std::fs::write("voucherlist.cursor", cursor)?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
 pub struct PageStream<T, S>
 where
     RequestWithState<T, S>: Paginated + Clone + Endpoint,
     T: DeserializeOwned + Clone,
-    S: Sync + Send + Clone + 'static,
+    S: MaybeSendSync + Clone + 'static,
 {
     request: RequestWithState<T, S>,
-    future: Option<Pin<Box<FutureType<T>>>>,
-    pages: Option<Range<usize>>,
+    futures: FuturesOrdered<Pin<Box<FutureType<T>>>>,
+    page_size: Option<usize>,
+    prefetch: usize,
+    /// The next page that will be requested
+    next_page: usize,
+    /// The page `iter` is taken from
+    page: usize,
+    /// The number of items taken from `page`
+    offset: usize,
+    /// The number of items to skip when `page` is received
+    skip: usize,
+    total_pages: Option<usize>,
+    /// Pages that were received before they were needed
+    received: VecDeque<Result<Page<T>>>,
     iter: Option<IntoIter<T>>,
+    seen: HashSet<Uuid>,
+    finished: bool,
 }
 
 impl<T, S> From<RequestWithState<T, S>> for PageStream<T, S>
 where
     RequestWithState<T, S>: Paginated + Clone + Endpoint,
-    T: DeserializeOwned + Sync + Send + Clone + 'static,
-    S: Sync + Send + Clone + 'static,
+    T: DeserializeOwned + MaybeSendSync + Clone + 'static,
+    S: MaybeSendSync + Clone + 'static,
 {
     fn from(request: RequestWithState<T, S>) -> Self {
-        let request_clone = request.clone();
-
         Self {
             request,
-            future: Some(Box::pin(request_clone.page(0))),
-            pages: None,
+            futures: FuturesOrdered::new(),
+            page_size: None,
+            prefetch: 0,
+            next_page: 0,
+            page: 0,
+            offset: 0,
+            skip: 0,
+            total_pages: None,
+            received: VecDeque::new(),
             iter: None,
+            seen: HashSet::new(),
+            finished: false,
         }
     }
 }
 
 impl<T, S> PageStream<T, S>
 where
-    RequestWithState<T, S>: Endpoint + Paginated + Unpin + Sync + Send + Clone,
-    T: DeserializeOwned + Unpin + Sync + Send + Clone + 'static,
-    S: Sync + Send + Clone + 'static,
+    RequestWithState<T, S>:
+        Endpoint + Paginated + Unpin + MaybeSendSync + Clone,
+    T: DeserializeOwned + Unpin + MaybeSendSync + Clone + 'static,
+    S: MaybeSendSync + Clone + 'static,
 {
-    fn poll_item(&mut self) -> Option<T> {
-        self.iter.as_mut().and_then(|x| x.next())
+    /// Sets the number of items requested per page. LexOffice allows up to
+    /// 250 items per page for most endpoints.
+    pub fn page_size(mut self, page_size: usize) -> Self {
+        self.page_size = Some(page_size);
+        self
     }
 
-    fn poll_future(
-        mut self: Pin<&mut Self>,
-        cx: &mut Context<'_>,
-    ) -> Poll<Option<Result<T>>> {
-        if let Some(future) = self.future.as_mut() {
-            match Pin::new(future).poll(cx) {
-                Poll::Ready(Ok(page)) => self.on_new_page(page),
-                Poll::Ready(Err(err)) => self.on_error(err),
-                Poll::Pending => Poll::Pending,
-            }
-        } else {
-            Poll::Ready(None)
+    /// Sets the number of pages that are requested ahead of time, once the
+    /// total number of pages is known. Defaults to `0`.
+    pub fn prefetch(mut self, prefetch: usize) -> Self {
+        self.prefetch = prefetch;
+        self
+    }
+
+    /// Continues the stream at the position of `cursor`. The page size and
+    /// the filters of `cursor` replace those of this stream.
+    pub fn resume(mut self, cursor: Cursor) -> Self {
+        self.request.url.set_query(cursor.query.as_deref());
+        self.page_size = cursor.page_size;
+        self.next_page = cursor.page;
+        self.page = cursor.page;
+        self.skip = cursor.offset;
+        self
+    }
+
+    /// Returns the current position of this stream.
+    pub fn cursor(&self) -> Cursor {
+        let (page, offset) = match self.iter.as_ref() {
+            Some(iter) if iter.len() == 0 => (self.page + 1, 0),
+            Some(_) => (self.page, self.offset),
+            None => (self.page, self.skip),
+        };
+        Cursor {
+            page,
+            offset,
+            page_size: self.page_size,
+            query: self.request.url.query().map(str::to_string),
         }
     }
 
-    fn on_new_page(
-        mut self: Pin<&mut Self>,
-        page: Page<T>,
-    ) -> Poll<Option<Result<T>>> {
-        let request = self.request.clone();
-        let pages = self.pages.get_or_insert(1..page.total_pages);
-        self.future = match pages.next() {
-            Some(x) => Some(Box::pin(request.page(x))),
-            None => None,
+    fn request_pages(&mut self) {
+        if self.finished {
+            return;
+        }
+        // the page that is needed next
+        let current = match self.iter {
+            Some(_) => self.page + 1,
+            None => self.page,
+        };
+        let end = match self.total_pages {
+            Some(total_pages) => total_pages.min(current + self.prefetch + 1),
+            None => current + 1,
         };
+        for page in self.next_page..end {
+            let request = self.request.clone();
+            let future: Pin<Box<FutureType<T>>> = match self.page_size {
+                Some(size) => Box::pin(request.page_size(page, size)),
+                None => Box::pin(request.page(page)),
+            };
+            self.futures.push_back(future);
+        }
+        self.next_page = self.next_page.max(end);
+    }
+
+    fn on_new_page(&mut self, page: Page<T>) {
+        if self.iter.is_some() {
+            self.page += 1;
+        }
+        self.total_pages = Some(page.total_pages);
 
         let mut iter = page.content.into_iter();
-        let first_item = iter.next().map(Ok);
+        let skip = self.skip.min(iter.len());
+        if skip > 0 {
+            iter.nth(skip - 1);
+        }
+        self.offset = skip;
+        self.skip = 0;
         self.iter = Some(iter);
-
-        Poll::Ready(first_item)
     }
 
-    fn on_error(
-        mut self: Pin<&mut Self>,
-        err: Error,
-    ) -> Poll<Option<Result<T>>> {
-        self.future = None;
-        self.pages = None;
+    fn on_error(&mut self, err: Error) -> Poll<Option<Result<T>>> {
+        self.futures = FuturesOrdered::new();
+        self.received.clear();
+        self.finished = true;
         Poll::Ready(Some(Err(err)))
     }
 }
 
 impl<T, S> Stream for PageStream<T, S>
 where
-    RequestWithState<T, S>: Endpoint + Paginated + Unpin + Sync + Send + Clone,
-    T: DeserializeOwned + Unpin + Send + Sync + Clone + 'static,
-    S: Sync + Send + Clone + 'static,
+    RequestWithState<T, S>:
+        Endpoint + Paginated + Unpin + MaybeSendSync + Clone,
+    T: DeserializeOwned + HasId + Unpin + MaybeSendSync + Clone + 'static,
+    S: MaybeSendSync + Clone + 'static,
 {
     type Item = Result<T>;
 
@@ -116,14 +242,156 @@ where
         mut self: Pin<&mut Self>,
         cx: &mut Context<'_>,
     ) -> Poll<Option<Self::Item>> {
-        if let Some(item) = self.poll_item() {
-            Poll::Ready(Some(Ok(item)))
-        } else {
-            self.poll_future(cx)
+        loop {
+            self.request_pages();
+            while let Poll::Ready(Some(page)) = self.futures.poll_next_unpin(cx)
+            {
+                self.received.push_back(page);
+            }
+
+            while let Some(item) = self.iter.as_mut().and_then(|x| x.next()) {
+                self.offset += 1;
+                match item.id() {
+                    Some(id) if !self.seen.insert(*id) => continue,
+                    _ => return Poll::Ready(Some(Ok(item))),
+                }
+            }
+
+            match self.received.pop_front() {
+                Some(Ok(page)) => self.on_new_page(page),
+                Some(Err(err)) => return self.on_error(err),
+                None if self.finished || self.futures.is_empty() => {
+                    self.finished = true;
+                    return Poll::Ready(None);
+                }
+                None => return Poll::Pending,
+            }
         }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        unimplemented!();
+        // Even buffered items may be skipped as duplicates and LexOffice may
+        // add or remove items while streaming, so nothing is certain until
+        // the stream is finished.
+        if self.finished {
+            (0, Some(0))
+        } else {
+            (0, None)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod tests {
+    use super::*;
+    use crate::model::articles::Type;
+    use crate::model::Article;
+    use crate::testing::FakeServer;
+    use futures::TryStreamExt;
+    use serde_json::json;
+
+    fn insert_articles(server: &FakeServer, count: usize) -> Vec<Uuid> {
+        (0..count)
+            .map(|i| {
+                let article = json!({
+                    "title": format!("Article {}", i),
+                    "type": "PRODUCT",
+                });
+                server.insert("articles", &article)
+            })
+            .collect()
+    }
+
+    fn page_requests(server: &FakeServer) -> Vec<String> {
+        let mut requests = server.requests();
+        requests.sort();
+        requests
+    }
+
+    #[tokio::test]
+    async fn prefetch_requests_every_page_once() {
+        let server = FakeServer::start();
+        let ids = insert_articles(&server, 7);
+
+        let mut stream = server
+            .client()
+            .request::<Article>()
+            .stream()
+            .page_size(2)
+            .prefetch(2);
+        assert_eq!(stream.size_hint(), (0, None));
+        let first = stream.try_next().await.unwrap().unwrap();
+        assert_eq!(first.id, Some(ids[0]));
+        assert_eq!(stream.size_hint(), (0, None));
+
+        let rest = (&mut stream).try_collect::<Vec<_>>().await.unwrap();
+        let streamed = rest.iter().map(|x| x.id.unwrap()).collect::<Vec<_>>();
+        assert_eq!(streamed, ids[1..]);
+        assert_eq!(stream.size_hint(), (0, Some(0)));
+        assert_eq!(
+            page_requests(&server),
+            (0..4)
+                .map(|x| format!("GET /v1/articles?page={}&size=2", x))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn items_moved_to_the_next_page_are_returned_once() {
+        let server = FakeServer::start();
+        let service = server.insert(
+            "articles",
+            &json!({ "title": "Service", "type": "SERVICE" }),
+        );
+        let ids = insert_articles(&server, 3);
+        let client = server.client();
+
+        let mut stream = client
+            .request::<Article>()
+            .type_(&Type::Product)
+            .stream()
+            .page_size(1);
+        let first = stream.try_next().await.unwrap().unwrap();
+        assert_eq!(first.id, Some(ids[0]));
+
+        // Moves all following products one page further
+        client
+            .request::<Article>()
+            .update_with(service, |article| {
+                article._type = Some(Type::Product);
+                Ok(())
+            })
+            .await
+            .unwrap();
+
+        let rest = stream.try_collect::<Vec<_>>().await.unwrap();
+        let streamed = rest.iter().map(|x| x.id.unwrap()).collect::<Vec<_>>();
+        assert_eq!(streamed, ids[1..]);
+    }
+
+    #[tokio::test]
+    async fn resume_continues_at_the_cursor() {
+        let server = FakeServer::start();
+        let ids = insert_articles(&server, 5);
+        let client = server.client();
+
+        let mut stream = client.request::<Article>().stream().page_size(2);
+        for id in &ids[..3] {
+            let article = stream.try_next().await.unwrap().unwrap();
+            assert_eq!(article.id, Some(*id));
+        }
+        let cursor = serde_json::to_string(&stream.cursor()).unwrap();
+        let cursor = serde_json::from_str::<Cursor>(&cursor).unwrap();
+        assert_eq!((cursor.page, cursor.offset), (1, 1));
+
+        let rest = client
+            .request::<Article>()
+            .stream()
+            .resume(cursor)
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        let streamed = rest.iter().map(|x| x.id.unwrap()).collect::<Vec<_>>();
+        assert_eq!(streamed, ids[3..]);
     }
 }
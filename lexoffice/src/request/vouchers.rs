@@ -1,10 +1,191 @@
+use super::files::FileResponse;
+use crate::error::Error;
+use crate::model::vouchers::*;
+use crate::model::PostingCategory;
 use crate::model::Voucher;
 use crate::request::impls::ById;
+use crate::request::impls::Storable;
+use crate::request::impls::Updatable;
 use crate::request::Endpoint;
 use crate::request::Request;
+#[cfg(all(feature = "fs", not(target_arch = "wasm32")))]
+use crate::request::ResultInfo;
+use crate::result::Result;
+use crate::types::{Amount, Date};
+use crate::util::to_json_response;
+use reqwest::multipart::{Form, Part};
+use reqwest::Method;
+use rust_decimal::Decimal;
+use std::convert::TryFrom;
+use uuid::Uuid;
 
 impl Endpoint for Request<Voucher> {
-    const ENDPOINT: &'static str = "invoices";
+    const ENDPOINT: &'static str = "vouchers";
+}
+
+impl Voucher {
+    /// Builds a purchase invoice with a single voucher item of
+    /// `gross_amount` booked on `category`. The tax amount is calculated
+    /// from `tax_rate_percent` and the voucher is assigned to the collective
+    /// contact. Fails with `Error::NoUuid` if `category` has no id and with
+    /// `Error::Decimal` if `tax_rate_percent` is not a finite number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lexoffice::model::{PostingCategory, Voucher};
+    /// use lexoffice::types::Amount;
+    ///
+    /// # fn run(category: PostingCategory) -> Result<(), Box<dyn std::error::Error>> {
+    /// let voucher = Voucher::purchase_invoice(
+    ///     "RE-2021-0815",
+    ///     chrono::NaiveDate::from_ymd_opt(2021, 4, 1).unwrap(),
+    ///     &category,
+    ///     Amount::new(11900, 2),
+    ///     19.0,
+    /// )?;
+    /// assert_eq!(voucher.total_tax_amount, Amount::new(1900, 2));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn purchase_invoice(
+        voucher_number: &str,
+        voucher_date: Date,
+        category: &PostingCategory,
+        gross_amount: Amount,
+        tax_rate_percent: f64,
+    ) -> Result<Self> {
+        let category_id = category.id.ok_or(Error::NoUuid)?;
+        let rate = Amount::try_from(tax_rate_percent)?.as_decimal();
+        let tax_amount = Amount::from(
+            gross_amount.as_decimal() * rate / (Decimal::from(100) + rate),
+        )
+        .round_cents();
+        let mut voucher = Self::builder()
+            ._type(Type::Purchaseinvoice)
+            .voucher_number(voucher_number.to_string())
+            .voucher_date(voucher_date)
+            .total_gross_amount(gross_amount)
+            .total_tax_amount(tax_amount)
+            .tax_type(TaxType::Gross)
+            .voucher_items(vec![VoucherItems::builder()
+                .amount(gross_amount)
+                .tax_amount(tax_amount)
+                .tax_rate_percent(tax_rate_percent)
+                .category_id(category_id)
+                .build()])
+            .build();
+        voucher.use_collective_contact = Some(true);
+        Ok(voucher)
+    }
+}
+
+impl Request<Voucher> {
+    /// Attaches a file to the voucher identified by `voucher_id` and
+    /// returns the id of the file.
+    pub async fn attach_file<I, P>(
+        self,
+        voucher_id: I,
+        file_part: P,
+    ) -> Result<Uuid>
+    where
+        I: Into<Uuid> + Send + Sync,
+        P: Into<Part>,
+    {
+        let mut url = self.url();
+        url.path_segments_mut()
+            .map_err(|_| Error::UrlCannotBeBase)?
+            .push(&voucher_id.into().to_string())
+            .push("files");
+        let form = Form::new().part("file", file_part.into());
+        to_json_response::<FileResponse>(
+            self.client()
+                .http_builder(Method::POST, url)
+                .multipart(form),
+        )
+        .await
+        .map(|x| x.id)
+    }
+
+    /// Attaches a file from a path to the voucher identified by
+    /// `voucher_id` and returns the id of the file.
+    #[cfg(all(feature = "fs", not(target_arch = "wasm32")))]
+    pub async fn attach_path<I, P>(self, voucher_id: I, path: P) -> Result<Uuid>
+    where
+        I: Into<Uuid> + Send + Sync,
+        P: AsRef<std::path::Path> + Send + Sync,
+    {
+        use crate::mime::*;
+        use reqwest::Body;
+
+        let path = path.as_ref();
+        let file = tokio::fs::File::open(path).await?;
+        let stream = crate::fs::BytesStream::new(file);
+        let mime = path.mime();
+        let part = Part::stream(Body::wrap_stream(stream))
+            .mime_str(mime.as_ref())?
+            .file_name(format!("document{}", mime.extension()));
+        self.attach_file(voucher_id, part).await
+    }
+
+    /// Saves `voucher` and attaches the receipt at `path` to it. The file
+    /// is read before the voucher is saved, so a missing receipt doesn't
+    /// leave a voucher without file behind. If the voucher was saved but
+    /// the receipt couldn't be attached, `Error::ReceiptNotAttached` is
+    /// returned, which carries the id of the saved voucher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lexoffice::{ApiKey, Client};
+    /// use lexoffice::model::{PostingCategory, Voucher};
+    /// use lexoffice::types::Amount;
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new(ApiKey::try_default().await?);
+    /// let categories = client.request::<PostingCategory>().get().await?;
+    /// let category = categories
+    ///     .iter()
+    ///     .find(|x| x.name.as_deref() == Some("Bürobedarf"))
+    ///     .unwrap();
+    /// let voucher = Voucher::purchase_invoice(
+    ///     "RE-2021-0815",
+    ///     chrono::NaiveDate::from_ymd_opt(2021, 4, 1).unwrap(),
+    ///     category,
+    ///     Amount::new(11900, 2),
+    ///     19.0,
+    /// )?;
+    /// let result = client
+    ///     .request::<Voucher>()
+    ///     .save_receipt(voucher, "receipt.pdf")
+    ///     .await?;
+    /// println!("{:#?}", result);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(all(feature = "fs", not(target_arch = "wasm32")))]
+    pub async fn save_receipt<P>(
+        self,
+        voucher: Voucher,
+        path: P,
+    ) -> Result<ResultInfo<Voucher>>
+    where
+        P: AsRef<std::path::Path> + Send + Sync,
+    {
+        use super::files::file_part;
+        use crate::error::ReceiptNotAttached;
+        use crate::mime::MimeExt;
+
+        let path = path.as_ref();
+        let mime = path.mime();
+        let bytes = tokio::fs::read(path).await?;
+        let part = file_part(mime.as_ref(), bytes)?;
+        let result = self.clone().save(voucher).await?;
+        match self.attach_file(result.id, part).await {
+            Ok(_) => Ok(result),
+            Err(e) => Err(ReceiptNotAttached::new(result.id, e).into()),
+        }
+    }
 }
 
 /// # Examples
@@ -16,10 +197,148 @@ impl Endpoint for Request<Voucher> {
 /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
 /// let client = Client::new(ApiKey::try_default().await?);
 /// let uuid = uuid::Uuid::parse_str("f4add52b-44e3-474a-b718-890885094d9a")?;
-/// let invoices = client.request::<Voucher>().by_id(uuid).await?;
-/// println!("{:#?}", invoices);
+/// let voucher = client.request::<Voucher>().by_id(uuid).await?;
+/// println!("{:#?}", voucher);
 /// # Ok(())
 /// # }
 /// ```
 ///
 impl ById for Request<Voucher> {}
+
+/// # Examples
+///
+/// ```
+/// use lexoffice::{ApiKey, Client};
+/// use lexoffice::model::Voucher;
+///
+/// # async fn run(voucher: Voucher) -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(ApiKey::try_default().await?);
+/// let result = client.request::<Voucher>().save(voucher).await?;
+/// println!("{:#?}", result);
+/// # Ok(())
+/// # }
+/// ```
+///
+impl Storable for Request<Voucher> {}
+
+/// # Examples
+///
+/// ```
+/// use lexoffice::{ApiKey, Client};
+/// use lexoffice::model::Voucher;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(ApiKey::try_default().await?);
+/// let uuid = uuid::Uuid::parse_str("f4add52b-44e3-474a-b718-890885094d9a")?;
+/// let result = client
+///     .request::<Voucher>()
+///     .update_with(uuid, |voucher| {
+///         voucher.remark = Some("Paid in cash".to_string());
+///         Ok(())
+///     })
+///     .await?;
+/// println!("{:#?}", result);
+/// # Ok(())
+/// # }
+/// ```
+///
+impl Updatable for Request<Voucher> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn category() -> PostingCategory {
+        serde_json::from_value(json!({
+            "id": "8f8664a8-fd86-11e1-a21f-0800200c9a66",
+            "name": "Bürobedarf",
+            "type": "outgo",
+            "contactRequired": false,
+            "splitAllowed": true,
+            "groupName": "Sonstiges"
+        }))
+        .unwrap()
+    }
+
+    fn date() -> Date {
+        chrono::NaiveDate::from_ymd_opt(2021, 4, 1).unwrap()
+    }
+
+    fn tax_amount(gross_amount: Amount, tax_rate_percent: f64) -> Amount {
+        Voucher::purchase_invoice(
+            "RE-1",
+            date(),
+            &category(),
+            gross_amount,
+            tax_rate_percent,
+        )
+        .unwrap()
+        .total_tax_amount
+    }
+
+    #[test]
+    fn purchase_invoices_have_a_single_item() {
+        let voucher = Voucher::purchase_invoice(
+            "RE-2021-0815",
+            date(),
+            &category(),
+            Amount::new(11900, 2),
+            19.0,
+        )
+        .unwrap();
+
+        assert_eq!(voucher._type, Type::Purchaseinvoice);
+        assert_eq!(voucher.voucher_number, "RE-2021-0815");
+        assert_eq!(voucher.voucher_date, date());
+        assert_eq!(voucher.total_gross_amount, Amount::new(11900, 2));
+        assert_eq!(voucher.total_tax_amount, Amount::new(1900, 2));
+        assert_eq!(voucher.tax_type, TaxType::Gross);
+        assert_eq!(voucher.use_collective_contact, Some(true));
+
+        let items = voucher.voucher_items;
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].amount, Amount::new(11900, 2));
+        assert_eq!(items[0].tax_amount, Amount::new(1900, 2));
+        assert_eq!(items[0].tax_rate_percent, 19.0);
+        assert_eq!(items[0].category_id, category().id.unwrap());
+    }
+
+    #[test]
+    fn purchase_invoice_taxes_are_rounded_to_cents() {
+        // 0.6542…
+        assert_eq!(tax_amount(Amount::new(1000, 2), 7.0), Amount::new(65, 2));
+        // 0.1596…
+        assert_eq!(tax_amount(Amount::new(100, 2), 19.0), Amount::new(16, 2));
+        // 0.0015…
+        assert_eq!(tax_amount(Amount::new(1, 2), 19.0), Amount::new(0, 2));
+        assert_eq!(tax_amount(Amount::new(5000, 2), 0.0), Amount::new(0, 2));
+        // negative amounts round away from zero as well
+        assert_eq!(tax_amount(Amount::new(-100, 2), 19.0), Amount::new(-16, 2));
+    }
+
+    #[test]
+    fn purchase_invoices_need_a_category_id() {
+        let category = PostingCategory::builder().build();
+        let result = Voucher::purchase_invoice(
+            "RE-1",
+            date(),
+            &category,
+            Amount::new(100, 2),
+            19.0,
+        );
+        assert!(matches!(result, Err(Error::NoUuid)));
+    }
+
+    #[test]
+    fn purchase_invoices_need_a_finite_tax_rate() {
+        let result = Voucher::purchase_invoice(
+            "RE-1",
+            date(),
+            &category(),
+            Amount::new(100, 2),
+            f64::NAN,
+        );
+        assert!(matches!(result, Err(Error::Decimal(_))));
+    }
+}
@@ -0,0 +1,158 @@
+use reqwest::header::RETRY_AFTER;
+use reqwest::Method;
+use reqwest::Response;
+use reqwest::StatusCode;
+use std::time::Duration;
+use typed_builder::TypedBuilder;
+
+/// Describes if and how often a `Client` retries requests that failed
+/// because LexOffice was throttling or temporarily unavailable.
+///
+/// Requests are retried with an exponential backoff. If the response
+/// contains a `Retry-After` header, its value is used instead. Both are
+/// limited to `max_backoff`. By default
+/// only the idempotent `GET` and `DELETE` requests are retried. Requests
+/// with a streamed body, such as file uploads from a path, are never
+/// retried.
+///
+/// # Examples
+///
+/// ```
+/// use lexoffice::{ApiKey, Client, RetryPolicy};
+/// use std::time::Duration;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::builder()
+///     .api_key(ApiKey::try_default().await?)
+///     .retry_policy(
+///         RetryPolicy::builder()
+///             .max_retries(5)
+///             .initial_backoff(Duration::from_secs(1))
+///             .build(),
+///     )
+///     .build();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, TypedBuilder)]
+#[builder(doc)]
+pub struct RetryPolicy {
+    /// The maximum number of retries per request
+    #[builder(default = 3)]
+    max_retries: u32,
+
+    /// The backoff before the first retry. It doubles with every retry.
+    #[builder(default = Duration::from_millis(500))]
+    initial_backoff: Duration,
+
+    /// The upper limit for the exponential backoff and the `Retry-After`
+    /// header
+    #[builder(default = Duration::from_secs(30))]
+    max_backoff: Duration,
+
+    /// The HTTP methods that are retried
+    #[builder(default = vec![Method::GET, Method::DELETE])]
+    methods: Vec<Method>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl RetryPolicy {
+    /// Returns the number of retries allowed for `method`.
+    pub(crate) fn max_retries(&self, method: &Method) -> u32 {
+        if self.methods.contains(method) {
+            self.max_retries
+        } else {
+            0
+        }
+    }
+
+    /// Returns the time to wait before the request that produced `response`
+    /// is sent again, or `None` if it shouldn't be retried.
+    pub(crate) fn backoff(
+        &self,
+        attempt: u32,
+        response: &Response,
+    ) -> Option<Duration> {
+        match response.status() {
+            StatusCode::TOO_MANY_REQUESTS
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT => (),
+            _ => return None,
+        }
+
+        let backoff = retry_after(response).unwrap_or_else(|| {
+            self.initial_backoff
+                .checked_mul(2u32.saturating_pow(attempt))
+                .unwrap_or(self.max_backoff)
+        });
+        Some(backoff.min(self.max_backoff))
+    }
+}
+
+/// Parses the `Retry-After` header, which is either a number of seconds or
+/// a HTTP date.
+fn retry_after(response: &Response) -> Option<Duration> {
+    let value = response.headers().get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    cfg_if::cfg_if! {
+        if #[cfg(not(target_arch = "wasm32"))] {
+            let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+            let now = chrono::Utc::now();
+            Some((date.with_timezone(&chrono::Utc) - now).to_std().unwrap_or_default())
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response(status: StatusCode, retry_after: Option<&str>) -> Response {
+        let mut response = http::Response::builder().status(status);
+        if let Some(retry_after) = retry_after {
+            response = response.header(RETRY_AFTER, retry_after);
+        }
+        response.body("").unwrap().into()
+    }
+
+    #[test]
+    fn retry_after_is_clamped_to_max_backoff() {
+        let policy = RetryPolicy::builder()
+            .max_backoff(Duration::from_secs(2))
+            .build();
+        let throttled = response(StatusCode::TOO_MANY_REQUESTS, Some("3600"));
+        assert_eq!(policy.backoff(0, &throttled), Some(Duration::from_secs(2)));
+        let throttled = response(StatusCode::TOO_MANY_REQUESTS, Some("1"));
+        assert_eq!(policy.backoff(0, &throttled), Some(Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn backoff_grows_exponentially() {
+        let policy = RetryPolicy::default();
+        let unavailable = response(StatusCode::SERVICE_UNAVAILABLE, None);
+        assert_eq!(
+            policy.backoff(0, &unavailable),
+            Some(Duration::from_millis(500))
+        );
+        assert_eq!(
+            policy.backoff(2, &unavailable),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(
+            policy.backoff(40, &unavailable),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(policy.backoff(0, &response(StatusCode::OK, None)), None);
+    }
+}
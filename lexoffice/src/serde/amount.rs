@@ -0,0 +1,27 @@
+use crate::types::Amount;
+use rust_decimal::serde::arbitrary_precision;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// Amounts are (de)serialized from the text of the JSON number, so no
+// precision is lost on the way through `f64`.
+
+impl<'de> Deserialize<'de> for Amount {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        arbitrary_precision::deserialize(deserializer).map(Amount::from)
+    }
+}
+
+impl Serialize for Amount {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        arbitrary_precision::serialize(
+            &self.as_decimal().normalize(),
+            serializer,
+        )
+    }
+}
@@ -0,0 +1,26 @@
+//! Helpers for the generated model enums.
+//!
+//! The enums derive their (de)serialization for the known variants with
+//! `#[serde(remote = "Self")]`. Values that are not known to this crate are
+//! mapped to the `Unknown` variant, unless the `strict` feature is enabled.
+use serde::de::value::StringDeserializer;
+use serde::de::IntoDeserializer;
+use serde::{Deserialize, Deserializer};
+
+pub(crate) fn deserialize<'de, D, T, F, U>(
+    deserializer: D,
+    known: F,
+    unknown: U,
+) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    F: FnOnce(StringDeserializer<D::Error>) -> Result<T, D::Error>,
+    U: FnOnce(String) -> T,
+{
+    let value = String::deserialize(deserializer)?;
+    match known(value.clone().into_deserializer()) {
+        Ok(x) => Ok(x),
+        Err(e) if cfg!(feature = "strict") => Err(e),
+        Err(_) => Ok(unknown(value)),
+    }
+}
@@ -1,2 +1,4 @@
+pub(crate) mod amount;
 pub(crate) mod date;
+pub(crate) mod lenient_enum;
 pub(crate) mod optional_date;
@@ -1,12 +1,43 @@
 use crate::serde::date;
 use crate::types::Date;
-use serde::{Deserializer, Serializer};
+use serde::{de, Deserializer, Serializer};
+use std::fmt;
+
+struct OptionalDateVisitor;
+
+impl<'de> de::Visitor<'de> for OptionalDateVisitor {
+    type Value = Option<Date>;
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("null or a string containing a date")
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(None)
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(None)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        date::deserialize(deserializer).map(Some)
+    }
+}
 
 pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Date>, D::Error>
 where
     D: Deserializer<'de>,
 {
-    date::deserialize(deserializer).map(Some)
+    deserializer.deserialize_option(OptionalDateVisitor)
 }
 
 pub fn serialize<S>(
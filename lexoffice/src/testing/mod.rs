@@ -0,0 +1,579 @@
+//! An in-process fake of the LexOffice API for hermetic tests
+//!
+//! `FakeServer` listens on a random local port and keeps all objects in
+//! memory. It implements the articles, contacts, event subscriptions, files,
+//! sales vouchers, vouchers and voucherlist endpoints, including pagination,
+//! filters, optimistic locking, pursuing of sales vouchers, attaching files
+//! to vouchers and rendering of documents. Errors can be injected to test how
+//! an integration handles failures.
+//!
+//! # Examples
+//!
+//! ```
+//! use futures::TryStreamExt;
+//! use lexoffice::model::contacts::{Company, Customer, Roles};
+//! use lexoffice::model::Contact;
+//! use lexoffice::testing::FakeServer;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let server = FakeServer::start();
+//! let client = server.client();
+//!
+//! for name in &["Bike & Ride GmbH", "Foo & Bar KG"] {
+//!     let contact = Contact::builder()
+//!         .roles(Roles::builder().customer(Customer::builder().build()).build())
+//!         .company(Company::builder().name(name.to_string()).build())
+//!         .build();
+//!     client.request::<Contact>().save(contact).await?;
+//! }
+//!
+//! let contacts = client
+//!     .request::<Contact>()
+//!     .stream()
+//!     .try_collect::<Vec<_>>()
+//!     .await?;
+//! assert_eq!(contacts.len(), 2);
+//!
+//! let id = contacts[0].id.unwrap();
+//! client
+//!     .request::<Contact>()
+//!     .update_with(id, |contact| {
+//!         contact.note = Some("Key account".to_string());
+//!         Ok(())
+//!     })
+//!     .await?;
+//! let contact = client.request::<Contact>().by_id(id).await?;
+//! assert_eq!(contact.version, 1);
+//! assert_eq!(contact.note.as_deref(), Some("Key account"));
+//! # Ok(())
+//! # }
+//! ```
+
+mod store;
+
+use crate::client::{ApiKey, Client};
+use futures::channel::oneshot;
+use hyper::header::{CONTENT_TYPE, RETRY_AFTER};
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Method, Request, Response, Server, StatusCode};
+use reqwest::Url;
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::net::{SocketAddr, TcpListener};
+use std::sync::{Arc, Mutex};
+use store::{Store, StoreError, StoreResult};
+use uuid::Uuid;
+
+/// The page size used if a request doesn't specify one
+const DEFAULT_PAGE_SIZE: usize = 25;
+/// The largest page size LexOffice accepts
+const MAX_PAGE_SIZE: usize = 250;
+
+/// A fake LexOffice server running in the background.
+///
+/// The server is shut down when the `FakeServer` is dropped.
+#[derive(Debug)]
+pub struct FakeServer {
+    addr: SocketAddr,
+    store: Arc<Mutex<Store>>,
+    shutdown: Option<oneshot::Sender<()>>,
+}
+
+impl FakeServer {
+    /// Starts a new server on a random local port. This must be called from
+    /// within a Tokio runtime.
+    pub fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let store = Arc::new(Mutex::new(Store::default()));
+        let (shutdown, shutdown_rx) = oneshot::channel::<()>();
+
+        let service_store = store.clone();
+        let make_service = make_service_fn(move |_| {
+            let store = service_store.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request| {
+                    let store = store.clone();
+                    async move { Ok::<_, Infallible>(handle(&store, request).await) }
+                }))
+            }
+        });
+        let server = Server::from_tcp(listener)
+            .unwrap()
+            .serve(make_service)
+            .with_graceful_shutdown(async {
+                shutdown_rx.await.ok();
+            });
+        tokio::spawn(server);
+
+        Self {
+            addr,
+            store,
+            shutdown: Some(shutdown),
+        }
+    }
+
+    /// Returns the URL that has to be passed to `ClientBuilder::base_url()`
+    pub fn base_url(&self) -> Url {
+        Url::parse(&format!("http://{}/v1", self.addr)).unwrap()
+    }
+
+    /// Creates a `Client` that sends its requests to this server.
+    pub fn client(&self) -> Client {
+        Client::builder()
+            .api_key(ApiKey::from("fake-api-key"))
+            .base_url(self.base_url())
+            .build()
+    }
+
+    /// Stores `object` at `endpoint` as if it was created by LexOffice and
+    /// returns its id. This allows to populate endpoints that are read-only,
+    /// e.g. `voucherlist`.
+    pub fn insert<T: Serialize>(&self, endpoint: &str, object: &T) -> Uuid {
+        let object = serde_json::to_value(object).unwrap();
+        let object = self.store().insert(endpoint, object);
+        serde_json::from_value(object["id"].clone())
+            .or_else(|_| {
+                serde_json::from_value(object["subscriptionId"].clone())
+            })
+            .unwrap()
+    }
+
+    /// Returns all objects stored at `endpoint` as JSON.
+    pub fn objects(&self, endpoint: &str) -> Vec<Value> {
+        self.store().objects(endpoint)
+    }
+
+    /// Answers the next request to `endpoint` with `status` instead of
+    /// handling it. Multiple errors are returned in the order they were
+    /// injected. Throttling errors contain a `Retry-After` header of zero
+    /// seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lexoffice::model::Contact;
+    /// use lexoffice::testing::FakeServer;
+    /// use reqwest::StatusCode;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let server = FakeServer::start();
+    /// server.fail_next("contacts", StatusCode::INTERNAL_SERVER_ERROR);
+    ///
+    /// let result = server.client().request::<Contact>().page(0).await;
+    /// match result {
+    ///     Err(lexoffice::Error::LexOffice(e)) => {
+    ///         assert_eq!(e.status(), StatusCode::INTERNAL_SERVER_ERROR)
+    ///     }
+    ///     _ => panic!("expected an error"),
+    /// }
+    /// assert!(server.client().request::<Contact>().page(0).await.is_ok());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fail_next(&self, endpoint: &str, status: StatusCode) {
+        self.store()
+            .errors
+            .push_back((endpoint.to_string(), status));
+    }
+
+    /// Returns all requests the server received in the form
+    /// `METHOD /path?query`, e.g. `GET /v1/contacts?page=0`.
+    pub fn requests(&self) -> Vec<String> {
+        self.store().requests.clone()
+    }
+
+    fn store(&self) -> std::sync::MutexGuard<'_, Store> {
+        self.store.lock().unwrap()
+    }
+}
+
+impl Drop for FakeServer {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            shutdown.send(()).ok();
+        }
+    }
+}
+
+async fn handle(
+    store: &Mutex<Store>,
+    request: Request<Body>,
+) -> Response<Body> {
+    let method = request.method().clone();
+    let uri = request.uri().clone();
+    let content_type = request
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|x| x.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+    let body = match hyper::body::to_bytes(request.into_body()).await {
+        Ok(body) => body,
+        Err(e) => return error(StatusCode::BAD_REQUEST, &e.to_string()),
+    };
+
+    let path = uri.path();
+    let query = Url::parse(&format!("http://localhost{}", uri))
+        .map(|x| x.query_pairs().into_owned().collect::<HashMap<_, _>>())
+        .unwrap_or_default();
+    let segments = path
+        .trim_start_matches("/v1")
+        .split('/')
+        .filter(|x| !x.is_empty())
+        .collect::<Vec<_>>();
+
+    let mut store = store.lock().unwrap();
+    store.requests.push(format!("{} {}", method, uri));
+    let endpoint = segments.first().copied().unwrap_or_default();
+    if let Some(status) = store.take_error(endpoint) {
+        let mut response = error(status, "injected error");
+        if status == StatusCode::TOO_MANY_REQUESTS
+            || status == StatusCode::SERVICE_UNAVAILABLE
+        {
+            response
+                .headers_mut()
+                .insert(RETRY_AFTER, "0".parse().unwrap());
+        }
+        return response;
+    }
+
+    let result =
+        route(&mut store, &method, &segments, &query, &content_type, &body);
+    match result {
+        Ok(response) => response,
+        Err(e) => error(e.status, &e.message),
+    }
+}
+
+fn route(
+    store: &mut Store,
+    method: &Method,
+    segments: &[&str],
+    query: &HashMap<String, String>,
+    content_type: &str,
+    body: &[u8],
+) -> StoreResult<Response<Body>> {
+    let id = segments.get(1).map(|x| parse_id(x)).transpose()?;
+    match (method, segments, id) {
+        (&Method::POST, ["files"], None) => {
+            let file = parse_multipart(content_type, body)?;
+            let id = Uuid::new_v4();
+            store.files.insert(id, file);
+            Ok(json_response(StatusCode::ACCEPTED, &json!({ "id": id })))
+        }
+        (&Method::GET, ["files", _], Some(id)) => {
+            let file = store.files.get(&id).ok_or_else(|| StoreError {
+                status: StatusCode::NOT_FOUND,
+                message: format!("files {} does not exist", id),
+            })?;
+            Ok(Response::builder()
+                .header(CONTENT_TYPE, file.content_type.as_str())
+                .body(Body::from(file.bytes.clone()))
+                .unwrap())
+        }
+        (&Method::POST, ["vouchers", _, "files"], Some(id)) => {
+            let file = parse_multipart(content_type, body)?;
+            let file_id = store.attach("vouchers", id, file)?;
+            Ok(json_response(
+                StatusCode::ACCEPTED,
+                &json!({ "id": file_id }),
+            ))
+        }
+        (&Method::GET, [endpoint, _, "document"], Some(id)) => {
+            let file_id = store.render(endpoint, id)?;
+            Ok(json_response(
+                StatusCode::OK,
+                &json!({ "documentFileId": file_id }),
+            ))
+        }
+        (&Method::GET, ["voucherlist"], None) => {
+            let (page, size) = page_params(query);
+            let filter = |object: &Value| {
+                ["voucherType", "voucherStatus"]
+                    .iter()
+                    .all(|field| match query.get(*field).map(String::as_str) {
+                        None | Some("any") => true,
+                        Some(values) => values
+                            .split(',')
+                            .any(|x| object[*field].as_str() == Some(x)),
+                    })
+            };
+            Ok(json_response(
+                StatusCode::OK,
+                &store.page("voucherlist", page, size, filter),
+            ))
+        }
+        (&Method::GET, ["contacts"], None) => {
+            let (page, size) = page_params(query);
+            Ok(json_response(
+                StatusCode::OK,
+                &store
+                    .page("contacts", page, size, |x| contact_filter(query, x)),
+            ))
+        }
+        (&Method::GET, ["articles"], None) => {
+            let (page, size) = page_params(query);
+            let filter = |object: &Value| {
+                ["articleNumber", "gtin", "type"].iter().all(
+                    |field| match query.get(*field) {
+                        None => true,
+                        Some(x) => object[*field].as_str() == Some(x.as_str()),
+                    },
+                )
+            };
+            Ok(json_response(
+                StatusCode::OK,
+                &store.page("articles", page, size, filter),
+            ))
+        }
+        (&Method::GET, [endpoint], None) => {
+            let (page, size) = page_params(query);
+            Ok(json_response(
+                StatusCode::OK,
+                &store.page(endpoint, page, size, |_| true),
+            ))
+        }
+        (&Method::GET, [endpoint, _], Some(id)) => {
+            Ok(json_response(StatusCode::OK, &store.get(endpoint, id)?))
+        }
+        (&Method::POST, [endpoint], None) => {
+            let object = parse_json(body)?;
+            let object = if store::sales_voucher(endpoint).is_some() {
+                let finalize =
+                    query.get("finalize").map(String::as_str) == Some("true");
+                let preceding = query
+                    .get("precedingSalesVoucherId")
+                    .map(|x| parse_id(x))
+                    .transpose()?;
+                store.insert_voucher(endpoint, object, finalize, preceding)?
+            } else {
+                store.insert(endpoint, object)
+            };
+            Ok(json_response(
+                StatusCode::OK,
+                &result_info(endpoint, &object),
+            ))
+        }
+        (&Method::PUT, [endpoint, _], Some(id)) => {
+            let object = store.update(endpoint, id, parse_json(body)?)?;
+            Ok(json_response(
+                StatusCode::OK,
+                &result_info(endpoint, &object),
+            ))
+        }
+        (&Method::DELETE, [endpoint, _], Some(id)) => {
+            store.delete(endpoint, id)?;
+            Ok(Response::builder()
+                .status(StatusCode::NO_CONTENT)
+                .body(Body::empty())
+                .unwrap())
+        }
+        _ => Err(StoreError {
+            status: StatusCode::NOT_FOUND,
+            message: "unknown endpoint".to_string(),
+        }),
+    }
+}
+
+fn parse_id(id: &str) -> StoreResult<Uuid> {
+    Uuid::parse_str(id).map_err(|e| StoreError {
+        status: StatusCode::BAD_REQUEST,
+        message: e.to_string(),
+    })
+}
+
+fn parse_json(body: &[u8]) -> StoreResult<Value> {
+    serde_json::from_slice(body).map_err(|e| StoreError {
+        status: StatusCode::BAD_REQUEST,
+        message: e.to_string(),
+    })
+}
+
+/// Extracts the part named `file` from a `multipart/form-data` body. Like
+/// LexOffice, only PDF, PNG and JPEG files are accepted.
+fn parse_multipart(
+    content_type: &str,
+    body: &[u8],
+) -> StoreResult<store::File> {
+    let invalid = || StoreError {
+        status: StatusCode::BAD_REQUEST,
+        message: "invalid multipart body".to_string(),
+    };
+    let boundary = content_type
+        .split(';')
+        .find_map(|x| x.trim().strip_prefix("boundary="))
+        .ok_or_else(invalid)?;
+    let delimiter = format!("--{}", boundary).into_bytes();
+
+    split(body, &delimiter)
+        .into_iter()
+        .filter_map(|part| {
+            let header_end = find(part, b"\r\n\r\n")?;
+            let headers = String::from_utf8_lossy(&part[..header_end]);
+            if !headers.contains("name=\"file\"") {
+                return None;
+            }
+            let content_type = headers
+                .lines()
+                .find_map(|x| {
+                    let (key, value) = x.split_at(x.find(':')?);
+                    if key.eq_ignore_ascii_case("content-type") {
+                        Some(value[1..].trim().to_string())
+                    } else {
+                        None
+                    }
+                })
+                .unwrap_or_else(|| "application/octet-stream".to_string());
+            let bytes = &part[header_end + 4..];
+            let bytes = bytes.strip_suffix(b"\r\n").unwrap_or(bytes);
+            Some(store::File {
+                content_type,
+                bytes: bytes.to_vec(),
+            })
+        })
+        .next()
+        .ok_or_else(invalid)
+        .and_then(|file| match file.content_type.as_str() {
+            "application/pdf" | "image/png" | "image/jpeg" => Ok(file),
+            x => Err(StoreError {
+                status: StatusCode::NOT_ACCEPTABLE,
+                message: format!("unsupported file type {}", x),
+            }),
+        })
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|x| x == needle)
+}
+
+fn split<'a>(mut haystack: &'a [u8], delimiter: &[u8]) -> Vec<&'a [u8]> {
+    let mut parts = vec![];
+    while let Some(index) = find(haystack, delimiter) {
+        parts.push(&haystack[..index]);
+        haystack = &haystack[index + delimiter.len()..];
+    }
+    parts.push(haystack);
+    parts
+}
+
+fn contact_filter(query: &HashMap<String, String>, contact: &Value) -> bool {
+    let strings = |pointers: &[&str]| {
+        pointers
+            .iter()
+            .filter_map(|x| contact.pointer(x))
+            .flat_map(|x| match x {
+                Value::Object(x) => x.values().cloned().collect(),
+                x => vec![x.clone()],
+            })
+            .flat_map(|x| match x {
+                Value::Array(x) => x,
+                x => vec![x],
+            })
+            .filter_map(|x| match x {
+                Value::String(x) => Some(x),
+                Value::Number(x) => Some(x.to_string()),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+    };
+    let matches = |field: &str, pointers: &[&str]| match query.get(field) {
+        None => true,
+        Some(pattern) => strings(pointers).iter().any(|x| like(pattern, x)),
+    };
+    let role = |field: &str| match query.get(field).map(String::as_str) {
+        None => true,
+        Some(value) => {
+            let has_role = contact.pointer(&format!("/roles/{}", field));
+            (value == "true") == has_role.is_some()
+        }
+    };
+
+    matches("email", &["/emailAddresses"])
+        && matches(
+            "name",
+            &["/company/name", "/person/firstName", "/person/lastName"],
+        )
+        && matches(
+            "number",
+            &["/roles/customer/number", "/roles/vendor/number"],
+        )
+        && role("customer")
+        && role("vendor")
+}
+
+/// Matches `value` case insensitively against a pattern in which `%` matches
+/// any number of characters and `_` a single character.
+fn like(pattern: &str, value: &str) -> bool {
+    fn matches(pattern: &[char], value: &[char]) -> bool {
+        match pattern.split_first() {
+            None => value.is_empty(),
+            Some(('%', rest)) => {
+                (0..=value.len()).any(|i| matches(rest, &value[i..]))
+            }
+            Some((c, rest)) => match value.split_first() {
+                Some((v, value)) if *c == '_' || c == v => matches(rest, value),
+                _ => false,
+            },
+        }
+    }
+    let pattern = pattern.to_lowercase().chars().collect::<Vec<_>>();
+    let value = value.to_lowercase().chars().collect::<Vec<_>>();
+    matches(&pattern, &value)
+}
+
+fn page_params(query: &HashMap<String, String>) -> (usize, usize) {
+    let page = query.get("page").and_then(|x| x.parse().ok()).unwrap_or(0);
+    let size = query
+        .get("size")
+        .and_then(|x| x.parse().ok())
+        .unwrap_or(DEFAULT_PAGE_SIZE)
+        .clamp(1, MAX_PAGE_SIZE);
+    (page, size)
+}
+
+fn result_info(endpoint: &str, object: &Value) -> Value {
+    let id = if object["id"].is_null() {
+        &object["subscriptionId"]
+    } else {
+        &object["id"]
+    };
+    let now = json!(chrono::Utc::now());
+    json!({
+        "id": id,
+        "resourceUri": format!(
+            "https://api.lexoffice.io/v1/{}/{}",
+            endpoint,
+            id.as_str().unwrap_or_default()
+        ),
+        "createdDate": object.get("createdDate").unwrap_or(&now),
+        "updatedDate": object.get("updatedDate").unwrap_or(&now),
+        "version": object.get("version").cloned().unwrap_or_else(|| json!(0)),
+    })
+}
+
+fn json_response(status: StatusCode, value: &Value) -> Response<Body> {
+    Response::builder()
+        .status(status)
+        .header(CONTENT_TYPE, "application/json")
+        .body(Body::from(value.to_string()))
+        .unwrap()
+}
+
+/// Creates an error response in the format LexOffice uses.
+fn error(status: StatusCode, message: &str) -> Response<Body> {
+    json_response(
+        status,
+        &json!({
+            "timestamp": chrono::Utc::now(),
+            "status": status.as_u16(),
+            "error": status.canonical_reason().unwrap_or_default(),
+            "traceId": Uuid::new_v4().to_simple().to_string(),
+            "message": message,
+        }),
+    )
+}
@@ -0,0 +1,374 @@
+use chrono::Utc;
+use reqwest::StatusCode;
+use serde_json::{json, Map, Value};
+use std::collections::{HashMap, VecDeque};
+use uuid::Uuid;
+
+/// Describes how the objects of an endpoint look like.
+struct Resource {
+    id: &'static str,
+    versioned: bool,
+    created_date: bool,
+    updated_date: bool,
+}
+
+fn resource(endpoint: &str) -> Resource {
+    match endpoint {
+        "event-subscriptions" => Resource {
+            id: "subscriptionId",
+            versioned: false,
+            created_date: true,
+            updated_date: false,
+        },
+        "contacts" => Resource {
+            id: "id",
+            versioned: true,
+            created_date: false,
+            updated_date: false,
+        },
+        "voucherlist" => Resource {
+            id: "id",
+            versioned: false,
+            created_date: true,
+            updated_date: true,
+        },
+        _ => Resource {
+            id: "id",
+            versioned: true,
+            created_date: true,
+            updated_date: true,
+        },
+    }
+}
+
+/// The sales voucher endpoints with their voucher type and the prefix of
+/// their voucher numbers.
+const SALES_VOUCHERS: &[(&str, &str, &str)] = &[
+    ("credit-notes", "creditnote", "GS"),
+    ("delivery-notes", "deliverynote", "LS"),
+    ("dunnings", "dunning", "MA"),
+    ("invoices", "invoice", "RE"),
+    ("order-confirmations", "orderconfirmation", "AB"),
+    ("quotations", "quotation", "AG"),
+];
+
+/// Returns the voucher type and voucher number prefix of a sales voucher
+/// endpoint.
+pub(super) fn sales_voucher(
+    endpoint: &str,
+) -> Option<(&'static str, &'static str)> {
+    SALES_VOUCHERS
+        .iter()
+        .find(|(x, _, _)| *x == endpoint)
+        .map(|(_, voucher_type, prefix)| (*voucher_type, *prefix))
+}
+
+fn related_voucher(endpoint: &str, voucher: &Value) -> Value {
+    json!({
+        "id": voucher["id"],
+        "voucherNumber": voucher["voucherNumber"],
+        "voucherType": sales_voucher(endpoint).map(|(x, _)| x),
+    })
+}
+
+/// An error that is sent instead of the response.
+pub(super) struct StoreError {
+    pub status: StatusCode,
+    pub message: String,
+}
+
+impl StoreError {
+    fn new(status: StatusCode, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            message: message.into(),
+        }
+    }
+
+    fn not_found(endpoint: &str, id: Uuid) -> Self {
+        Self::new(
+            StatusCode::NOT_FOUND,
+            format!("{} {} does not exist", endpoint, id),
+        )
+    }
+}
+
+pub(super) type StoreResult<T> = std::result::Result<T, StoreError>;
+
+#[derive(Debug, Default)]
+pub(super) struct File {
+    pub content_type: String,
+    pub bytes: Vec<u8>,
+}
+
+#[derive(Debug, Default)]
+pub(super) struct Store {
+    objects: HashMap<String, Vec<(Uuid, Value)>>,
+    pub files: HashMap<Uuid, File>,
+    pub errors: VecDeque<(String, StatusCode)>,
+    pub requests: Vec<String>,
+    voucher_numbers: usize,
+}
+
+impl Store {
+    pub fn objects(&self, endpoint: &str) -> Vec<Value> {
+        self.objects
+            .get(endpoint)
+            .map(|x| x.iter().map(|(_, x)| x.clone()).collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns the error injected for `endpoint`, if any.
+    pub fn take_error(&mut self, endpoint: &str) -> Option<StatusCode> {
+        let index = self.errors.iter().position(|(x, _)| x == endpoint)?;
+        self.errors.remove(index).map(|(_, status)| status)
+    }
+
+    /// Stores `object`, filling in the fields LexOffice would generate.
+    pub fn insert(&mut self, endpoint: &str, object: Value) -> Value {
+        self.insert_with_id(endpoint, Uuid::new_v4(), object)
+    }
+
+    fn insert_with_id(
+        &mut self,
+        endpoint: &str,
+        id: Uuid,
+        object: Value,
+    ) -> Value {
+        let resource = resource(endpoint);
+        let mut object = match object {
+            Value::Object(x) => x,
+            _ => Map::new(),
+        };
+        let now = json!(Utc::now());
+        object.insert(resource.id.to_string(), json!(id));
+        if resource.versioned {
+            object.insert("version".to_string(), json!(0));
+        }
+        if resource.created_date {
+            object.insert("createdDate".to_string(), now.clone());
+        }
+        if resource.updated_date {
+            object.insert("updatedDate".to_string(), now);
+        }
+        let object = Value::Object(object);
+        self.objects
+            .entry(endpoint.to_string())
+            .or_default()
+            .push((id, object.clone()));
+        object
+    }
+
+    /// Stores a new sales voucher and adds it to the voucherlist. A voucher
+    /// created from a `preceding` voucher is related to it in both
+    /// directions.
+    pub fn insert_voucher(
+        &mut self,
+        endpoint: &str,
+        mut voucher: Value,
+        finalize: bool,
+        preceding: Option<Uuid>,
+    ) -> StoreResult<Value> {
+        let (voucher_type, prefix) = sales_voucher(endpoint).unwrap();
+        let preceding = preceding
+            .map(|id| {
+                SALES_VOUCHERS
+                    .iter()
+                    .find_map(|(endpoint, _, _)| {
+                        let voucher = self.get(endpoint, id).ok()?;
+                        Some((*endpoint, voucher))
+                    })
+                    .ok_or_else(|| {
+                        StoreError::new(
+                            StatusCode::NOT_ACCEPTABLE,
+                            format!("sales voucher {} does not exist", id),
+                        )
+                    })
+            })
+            .transpose()?;
+
+        let status = if finalize { "open" } else { "draft" };
+        voucher["voucherStatus"] = json!(status);
+        if finalize {
+            self.voucher_numbers += 1;
+            voucher["voucherNumber"] =
+                json!(format!("{}{:04}", prefix, self.voucher_numbers));
+        }
+        if let Some((endpoint, preceding)) = &preceding {
+            voucher["relatedVouchers"] =
+                json!([related_voucher(endpoint, preceding)]);
+        }
+        let voucher = self.insert(endpoint, voucher);
+
+        if let Some((preceding_endpoint, mut preceding)) = preceding {
+            let related = related_voucher(endpoint, &voucher);
+            match preceding["relatedVouchers"].as_array_mut() {
+                Some(x) => x.push(related),
+                None => preceding["relatedVouchers"] = json!([related]),
+            }
+            let id = serde_json::from_value(preceding["id"].clone()).unwrap();
+            self.replace(preceding_endpoint, id, preceding);
+        }
+
+        let total = &voucher["totalPrice"]["totalGrossAmount"];
+        let entry = json!({
+            "voucherType": voucher_type,
+            "voucherStatus": status,
+            "voucherNumber": voucher["voucherNumber"],
+            "voucherDate": voucher["voucherDate"],
+            "contactName": voucher["address"]["name"],
+            "totalAmount": total,
+            "openAmount": total,
+            "currency": voucher["totalPrice"]["currency"],
+            "archived": false,
+        });
+        // The voucherlist entry shares the id of its voucher
+        let id = serde_json::from_value(voucher["id"].clone()).unwrap();
+        self.insert_with_id("voucherlist", id, entry);
+        Ok(voucher)
+    }
+
+    /// Replaces a stored object without touching its metadata.
+    fn replace(&mut self, endpoint: &str, id: Uuid, object: Value) {
+        let stored = self
+            .objects
+            .get_mut(endpoint)
+            .and_then(|x| x.iter_mut().find(|(x, _)| *x == id));
+        if let Some((_, stored)) = stored {
+            *stored = object;
+        }
+    }
+
+    pub fn get(&self, endpoint: &str, id: Uuid) -> StoreResult<Value> {
+        self.objects
+            .get(endpoint)
+            .and_then(|x| x.iter().find(|(x, _)| *x == id))
+            .map(|(_, x)| x.clone())
+            .ok_or_else(|| StoreError::not_found(endpoint, id))
+    }
+
+    pub fn update(
+        &mut self,
+        endpoint: &str,
+        id: Uuid,
+        object: Value,
+    ) -> StoreResult<Value> {
+        let resource = resource(endpoint);
+        let stored = self
+            .objects
+            .get_mut(endpoint)
+            .and_then(|x| x.iter_mut().find(|(x, _)| *x == id))
+            .map(|(_, x)| x)
+            .ok_or_else(|| StoreError::not_found(endpoint, id))?;
+
+        let mut object = match object {
+            Value::Object(x) => x,
+            _ => {
+                return Err(StoreError::new(
+                    StatusCode::BAD_REQUEST,
+                    "expected a JSON object",
+                ))
+            }
+        };
+        if resource.versioned {
+            let version = stored["version"].as_i64().unwrap_or_default();
+            if object.get("version").and_then(Value::as_i64) != Some(version) {
+                return Err(StoreError::new(
+                    StatusCode::CONFLICT,
+                    format!("{} {} has version {}", endpoint, id, version),
+                ));
+            }
+            object.insert("version".to_string(), json!(version + 1));
+        }
+        for field in &["createdDate", resource.id] {
+            if let Some(x) = stored.get(*field) {
+                object.insert(field.to_string(), x.clone());
+            }
+        }
+        if resource.updated_date {
+            object.insert("updatedDate".to_string(), json!(Utc::now()));
+        }
+        *stored = Value::Object(object);
+        Ok(stored.clone())
+    }
+
+    pub fn delete(&mut self, endpoint: &str, id: Uuid) -> StoreResult<()> {
+        let list = self.objects.entry(endpoint.to_string()).or_default();
+        let index = list
+            .iter()
+            .position(|(x, _)| *x == id)
+            .ok_or_else(|| StoreError::not_found(endpoint, id))?;
+        list.remove(index);
+        Ok(())
+    }
+
+    /// Returns a page of the objects of `endpoint` that match `filter`.
+    pub fn page<F>(
+        &self,
+        endpoint: &str,
+        page: usize,
+        size: usize,
+        filter: F,
+    ) -> Value
+    where
+        F: Fn(&Value) -> bool,
+    {
+        let objects = self
+            .objects
+            .get(endpoint)
+            .map(|x| x.iter().map(|(_, x)| x).filter(|x| filter(x)))
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        let total_elements = objects.len();
+        let total_pages = total_elements.div_ceil(size);
+        let content = objects
+            .into_iter()
+            .skip(page * size)
+            .take(size)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        json!({
+            "numberOfElements": content.len(),
+            "content": content,
+            "first": page == 0,
+            "last": page + 1 >= total_pages,
+            "totalPages": total_pages,
+            "totalElements": total_elements,
+            "size": size,
+            "number": page,
+            "sort": [],
+        })
+    }
+
+    /// Stores `file` and adds its id to the `files` of an object.
+    pub fn attach(
+        &mut self,
+        endpoint: &str,
+        id: Uuid,
+        file: File,
+    ) -> StoreResult<Uuid> {
+        let mut object = self.get(endpoint, id)?;
+        let file_id = Uuid::new_v4();
+        match object["files"].as_array_mut() {
+            Some(files) => files.push(json!(file_id)),
+            None => object["files"] = json!([file_id]),
+        }
+        self.replace(endpoint, id, object);
+        self.files.insert(file_id, file);
+        Ok(file_id)
+    }
+
+    /// Creates a fake PDF document for an object and returns its file id.
+    pub fn render(&mut self, endpoint: &str, id: Uuid) -> StoreResult<Uuid> {
+        self.get(endpoint, id)?;
+        let file_id = Uuid::new_v4();
+        let file = File {
+            content_type: "application/pdf".to_string(),
+            bytes: format!("%PDF-1.4\n% {} {}\n", endpoint, id).into_bytes(),
+        };
+        self.files.insert(file_id, file);
+        Ok(file_id)
+    }
+}
@@ -1,6 +1,12 @@
 //! Types used in the model
 
+use derive_more::{
+    Add, AddAssign, Display, From, FromStr, Into, Neg, Sub, SubAssign, Sum,
+};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
 
 /// Type for storing date and time
 pub type DateTime = chrono::DateTime<chrono::Utc>;
@@ -9,9 +15,597 @@ pub type Date = chrono::NaiveDate;
 /// Type for storing a country code
 pub type CountryCode = String;
 
-/// Type for safing Currencies
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
-pub enum Currency {
+macro_rules! currencies {
+    ($( $(#[$meta:meta])* $code:ident, )*) => {
+        /// Type for storing ISO 4217 currencies
+        ///
+        /// Currencies that are not known to this crate are kept as
+        /// `Currency::Unknown`, so they can still be deserialized and sent
+        /// back to LexOffice.
+        #[derive(Debug, PartialEq, Eq, Hash, Clone)]
+        pub enum Currency {
+            $( $(#[$meta])* $code, )*
+            /// A currency code that is not known to this crate
+            Unknown(String),
+        }
+
+        impl Currency {
+            /// Returns the ISO 4217 code of the currency, e.g. `EUR`
+            pub fn code(&self) -> &str {
+                match self {
+                    $( Self::$code => stringify!($code), )*
+                    Self::Unknown(code) => code,
+                }
+            }
+        }
+
+        impl From<&str> for Currency {
+            fn from(code: &str) -> Self {
+                match code {
+                    $( stringify!($code) => Self::$code, )*
+                    code => Self::Unknown(code.to_string()),
+                }
+            }
+        }
+    };
+}
+
+currencies! {
+    /// Dirham of the United Arab Emirates
+    AED,
+    /// Afghan afghani
+    AFN,
+    /// Albanian lek
+    ALL,
+    /// Armenian dram
+    AMD,
+    /// Netherlands Antillean guilder
+    ANG,
+    /// Angolan kwanza
+    AOA,
+    /// Argentine peso
+    ARS,
+    /// Australian dollar
+    AUD,
+    /// Aruban florin
+    AWG,
+    /// Azerbaijani manat
+    AZN,
+    /// Bosnia and Herzegovina convertible mark
+    BAM,
+    /// Barbados dollar
+    BBD,
+    /// Bangladeshi taka
+    BDT,
+    /// Bulgarian lev
+    BGN,
+    /// Bahraini dinar
+    BHD,
+    /// Burundian franc
+    BIF,
+    /// Bermudian dollar
+    BMD,
+    /// Brunei dollar
+    BND,
+    /// Boliviano
+    BOB,
+    /// Brazilian real
+    BRL,
+    /// Bahamian dollar
+    BSD,
+    /// Bhutanese ngultrum
+    BTN,
+    /// Botswana pula
+    BWP,
+    /// Belarusian ruble
+    BYN,
+    /// Belize dollar
+    BZD,
+    /// Canadian dollar
+    CAD,
+    /// Congolese franc
+    CDF,
+    /// Swiss franc
+    CHF,
+    /// Chilean peso
+    CLP,
+    /// Renminbi
+    CNY,
+    /// Colombian peso
+    COP,
+    /// Costa Rican colon
+    CRC,
+    /// Cuban peso
+    CUP,
+    /// Cape Verdean escudo
+    CVE,
+    /// Czech koruna
+    CZK,
+    /// Djiboutian franc
+    DJF,
+    /// Danish krone
+    DKK,
+    /// Dominican peso
+    DOP,
+    /// Algerian dinar
+    DZD,
+    /// Egyptian pound
+    EGP,
+    /// Eritrean nakfa
+    ERN,
+    /// Ethiopian birr
+    ETB,
     /// Euro
     EUR,
+    /// Fiji dollar
+    FJD,
+    /// Falkland Islands pound
+    FKP,
+    /// Pound sterling
+    GBP,
+    /// Georgian lari
+    GEL,
+    /// Ghanaian cedi
+    GHS,
+    /// Gibraltar pound
+    GIP,
+    /// Gambian dalasi
+    GMD,
+    /// Guinean franc
+    GNF,
+    /// Guatemalan quetzal
+    GTQ,
+    /// Guyanese dollar
+    GYD,
+    /// Hong Kong dollar
+    HKD,
+    /// Honduran lempira
+    HNL,
+    /// Haitian gourde
+    HTG,
+    /// Hungarian forint
+    HUF,
+    /// Indonesian rupiah
+    IDR,
+    /// Israeli new shekel
+    ILS,
+    /// Indian rupee
+    INR,
+    /// Iraqi dinar
+    IQD,
+    /// Iranian rial
+    IRR,
+    /// Icelandic krona
+    ISK,
+    /// Jamaican dollar
+    JMD,
+    /// Jordanian dinar
+    JOD,
+    /// Japanese yen
+    JPY,
+    /// Kenyan shilling
+    KES,
+    /// Kyrgyzstani som
+    KGS,
+    /// Cambodian riel
+    KHR,
+    /// Comoro franc
+    KMF,
+    /// North Korean won
+    KPW,
+    /// South Korean won
+    KRW,
+    /// Kuwaiti dinar
+    KWD,
+    /// Cayman Islands dollar
+    KYD,
+    /// Kazakhstani tenge
+    KZT,
+    /// Lao kip
+    LAK,
+    /// Lebanese pound
+    LBP,
+    /// Sri Lankan rupee
+    LKR,
+    /// Liberian dollar
+    LRD,
+    /// Lesotho loti
+    LSL,
+    /// Libyan dinar
+    LYD,
+    /// Moroccan dirham
+    MAD,
+    /// Moldovan leu
+    MDL,
+    /// Malagasy ariary
+    MGA,
+    /// Macedonian denar
+    MKD,
+    /// Myanmar kyat
+    MMK,
+    /// Mongolian togrog
+    MNT,
+    /// Macanese pataca
+    MOP,
+    /// Mauritanian ouguiya
+    MRU,
+    /// Mauritian rupee
+    MUR,
+    /// Maldivian rufiyaa
+    MVR,
+    /// Malawian kwacha
+    MWK,
+    /// Mexican peso
+    MXN,
+    /// Malaysian ringgit
+    MYR,
+    /// Mozambican metical
+    MZN,
+    /// Namibian dollar
+    NAD,
+    /// Nigerian naira
+    NGN,
+    /// Nicaraguan cordoba
+    NIO,
+    /// Norwegian krone
+    NOK,
+    /// Nepalese rupee
+    NPR,
+    /// New Zealand dollar
+    NZD,
+    /// Omani rial
+    OMR,
+    /// Panamanian balboa
+    PAB,
+    /// Peruvian sol
+    PEN,
+    /// Papua New Guinean kina
+    PGK,
+    /// Philippine peso
+    PHP,
+    /// Pakistani rupee
+    PKR,
+    /// Polish zloty
+    PLN,
+    /// Paraguayan guarani
+    PYG,
+    /// Qatari riyal
+    QAR,
+    /// Romanian leu
+    RON,
+    /// Serbian dinar
+    RSD,
+    /// Russian ruble
+    RUB,
+    /// Rwandan franc
+    RWF,
+    /// Saudi riyal
+    SAR,
+    /// Solomon Islands dollar
+    SBD,
+    /// Seychelles rupee
+    SCR,
+    /// Sudanese pound
+    SDG,
+    /// Swedish krona
+    SEK,
+    /// Singapore dollar
+    SGD,
+    /// Saint Helena pound
+    SHP,
+    /// Sierra Leonean leone
+    SLE,
+    /// Somali shilling
+    SOS,
+    /// Surinamese dollar
+    SRD,
+    /// South Sudanese pound
+    SSP,
+    /// Sao Tome and Principe dobra
+    STN,
+    /// Salvadoran colon
+    SVC,
+    /// Syrian pound
+    SYP,
+    /// Swazi lilangeni
+    SZL,
+    /// Thai baht
+    THB,
+    /// Tajikistani somoni
+    TJS,
+    /// Turkmenistan manat
+    TMT,
+    /// Tunisian dinar
+    TND,
+    /// Tongan pa'anga
+    TOP,
+    /// Turkish lira
+    TRY,
+    /// Trinidad and Tobago dollar
+    TTD,
+    /// New Taiwan dollar
+    TWD,
+    /// Tanzanian shilling
+    TZS,
+    /// Ukrainian hryvnia
+    UAH,
+    /// Ugandan shilling
+    UGX,
+    /// United States dollar
+    USD,
+    /// Uruguayan peso
+    UYU,
+    /// Uzbekistan sum
+    UZS,
+    /// Venezuelan bolivar soberano
+    VES,
+    /// Vietnamese dong
+    VND,
+    /// Vanuatu vatu
+    VUV,
+    /// Samoan tala
+    WST,
+    /// CFA franc BEAC
+    XAF,
+    /// East Caribbean dollar
+    XCD,
+    /// CFA franc BCEAO
+    XOF,
+    /// CFP franc
+    XPF,
+    /// Yemeni rial
+    YER,
+    /// South African rand
+    ZAR,
+    /// Zambian kwacha
+    ZMW,
+    /// Zimbabwean dollar
+    ZWL,
+}
+
+impl From<String> for Currency {
+    fn from(code: String) -> Self {
+        Self::from(code.as_str())
+    }
+}
+
+impl From<Currency> for String {
+    fn from(currency: Currency) -> Self {
+        currency.code().to_string()
+    }
+}
+
+impl std::str::FromStr for Currency {
+    type Err = std::convert::Infallible;
+
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(code))
+    }
+}
+
+impl std::fmt::Display for Currency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl Serialize for Currency {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.code())
+    }
+}
+
+impl<'de> Deserialize<'de> for Currency {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(Self::from)
+    }
+}
+
+/// Type for storing monetary amounts
+///
+/// `Amount` is backed by a fixed-point decimal, so summing up line items
+/// doesn't accumulate rounding errors. It is (de)serialized as a JSON number
+/// and round-trips the amounts sent by LexOffice exactly.
+///
+/// # Examples
+///
+/// ```
+/// use lexoffice::types::Amount;
+///
+/// let items: Vec<Amount> = vec!["0.1".parse()?, "0.2".parse()?];
+/// let sum: Amount = items.into_iter().sum();
+/// assert_eq!(sum, "0.3".parse()?);
+/// assert_eq!(serde_json::to_string(&sum)?, "0.3");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Add,
+    AddAssign,
+    Sub,
+    SubAssign,
+    Neg,
+    Sum,
+    Display,
+    From,
+    Into,
+    FromStr,
+)]
+pub struct Amount(Decimal);
+
+impl Amount {
+    /// Creates an amount from an integer `num` and a `scale`, e.g.
+    /// `Amount::new(1999, 2)` is `19.99`.
+    pub fn new(num: i64, scale: u32) -> Self {
+        Self(Decimal::new(num, scale))
+    }
+
+    /// Returns the underlying decimal
+    pub fn as_decimal(&self) -> Decimal {
+        self.0
+    }
+
+    /// Rounds the amount to full cents, rounding half away from zero.
+    pub fn round_cents(&self) -> Self {
+        Self(self.0.round_dp_with_strategy(
+            2,
+            rust_decimal::RoundingStrategy::MidpointAwayFromZero,
+        ))
+    }
+
+    /// Converts the amount to a `f64`. This may lose precision.
+    pub fn to_f64(&self) -> f64 {
+        self.0.to_f64().unwrap_or_default()
+    }
+}
+
+impl From<i64> for Amount {
+    fn from(value: i64) -> Self {
+        Self(Decimal::from(value))
+    }
+}
+
+impl TryFrom<f64> for Amount {
+    type Error = rust_decimal::Error;
+
+    /// Converts a `f64` to the shortest decimal that round-trips to the same
+    /// `f64`, e.g. `0.1` becomes exactly `0.1`. Fails for NaN, infinite and
+    /// out of range values.
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        if !value.is_finite() {
+            return Err(rust_decimal::Error::ConversionTo("Amount".into()));
+        }
+        value.to_string().parse::<Decimal>().map(Self)
+    }
+}
+
+/// An `Amount` in a specific `Currency`
+///
+/// Arithmetic on `Money` is only possible if both operands share the same
+/// currency, so totals can't be summed across currencies by accident.
+/// `Payment::remaining()` and the `total_*()` methods of the `TotalPrice`
+/// models return `Money`.
+///
+/// # Examples
+///
+/// ```
+/// use lexoffice::types::{Amount, Currency, Money};
+///
+/// let a = Money::new(Amount::new(1050, 2), Currency::CHF);
+/// let b = Money::new(Amount::new(250, 2), Currency::CHF);
+/// let c = Money::new(Amount::new(100, 2), Currency::EUR);
+///
+/// assert_eq!(a.checked_add(&b), Some(Money::new(Amount::new(13, 0), Currency::CHF)));
+/// assert_eq!(a.checked_add(&c), None);
+/// assert_eq!(Money::sum(Currency::CHF, vec![a, b, c]), None);
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+pub struct Money {
+    /// The amount of money
+    pub amount: Amount,
+    /// The currency of `amount`
+    pub currency: Currency,
+}
+
+impl Money {
+    /// Creates a new `Money` object
+    pub fn new(amount: Amount, currency: Currency) -> Self {
+        Self { amount, currency }
+    }
+
+    /// Adds `other` to `self`. Returns `None` if the currencies differ.
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        if self.currency == other.currency {
+            Some(Self::new(self.amount + other.amount, self.currency.clone()))
+        } else {
+            None
+        }
+    }
+
+    /// Subtracts `other` from `self`. Returns `None` if the currencies differ.
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        if self.currency == other.currency {
+            Some(Self::new(self.amount - other.amount, self.currency.clone()))
+        } else {
+            None
+        }
+    }
+
+    /// Sums up `items` in `currency`. Returns `None` if any item is in a
+    /// different currency.
+    pub fn sum<I>(currency: Currency, items: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        items
+            .into_iter()
+            .try_fold(Self::new(Amount::default(), currency), |sum, item| {
+                sum.checked_add(&item)
+            })
+    }
+}
+
+impl std::fmt::Display for Money {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.amount, self.currency)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(json: &str) -> (Amount, String) {
+        let amount = serde_json::from_str::<Amount>(json).unwrap();
+        let serialized = serde_json::to_string(&amount).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Amount>(&serialized).unwrap(),
+            amount
+        );
+        (amount, serialized)
+    }
+
+    #[test]
+    fn amounts_keep_their_precision() {
+        assert_eq!(round_trip("0.1"), (Amount::new(1, 1), "0.1".to_string()));
+        assert_eq!(
+            round_trip("1234567890.12"),
+            (Amount::new(123456789012, 2), "1234567890.12".to_string())
+        );
+        assert_eq!(
+            round_trip("1e-7"),
+            (Amount::new(1, 7), "0.0000001".to_string())
+        );
+        assert_eq!(round_trip("19.00"), (Amount::new(19, 0), "19".to_string()));
+    }
+
+    #[test]
+    fn amounts_from_f64() {
+        assert_eq!(Amount::try_from(0.1), Ok(Amount::new(1, 1)));
+        assert_eq!(Amount::try_from(-2.5), Ok(Amount::new(-25, 1)));
+        assert!(Amount::try_from(f64::NAN).is_err());
+        assert!(Amount::try_from(f64::INFINITY).is_err());
+    }
+
+    #[test]
+    fn money_round_trip() {
+        let money = Money::new(Amount::new(1050, 2), Currency::CHF);
+        let json = serde_json::to_string(&money).unwrap();
+        assert_eq!(json, r#"{"amount":10.5,"currency":"CHF"}"#);
+        assert_eq!(serde_json::from_str::<Money>(&json).unwrap(), money);
+    }
 }
@@ -1,8 +1,9 @@
+use crate::client::HttpRequest;
 use crate::error::LexOfficeError;
+use crate::error::Message;
 use crate::Result;
 use mime::APPLICATION_JSON;
 use reqwest::header::ACCEPT;
-use reqwest::RequestBuilder;
 use reqwest::Response;
 use serde::de::DeserializeOwned;
 
@@ -11,12 +12,21 @@ pub async fn error_for_lexoffice(response: Response) -> Result<Response> {
     if status.is_success() {
         Ok(response)
     } else {
-        let message = response.json().await?;
-        Err(LexOfficeError::new(status, message).into())
+        let url = response.url().clone();
+        let text = response.text().await?;
+        let message = serde_json::from_str(&text).unwrap_or_else(|_| {
+            if text.trim().is_empty() {
+                let reason = status.canonical_reason().unwrap_or_default();
+                Message::Message(reason.to_string())
+            } else {
+                Message::Message(text)
+            }
+        });
+        Err(LexOfficeError::new(status, message, Some(url)).into())
     }
 }
 
-pub async fn to_json_response<T>(request: RequestBuilder) -> Result<T>
+pub(crate) async fn to_json_response<T>(request: HttpRequest) -> Result<T>
 where
     T: DeserializeOwned,
 {
@@ -0,0 +1,359 @@
+//! Receiving and verifying webhooks sent by LexOffice
+//!
+//! LexOffice signs every webhook with its private key and sends the base64
+//! encoded RSA-SHA512 signature of the raw request body in the
+//! `X-Lxo-Signature` header. A `Verifier` checks this signature against
+//! LexOffice's public key, which can be downloaded from the
+//! [official documentation](https://developers.lexoffice.io/docs/#event-subscriptions-endpoint-webhook-signature).
+//! A `Dispatcher` additionally parses the payload and calls the handlers
+//! registered for its `EventType`.
+//!
+//! # Examples
+//!
+//! ```
+//! use lexoffice::model::event_subscriptions::EventType;
+//! use lexoffice::webhook::{Dispatcher, Verifier};
+//! use rsa::pkcs1v15::SigningKey;
+//! use rsa::pkcs8::{EncodePublicKey, LineEnding};
+//! use rsa::signature::{SignatureEncoding, Signer};
+//! use sha2::Sha512;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! // Use a self generated key pair instead of LexOffice's key for testing
+//! let private_key = rsa::RsaPrivateKey::new(&mut rand::thread_rng(), 1024)?;
+//! let public_key = private_key.to_public_key().to_public_key_pem(LineEnding::LF)?;
+//!
+//! let body = br#"{
+//!     "organizationId": "aa93e8a8-2aa3-470b-b914-caad8a255dd8",
+//!     "eventType": "invoice.created",
+//!     "resourceId": "403f8dde-6d17-4e3b-b0ca-5f0ee8e2a9a3",
+//!     "eventDate": "2021-03-19T15:09:32.921+01:00"
+//! }"#;
+//! let signature = base64::encode(
+//!     SigningKey::<Sha512>::new(private_key).sign(body).to_bytes(),
+//! );
+//!
+//! let dispatcher = Dispatcher::new(Verifier::from_pem(&public_key)?)
+//!     .on(EventType::InvoiceCreated, |callback| async move {
+//!         println!("new invoice {}", callback.resource_id);
+//!         Ok(())
+//!     });
+//! futures::executor::block_on(dispatcher.dispatch(body, &signature))?;
+//!
+//! // Tampered payloads are rejected
+//! let result = futures::executor::block_on(
+//!     dispatcher.dispatch(b"{}", &signature),
+//! );
+//! assert!(matches!(result, Err(lexoffice::Error::InvalidSignature)));
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::model::event_subscriptions::EventType;
+use crate::model::event_subscriptions::WebhookCallback;
+use crate::Error;
+use crate::Result;
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use rsa::pkcs1::DecodeRsaPublicKey;
+use rsa::pkcs1v15::{Signature, VerifyingKey};
+use rsa::pkcs8::DecodePublicKey;
+use rsa::signature::Verifier as _;
+use rsa::RsaPublicKey;
+use sha2::Sha512;
+use std::convert::TryFrom;
+use std::fmt;
+use std::future::Future;
+
+#[cfg(all(feature = "webhook-server", not(target_arch = "wasm32")))]
+pub mod server;
+
+/// The header containing the signature of a webhook
+pub const SIGNATURE_HEADER: &str = "X-Lxo-Signature";
+
+/// Verifies the signatures of webhooks sent by LexOffice.
+#[derive(Clone)]
+pub struct Verifier {
+    key: VerifyingKey<Sha512>,
+}
+
+impl Verifier {
+    /// Creates a `Verifier` from a PEM encoded RSA public key. Both
+    /// `BEGIN PUBLIC KEY` and `BEGIN RSA PUBLIC KEY` keys are supported.
+    pub fn from_pem(pem: &str) -> Result<Self> {
+        let key = RsaPublicKey::from_public_key_pem(pem)
+            .or_else(|_| RsaPublicKey::from_pkcs1_pem(pem))
+            .map_err(|_| Error::InvalidPublicKey)?;
+        Ok(Self::new(key))
+    }
+
+    /// Creates a `Verifier` from an RSA public key.
+    pub fn new(key: RsaPublicKey) -> Self {
+        Self {
+            key: VerifyingKey::new(key),
+        }
+    }
+
+    /// Checks that `signature` is the base64 encoded signature of `body`.
+    pub fn verify(&self, body: &[u8], signature: &str) -> Result<()> {
+        let signature = base64::decode(signature.trim())
+            .map_err(|_| Error::InvalidSignature)?;
+        let signature = Signature::try_from(signature.as_slice())
+            .map_err(|_| Error::InvalidSignature)?;
+        self.key
+            .verify(body, &signature)
+            .map_err(|_| Error::InvalidSignature)
+    }
+
+    /// Verifies the signature of `body` and parses it.
+    pub fn parse(
+        &self,
+        body: &[u8],
+        signature: &str,
+    ) -> Result<WebhookCallback> {
+        self.verify(body, signature)?;
+        Ok(serde_json::from_slice(body)?)
+    }
+}
+
+impl fmt::Debug for Verifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Verifier").finish()
+    }
+}
+
+type Handler = Box<
+    dyn Fn(WebhookCallback) -> BoxFuture<'static, Result<()>> + Send + Sync,
+>;
+
+/// Verifies webhooks and calls the handlers registered for their
+/// `EventType`.
+pub struct Dispatcher {
+    verifier: Verifier,
+    handlers: Vec<(EventType, Handler)>,
+    fallback: Option<Handler>,
+}
+
+impl Dispatcher {
+    /// Creates a `Dispatcher` without any handlers.
+    pub fn new(verifier: Verifier) -> Self {
+        Self {
+            verifier,
+            handlers: vec![],
+            fallback: None,
+        }
+    }
+
+    /// Registers `handler` for `event_type`. Multiple handlers may be
+    /// registered for the same event type. They are called in the order they
+    /// were registered.
+    pub fn on<F, Fut>(mut self, event_type: EventType, handler: F) -> Self
+    where
+        F: Fn(WebhookCallback) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        self.handlers
+            .push((event_type, Box::new(move |x| handler(x).boxed())));
+        self
+    }
+
+    /// Registers `handler` for all events without a handler of their own.
+    pub fn fallback<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(WebhookCallback) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        self.fallback = Some(Box::new(move |x| handler(x).boxed()));
+        self
+    }
+
+    /// Returns the `Verifier` used by this `Dispatcher`.
+    pub fn verifier(&self) -> &Verifier {
+        &self.verifier
+    }
+
+    /// Verifies and parses `body` and calls the handlers registered for its
+    /// event type. Events without a handler are passed to the fallback
+    /// handler, or ignored if there is none.
+    pub async fn dispatch(&self, body: &[u8], signature: &str) -> Result<()> {
+        let callback = self.verifier.parse(body, signature)?;
+        let mut handled = false;
+        for (event_type, handler) in &self.handlers {
+            if event_type == &callback.event_type {
+                handler(callback.clone()).await?;
+                handled = true;
+            }
+        }
+
+        if !handled {
+            if let Some(fallback) = &self.fallback {
+                fallback(callback).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Dispatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Dispatcher")
+            .field("verifier", &self.verifier)
+            .field(
+                "handlers",
+                &self.handlers.iter().map(|x| &x.0).collect::<Vec<_>>(),
+            )
+            .field("fallback", &self.fallback.is_some())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rsa::pkcs1::{EncodeRsaPublicKey, LineEnding};
+    use rsa::pkcs1v15::SigningKey;
+    use rsa::pkcs8::EncodePublicKey;
+    use rsa::signature::{SignatureEncoding, Signer};
+    use rsa::RsaPrivateKey;
+    use std::sync::{Arc, Mutex, OnceLock};
+
+    /// Returns a key pair that is generated once for all tests.
+    pub(super) fn private_key() -> &'static RsaPrivateKey {
+        static KEY: OnceLock<RsaPrivateKey> = OnceLock::new();
+        KEY.get_or_init(|| {
+            RsaPrivateKey::new(&mut rand::thread_rng(), 1024).unwrap()
+        })
+    }
+
+    pub(super) fn verifier() -> Verifier {
+        Verifier::new(private_key().to_public_key())
+    }
+
+    pub(super) fn sign(key: &RsaPrivateKey, body: &[u8]) -> String {
+        base64::encode(
+            SigningKey::<Sha512>::new(key.clone()).sign(body).to_bytes(),
+        )
+    }
+
+    pub(super) fn body(event_type: &str) -> Vec<u8> {
+        format!(
+            r#"{{
+                "organizationId": "aa93e8a8-2aa3-470b-b914-caad8a255dd8",
+                "eventType": "{}",
+                "resourceId": "403f8dde-6d17-4e3b-b0ca-5f0ee8e2a9a3",
+                "eventDate": "2021-03-19T15:09:32.921+01:00"
+            }}"#,
+            event_type
+        )
+        .into_bytes()
+    }
+
+    #[test]
+    fn valid_signatures_are_accepted() {
+        let body = body("invoice.created");
+        let callback = verifier().parse(&body, &sign(private_key(), &body));
+        assert_eq!(callback.unwrap().event_type, EventType::InvoiceCreated);
+    }
+
+    #[test]
+    fn tampered_bodies_are_rejected() {
+        let signature = sign(private_key(), &body("invoice.created"));
+        let result = verifier().verify(&body("invoice.deleted"), &signature);
+        assert!(matches!(result, Err(Error::InvalidSignature)));
+        let result = verifier().verify(b"", "not base64!");
+        assert!(matches!(result, Err(Error::InvalidSignature)));
+    }
+
+    #[test]
+    fn signatures_of_other_keys_are_rejected() {
+        let other = RsaPrivateKey::new(&mut rand::thread_rng(), 1024).unwrap();
+        let body = body("invoice.created");
+        let result = verifier().verify(&body, &sign(&other, &body));
+        assert!(matches!(result, Err(Error::InvalidSignature)));
+    }
+
+    #[test]
+    fn pkcs1_and_pkcs8_keys_are_supported() {
+        let public_key = private_key().to_public_key();
+        let body = body("invoice.created");
+        let signature = sign(private_key(), &body);
+        for pem in &[
+            public_key.to_pkcs1_pem(LineEnding::LF).unwrap(),
+            public_key.to_public_key_pem(LineEnding::LF).unwrap(),
+        ] {
+            let verifier = Verifier::from_pem(pem).unwrap();
+            assert!(verifier.verify(&body, &signature).is_ok());
+        }
+        assert!(matches!(
+            Verifier::from_pem("-----BEGIN PUBLIC KEY-----"),
+            Err(Error::InvalidPublicKey)
+        ));
+    }
+
+    fn recording_dispatcher(
+        calls: &Arc<Mutex<Vec<String>>>,
+        fallback: bool,
+    ) -> Dispatcher {
+        let record = |name: &'static str| {
+            let calls = calls.clone();
+            move |callback: WebhookCallback| {
+                let event_type =
+                    serde_plain::to_string(&callback.event_type).unwrap();
+                calls
+                    .lock()
+                    .unwrap()
+                    .push(format!("{} {}", name, event_type));
+                async { Ok(()) }
+            }
+        };
+        let dispatcher = Dispatcher::new(verifier())
+            .on(EventType::InvoiceCreated, record("first"))
+            .on(EventType::ContactChanged, record("contact"))
+            .on(EventType::InvoiceCreated, record("second"));
+        if fallback {
+            dispatcher.fallback(record("fallback"))
+        } else {
+            dispatcher
+        }
+    }
+
+    fn dispatch(dispatcher: &Dispatcher, event_type: &str) -> Result<()> {
+        let body = body(event_type);
+        let signature = sign(private_key(), &body);
+        futures::executor::block_on(dispatcher.dispatch(&body, &signature))
+    }
+
+    #[test]
+    fn events_are_routed_to_their_handlers() {
+        let calls = Arc::new(Mutex::new(vec![]));
+        let dispatcher = recording_dispatcher(&calls, true);
+        dispatch(&dispatcher, "invoice.created").unwrap();
+        assert_eq!(
+            *calls.lock().unwrap(),
+            ["first invoice.created", "second invoice.created"]
+        );
+    }
+
+    #[test]
+    fn unhandled_events_are_passed_to_the_fallback() {
+        let calls = Arc::new(Mutex::new(vec![]));
+        let dispatcher = recording_dispatcher(&calls, true);
+        dispatch(&dispatcher, "invoice.deleted").unwrap();
+        assert_eq!(*calls.lock().unwrap(), ["fallback invoice.deleted"]);
+
+        let calls = Arc::new(Mutex::new(vec![]));
+        let dispatcher = recording_dispatcher(&calls, false);
+        dispatch(&dispatcher, "invoice.deleted").unwrap();
+        assert!(calls.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn handler_errors_are_returned() {
+        let dispatcher = Dispatcher::new(verifier())
+            .on(EventType::InvoiceCreated, |_| async {
+                Err(Error::FailedToLoadApiKey)
+            });
+        let result = dispatch(&dispatcher, "invoice.created");
+        assert!(matches!(result, Err(Error::FailedToLoadApiKey)));
+    }
+}
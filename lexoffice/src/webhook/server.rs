@@ -0,0 +1,206 @@
+//! An embedded HTTP server receiving webhooks
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use lexoffice::model::event_subscriptions::EventType;
+//! use lexoffice::webhook::{server, Dispatcher, Verifier};
+//!
+//! # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! let pem = std::fs::read_to_string("lexoffice-public-key.pub")?;
+//! let dispatcher = Dispatcher::new(Verifier::from_pem(&pem)?)
+//!     .on(EventType::ContactChanged, |callback| async move {
+//!         println!("contact {} changed", callback.resource_id);
+//!         Ok(())
+//!     });
+//! server::serve(([0, 0, 0, 0], 8080).into(), dispatcher).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use super::{Dispatcher, SIGNATURE_HEADER};
+use crate::Error;
+use crate::Result;
+use http_body::{LengthLimitError, Limited};
+use hyper::header::CONTENT_LENGTH;
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Method, Request, Response, Server, StatusCode};
+use std::convert::Infallible;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+/// The maximum size of a webhook body in bytes. LexOffice's webhooks are
+/// only a few hundred bytes long.
+pub const MAX_BODY_SIZE: usize = 64 * 1024;
+
+/// Handles a single webhook request.
+///
+/// This can be used to receive webhooks in an existing `hyper` service.
+/// Requests with an invalid signature are answered with
+/// `401 Unauthorized`, failing handlers with `500 Internal Server Error`.
+/// Bodies larger than `MAX_BODY_SIZE` are rejected with
+/// `413 Payload Too Large` before their signature is checked.
+pub async fn handle(
+    dispatcher: &Dispatcher,
+    request: Request<Body>,
+) -> Response<Body> {
+    if request.method() != Method::POST {
+        return status(StatusCode::METHOD_NOT_ALLOWED);
+    }
+    let signature = match request
+        .headers()
+        .get(SIGNATURE_HEADER)
+        .and_then(|x| x.to_str().ok())
+    {
+        Some(signature) => signature.to_string(),
+        None => return status(StatusCode::UNAUTHORIZED),
+    };
+    let content_length = request
+        .headers()
+        .get(CONTENT_LENGTH)
+        .and_then(|x| x.to_str().ok())
+        .and_then(|x| x.parse::<u64>().ok());
+    if matches!(content_length, Some(x) if x > MAX_BODY_SIZE as u64) {
+        return status(StatusCode::PAYLOAD_TOO_LARGE);
+    }
+    let body = Limited::new(request.into_body(), MAX_BODY_SIZE);
+    let body = match hyper::body::to_bytes(body).await {
+        Ok(body) => body,
+        Err(e) if e.is::<LengthLimitError>() => {
+            return status(StatusCode::PAYLOAD_TOO_LARGE)
+        }
+        Err(_) => return status(StatusCode::BAD_REQUEST),
+    };
+
+    match dispatcher.dispatch(&body, &signature).await {
+        Ok(()) => status(StatusCode::OK),
+        Err(Error::InvalidSignature) => status(StatusCode::UNAUTHORIZED),
+        Err(Error::Json(_)) => status(StatusCode::BAD_REQUEST),
+        Err(_) => status(StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
+
+/// Listens on `addr` and passes all received webhooks to `dispatcher`.
+pub async fn serve(addr: SocketAddr, dispatcher: Dispatcher) -> Result<()> {
+    let dispatcher = Arc::new(dispatcher);
+    let make_service = make_service_fn(move |_| {
+        let dispatcher = dispatcher.clone();
+        async move {
+            Ok::<_, Infallible>(service_fn(move |request| {
+                let dispatcher = dispatcher.clone();
+                async move {
+                    Ok::<_, Infallible>(handle(&dispatcher, request).await)
+                }
+            }))
+        }
+    });
+    Server::try_bind(&addr)
+        .map_err(to_io)?
+        .serve(make_service)
+        .await
+        .map_err(to_io)?;
+    Ok(())
+}
+
+fn to_io(error: hyper::Error) -> Error {
+    io::Error::other(error).into()
+}
+
+fn status(status: StatusCode) -> Response<Body> {
+    let mut response = Response::new(Body::empty());
+    *response.status_mut() = status;
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::event_subscriptions::EventType;
+    use crate::webhook::tests::{body, private_key, sign, verifier};
+
+    fn dispatcher() -> Dispatcher {
+        Dispatcher::new(verifier())
+            .on(EventType::InvoiceCreated, |_| async { Ok(()) })
+            .on(EventType::InvoiceDeleted, |_| async {
+                Err(Error::FailedToLoadApiKey)
+            })
+    }
+
+    fn request(
+        method: Method,
+        body: Vec<u8>,
+        signature: Option<&str>,
+    ) -> Request<Body> {
+        let mut builder = Request::builder().method(method).uri("/webhook");
+        if let Some(signature) = signature {
+            builder = builder.header(SIGNATURE_HEADER, signature);
+        }
+        builder.body(Body::from(body)).unwrap()
+    }
+
+    async fn status_of(request: Request<Body>) -> StatusCode {
+        handle(&dispatcher(), request).await.status()
+    }
+
+    fn signed(event_type: &str) -> Request<Body> {
+        let body = body(event_type);
+        let signature = sign(private_key(), &body);
+        request(Method::POST, body, Some(&signature))
+    }
+
+    #[tokio::test]
+    async fn valid_webhooks_are_accepted() {
+        assert_eq!(status_of(signed("invoice.created")).await, StatusCode::OK);
+        // Events without a handler are ignored
+        assert_eq!(status_of(signed("contact.changed")).await, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn other_methods_are_not_allowed() {
+        let request = request(Method::GET, vec![], None);
+        assert_eq!(status_of(request).await, StatusCode::METHOD_NOT_ALLOWED);
+    }
+
+    #[tokio::test]
+    async fn invalid_signatures_are_unauthorized() {
+        let unsigned = request(Method::POST, body("invoice.created"), None);
+        assert_eq!(status_of(unsigned).await, StatusCode::UNAUTHORIZED);
+
+        let signature = sign(private_key(), b"{}");
+        let tampered =
+            request(Method::POST, body("invoice.created"), Some(&signature));
+        assert_eq!(status_of(tampered).await, StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn invalid_payloads_are_bad_requests() {
+        let signature = sign(private_key(), b"{}");
+        let request = request(Method::POST, b"{}".to_vec(), Some(&signature));
+        assert_eq!(status_of(request).await, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn failing_handlers_are_internal_server_errors() {
+        assert_eq!(
+            status_of(signed("invoice.deleted")).await,
+            StatusCode::INTERNAL_SERVER_ERROR
+        );
+    }
+
+    #[tokio::test]
+    async fn large_bodies_are_rejected() {
+        let body = vec![b' '; MAX_BODY_SIZE + 1];
+        let signature = sign(private_key(), &body);
+
+        let mut announced =
+            request(Method::POST, body.clone(), Some(&signature));
+        announced
+            .headers_mut()
+            .insert(CONTENT_LENGTH, body.len().into());
+        assert_eq!(status_of(announced).await, StatusCode::PAYLOAD_TOO_LARGE);
+
+        let unannounced = request(Method::POST, body, Some(&signature));
+        assert_eq!(status_of(unannounced).await, StatusCode::PAYLOAD_TOO_LARGE);
+    }
+}
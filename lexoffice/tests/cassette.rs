@@ -0,0 +1,132 @@
+use lexoffice::model::contacts::{Company, Customer, Roles};
+use lexoffice::model::Contact;
+use lexoffice::testing::FakeServer;
+use lexoffice::{ApiKey, Cassette, Client, Error};
+use std::path::PathBuf;
+
+fn contact(name: &str) -> Contact {
+    Contact::builder()
+        .roles(
+            Roles::builder()
+                .customer(Customer::builder().build())
+                .build(),
+        )
+        .company(Company::builder().name(name.to_string()).build())
+        .build()
+}
+
+fn client(server: &FakeServer, cassette: Cassette) -> Client {
+    Client::builder()
+        .api_key(ApiKey::from("fake-api-key"))
+        .base_url(server.base_url())
+        .cassette(cassette)
+        .build()
+}
+
+fn cassette_path() -> PathBuf {
+    std::env::temp_dir()
+        .join(format!("lexoffice-cassette-{}.json", rand::random::<u64>()))
+}
+
+#[tokio::test]
+async fn recorded_interactions_are_replayed() {
+    let path = cassette_path();
+    let server = FakeServer::start();
+    let cassette = Cassette::record(&path).redact("/company/name");
+    let recorder = client(&server, cassette);
+    let id = recorder
+        .request::<Contact>()
+        .save(contact("Bike & Ride GmbH"))
+        .await
+        .unwrap()
+        .id;
+    recorder.request::<Contact>().by_id(id).await.unwrap();
+
+    let recorded = std::fs::read_to_string(&path).unwrap();
+    assert!(!recorded.contains("Bike & Ride GmbH"));
+    assert!(!recorded.contains("fake-api-key"));
+
+    let cassette = Cassette::replay(&path).unwrap().redact("/company/name");
+    let player = client(&server, cassette.clone());
+    let result = player
+        .request::<Contact>()
+        .save(contact("Pedal Power AG"))
+        .await
+        .unwrap();
+    assert_eq!(result.id, id);
+    let replayed = player.request::<Contact>().by_id(id).await.unwrap();
+    let company = replayed.company.unwrap();
+    assert_eq!(company.name, "REDACTED");
+    assert!(cassette.is_finished());
+    assert_eq!(server.requests().len(), 2);
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn requests_with_other_bodies_are_unmatched() {
+    let path = cassette_path();
+    let server = FakeServer::start();
+    let recorder = client(&server, Cassette::record(&path));
+    recorder
+        .request::<Contact>()
+        .save(contact("Bike & Ride GmbH"))
+        .await
+        .unwrap();
+
+    let player = client(&server, Cassette::replay(&path).unwrap());
+    let result = player
+        .request::<Contact>()
+        .save(contact("Pedal Power AG"))
+        .await;
+    match result {
+        Err(Error::UnmatchedRequest(_)) => (),
+        x => panic!("expected an unmatched request, got {:?}", x),
+    }
+    assert_eq!(server.requests().len(), 1);
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn different_requests_are_replayed_in_any_order() {
+    let path = cassette_path();
+    let server = FakeServer::start();
+    let recorder = client(&server, Cassette::record(&path));
+    let mut ids = vec![];
+    for name in &["Bike & Ride GmbH", "Pedal Power AG"] {
+        ids.push(
+            recorder
+                .request::<Contact>()
+                .save(contact(name))
+                .await
+                .unwrap()
+                .id,
+        );
+    }
+    for id in &ids {
+        recorder.request::<Contact>().by_id(*id).await.unwrap();
+    }
+
+    let cassette = Cassette::replay(&path).unwrap();
+    let player = client(&server, cassette.clone());
+    for name in &["Pedal Power AG", "Bike & Ride GmbH"] {
+        player
+            .request::<Contact>()
+            .save(contact(name))
+            .await
+            .unwrap();
+    }
+    for id in ids.iter().rev() {
+        let replayed = player.request::<Contact>().by_id(*id).await.unwrap();
+        assert_eq!(replayed.id, Some(*id));
+    }
+    assert!(cassette.is_finished());
+    match player.request::<Contact>().by_id(ids[0]).await {
+        Err(Error::UnmatchedRequest(_)) => (),
+        x => panic!("expected an unmatched request, got {:?}", x),
+    }
+    assert_eq!(server.requests().len(), 4);
+
+    std::fs::remove_file(&path).unwrap();
+}
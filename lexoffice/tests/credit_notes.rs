@@ -0,0 +1,133 @@
+use lexoffice::model::invoices::*;
+use lexoffice::model::{CreditNote, Invoice};
+use lexoffice::request::Cancellation;
+use lexoffice::testing::FakeServer;
+use lexoffice::types::{Amount, Currency};
+use lexoffice::Error;
+use serde_json::json;
+
+fn invoice() -> Invoice {
+    let line_item = |name: &str, net_amount: Amount| {
+        LineItems::builder()
+            ._type(Type::Custom)
+            .name(name.to_string())
+            .quantity(2.0)
+            .unit_name("Stück".to_string())
+            .unit_price(
+                UnitPrice::builder()
+                    .currency(Currency::EUR)
+                    .net_amount(net_amount)
+                    .tax_rate_percentage(19.0)
+                    .build(),
+            )
+            .build()
+    };
+    Invoice::builder()
+        .voucher_date(chrono::Utc::now())
+        .address(
+            Address::builder()
+                .name("Bike & Ride GmbH & Co. KG".to_string())
+                .country_code("DE".to_string())
+                .build(),
+        )
+        .line_items(vec![
+            line_item("Abus Kabelschloss Primo 590", Amount::new(1340, 2)),
+            line_item("Energieriegel Testpaket", Amount::new(5, 0)),
+        ])
+        .total_price(TotalPrice::builder().currency(Currency::EUR).build())
+        .tax_conditions(TaxConditions::builder().tax_type(TaxType::Net).build())
+        .shipping_conditions(
+            ShippingConditions::builder()
+                .shipping_type(ShippingType::None)
+                .build(),
+        )
+        .build()
+}
+
+#[tokio::test]
+async fn invoices_are_cancelled_by_finalized_credit_notes() {
+    let server = FakeServer::start();
+    let client = server.client();
+    let invoice_id = client
+        .request::<Invoice>()
+        .finalize()
+        .save(invoice())
+        .await
+        .unwrap()
+        .id;
+
+    let result = client
+        .request::<CreditNote>()
+        .cancel_invoice(invoice_id)
+        .await
+        .unwrap();
+
+    let credit_note = client
+        .request::<CreditNote>()
+        .by_id(result.id)
+        .await
+        .unwrap();
+    let invoice = client.request::<Invoice>().by_id(invoice_id).await.unwrap();
+    assert_eq!(
+        serde_json::to_value(&credit_note.voucher_status).unwrap(),
+        json!("open")
+    );
+    assert_eq!(
+        credit_note.introduction,
+        Some(format!(
+            "Rechnungskorrektur zur Rechnung {}",
+            invoice.voucher_number.unwrap()
+        ))
+    );
+    assert_eq!(credit_note.address.name, invoice.address.unwrap().name);
+    let line_items = credit_note
+        .line_items
+        .iter()
+        .map(|x| {
+            let price = x.unit_price.as_ref().unwrap();
+            (x.name.as_str(), x.quantity, price.net_amount)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        line_items,
+        [
+            (
+                "Abus Kabelschloss Primo 590",
+                Some(2.0),
+                Some(Amount::new(1340, 2))
+            ),
+            (
+                "Energieriegel Testpaket",
+                Some(2.0),
+                Some(Amount::new(5, 0))
+            ),
+        ]
+    );
+
+    let related = server.objects("invoices")[0]["relatedVouchers"].clone();
+    assert_eq!(related[0]["id"], json!(result.id));
+    assert_eq!(related[0]["voucherType"], json!("creditnote"));
+}
+
+#[tokio::test]
+async fn invalid_partial_cancellations_are_not_saved() {
+    let server = FakeServer::start();
+    let client = server.client();
+    let invoice_id = client
+        .request::<Invoice>()
+        .finalize()
+        .save(invoice())
+        .await
+        .unwrap()
+        .id;
+
+    let result = client
+        .request::<CreditNote>()
+        .cancel_invoice_partially(invoice_id, Cancellation::LineItems(vec![2]))
+        .await;
+    match result {
+        Err(Error::InvalidCancellation(_)) => (),
+        x => panic!("expected an invalid cancellation, got {:?}", x),
+    }
+    assert!(server.objects("credit-notes").is_empty());
+}
@@ -0,0 +1,283 @@
+use lexoffice::model::articles::{Price, Type};
+use lexoffice::model::contacts::{Company, Customer, Roles};
+use lexoffice::model::{Article, Contact};
+use lexoffice::request::Request;
+use lexoffice::testing::FakeServer;
+use lexoffice::{ApiKey, Client, Error, RetryPolicy};
+use reqwest::StatusCode;
+use serde_json::json;
+
+fn contact(name: &str) -> Contact {
+    Contact::builder()
+        .roles(
+            Roles::builder()
+                .customer(Customer::builder().build())
+                .build(),
+        )
+        .company(Company::builder().name(name.to_string()).build())
+        .build()
+}
+
+fn article(title: &str) -> Article {
+    Article::builder()
+        .title(title.to_string())
+        ._type(Type::Service)
+        .price(Price::builder().build())
+        .build()
+}
+
+#[tokio::test]
+async fn pages_are_split_by_size() {
+    let server = FakeServer::start();
+    let client = server.client();
+    for i in 0..5 {
+        let contact = contact(&format!("Customer {}", i));
+        client.request::<Contact>().save(contact).await.unwrap();
+    }
+
+    let first = client.request::<Contact>().page_size(0, 2).await.unwrap();
+    assert_eq!(first.content.len(), 2);
+    assert_eq!((first.total_pages, first.total_elements), (3, 5));
+    assert!(first.first && !first.last);
+
+    let last = client.request::<Contact>().page_size(2, 2).await.unwrap();
+    assert_eq!(last.content.len(), 1);
+    assert_eq!(last.number, 2);
+    assert!(!last.first && last.last);
+    let company = last.content[0].company.as_ref().unwrap();
+    assert_eq!(company.name, "Customer 4");
+
+    let beyond = client.request::<Contact>().page_size(3, 2).await.unwrap();
+    assert!(beyond.content.is_empty());
+}
+
+#[tokio::test]
+async fn contacts_are_filtered() {
+    let server = FakeServer::start();
+    server.insert(
+        "contacts",
+        &json!({
+            "roles": { "customer": { "number": 10001 } },
+            "company": { "name": "Bike & Ride GmbH" },
+            "emailAddresses": { "business": ["info@bikeandride.de"] }
+        }),
+    );
+    server.insert(
+        "contacts",
+        &json!({
+            "roles": { "vendor": { "number": 70001 } },
+            "company": { "name": "Fahrrad Müller KG" },
+            "emailAddresses": { "business": ["einkauf@mueller.de"] }
+        }),
+    );
+    server.insert(
+        "contacts",
+        &json!({
+            "roles": {
+                "customer": { "number": 10002 },
+                "vendor": { "number": 70002 }
+            },
+            "person": { "firstName": "Max", "lastName": "Bike" },
+            "emailAddresses": { "private": ["max@example.com"] }
+        }),
+    );
+    let client = server.client();
+    let names = |request: Request<Contact>| async move {
+        request
+            .page(0)
+            .await
+            .unwrap()
+            .content
+            .into_iter()
+            .map(|x| match (x.company, x.person) {
+                (Some(company), _) => company.name,
+                (None, Some(person)) => person.last_name,
+                (None, None) => String::new(),
+            })
+            .collect::<Vec<_>>()
+    };
+    let request = || client.request::<Contact>();
+
+    assert_eq!(
+        names(request().email("info@bike%")).await,
+        ["Bike & Ride GmbH"]
+    );
+    assert_eq!(names(request().email("%@example.com")).await, ["Bike"]);
+    assert_eq!(
+        names(request().name("bike%")).await,
+        ["Bike & Ride GmbH", "Bike"]
+    );
+    assert_eq!(
+        names(request().name("%müller%")).await,
+        ["Fahrrad Müller KG"]
+    );
+    assert_eq!(names(request().number(70001)).await, ["Fahrrad Müller KG"]);
+    assert_eq!(names(request().number(10002)).await, ["Bike"]);
+    assert_eq!(
+        names(request().customer(true)).await,
+        ["Bike & Ride GmbH", "Bike"]
+    );
+    assert_eq!(
+        names(request().customer(false)).await,
+        ["Fahrrad Müller KG"]
+    );
+    assert_eq!(
+        names(request().vendor(true)).await,
+        ["Fahrrad Müller KG", "Bike"]
+    );
+
+    assert_eq!(names(request().name("%bike%").vendor(true)).await, ["Bike"]);
+    assert_eq!(
+        names(request().customer(true).number(70002)).await,
+        ["Bike"]
+    );
+    assert!(names(request().email("%@mueller.de").customer(true))
+        .await
+        .is_empty());
+}
+
+#[tokio::test]
+async fn stale_updates_are_version_conflicts() {
+    let server = FakeServer::start();
+    let client = server.client();
+    let id = client
+        .request::<Contact>()
+        .save(contact("Bike & Ride GmbH"))
+        .await
+        .unwrap()
+        .id;
+    let stale = client.request::<Contact>().by_id(id).await.unwrap();
+
+    let mut current = stale.clone();
+    current.note = Some("Key account".to_string());
+    let result = client.request::<Contact>().update(current).await.unwrap();
+    assert_eq!(result.version, 1);
+
+    match client.request::<Contact>().update(stale).await {
+        Err(Error::VersionConflict(conflict)) => {
+            assert_eq!(conflict.error().status(), StatusCode::CONFLICT);
+            assert_eq!(conflict.current_version(), Some(1));
+        }
+        x => panic!("expected a version conflict, got {:?}", x),
+    }
+    let contact = client.request::<Contact>().by_id(id).await.unwrap();
+    assert_eq!(contact.note.as_deref(), Some("Key account"));
+}
+
+#[tokio::test]
+async fn conflicting_updates_are_retried_with_one_get_per_conflict() {
+    let server = FakeServer::start();
+    let client = server.client();
+    let id = server.insert("articles", &article("Consulting"));
+    let mut calls = 0;
+
+    let result = client
+        .request::<Article>()
+        .update_with(id, |article| {
+            calls += 1;
+            if calls == 1 {
+                server.fail_next("articles", StatusCode::CONFLICT);
+            }
+            article.note = Some("Hourly".to_string());
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(calls, 2);
+    assert_eq!(result.version, 1);
+    assert_eq!(
+        server.requests(),
+        vec![
+            format!("GET /v1/articles/{}", id),
+            format!("PUT /v1/articles/{}", id),
+            format!("GET /v1/articles/{}", id),
+            format!("PUT /v1/articles/{}", id),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn exhausted_update_retries_are_version_conflicts() {
+    let server = FakeServer::start();
+    let client = server.client();
+    let id = server.insert("articles", &article("Consulting"));
+
+    let result = client
+        .request::<Article>()
+        .update_with_retries(id, 1, |_| {
+            server.fail_next("articles", StatusCode::CONFLICT);
+            Ok(())
+        })
+        .await;
+
+    match result {
+        Err(Error::VersionConflict(conflict)) => {
+            assert_eq!(conflict.current_version(), Some(0))
+        }
+        x => panic!("expected a version conflict, got {:?}", x),
+    }
+    let requests = server.requests();
+    assert_eq!(requests.len(), 5);
+    assert_eq!(requests.iter().filter(|x| x.starts_with("GET ")).count(), 3);
+}
+
+#[tokio::test]
+async fn deleted_objects_are_not_found() {
+    let server = FakeServer::start();
+    let client = server.client();
+    let id = client
+        .request::<Article>()
+        .save(article("Consulting"))
+        .await
+        .unwrap()
+        .id;
+
+    client.request::<Article>().delete(id).await.unwrap();
+
+    match client.request::<Article>().by_id(id).await {
+        Err(Error::LexOffice(e)) => assert!(e.is_not_found()),
+        x => panic!("expected a not found error, got {:?}", x),
+    }
+    match client.request::<Article>().delete(id).await {
+        Err(Error::LexOffice(e)) => assert!(e.is_not_found()),
+        x => panic!("expected a not found error, got {:?}", x),
+    }
+    assert!(server.objects("articles").is_empty());
+}
+
+#[tokio::test]
+async fn injected_errors_are_returned_without_retry_policy() {
+    let server = FakeServer::start();
+    let client = server.client();
+    server.fail_next("articles", StatusCode::TOO_MANY_REQUESTS);
+    server.fail_next("articles", StatusCode::SERVICE_UNAVAILABLE);
+
+    for status in &[
+        StatusCode::TOO_MANY_REQUESTS,
+        StatusCode::SERVICE_UNAVAILABLE,
+    ] {
+        match client.request::<Article>().page(0).await {
+            Err(Error::LexOffice(e)) => assert_eq!(e.status(), *status),
+            x => panic!("expected {}, got {:?}", status, x),
+        }
+    }
+    assert!(client.request::<Article>().page(0).await.is_ok());
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn injected_errors_are_retried_with_retry_policy() {
+    let server = FakeServer::start();
+    let client = Client::builder()
+        .api_key(ApiKey::from("fake-api-key"))
+        .base_url(server.base_url())
+        .retry_policy(RetryPolicy::default())
+        .build();
+    server.fail_next("articles", StatusCode::TOO_MANY_REQUESTS);
+    server.fail_next("articles", StatusCode::SERVICE_UNAVAILABLE);
+
+    let page = client.request::<Article>().page(0).await.unwrap();
+    assert!(page.content.is_empty());
+    assert_eq!(server.requests().len(), 3);
+}
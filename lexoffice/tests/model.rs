@@ -0,0 +1,96 @@
+use lexoffice::model::articles::Type;
+use lexoffice::model::Article;
+use serde_json::json;
+
+fn article(type_: &str) -> serde_json::Value {
+    json!({
+        "id": "eb46d328-e1dd-11eb-8c6c-37a7d0a2d8e3",
+        "title": "Lexware buchhaltung Premium 2024",
+        "type": type_,
+        "price": {
+            "netPrice": 61.90,
+            "leadingPrice": "NET",
+            "taxRate": 19,
+            "currency": "EUR"
+        },
+        "version": 1,
+        "externalReference": "SKU-4711"
+    })
+}
+
+#[test]
+fn extra_fields_are_always_available() {
+    // the field exists with and without the `strict` feature, so enabling
+    // the feature doesn't break code that uses it
+    let article = Article::builder().title("Service".to_string()).build();
+    assert!(article.extra.is_empty());
+    assert!(!serde_json::to_value(&article)
+        .unwrap()
+        .as_object()
+        .unwrap()
+        .contains_key("extra"));
+}
+
+#[cfg(not(feature = "strict"))]
+#[test]
+fn unknown_fields_round_trip() {
+    let value = article("PRODUCT");
+    let article: Article = serde_json::from_value(value.clone()).unwrap();
+
+    assert_eq!(article.extra["externalReference"], json!("SKU-4711"));
+    let price = article.price.as_ref().unwrap();
+    assert_eq!(
+        price.leading_price,
+        Some(lexoffice::model::articles::LeadingPrice::Net)
+    );
+    assert_eq!(price.extra["currency"], json!("EUR"));
+
+    let serialized = serde_json::to_value(&article).unwrap();
+    assert_eq!(serialized["externalReference"], value["externalReference"]);
+    assert_eq!(serialized["price"]["currency"], value["price"]["currency"]);
+}
+
+#[cfg(not(feature = "strict"))]
+#[test]
+fn unknown_enum_variants_round_trip() {
+    let article: Article =
+        serde_json::from_value(article("SUBSCRIPTION")).unwrap();
+
+    assert_eq!(
+        article._type,
+        Some(Type::Unknown("SUBSCRIPTION".to_string()))
+    );
+    let serialized = serde_json::to_value(&article).unwrap();
+    assert_eq!(serialized["type"], json!("SUBSCRIPTION"));
+}
+
+#[cfg(feature = "strict")]
+#[test]
+fn unknown_fields_are_rejected() {
+    let err = serde_json::from_value::<Article>(article("PRODUCT"))
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("unknown field"), "{}", err);
+
+    let mut value = article("PRODUCT");
+    value.as_object_mut().unwrap().remove("externalReference");
+    let err = serde_json::from_value::<Article>(value)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("unknown field `currency`"), "{}", err);
+}
+
+#[cfg(feature = "strict")]
+#[test]
+fn unknown_enum_variants_are_rejected() {
+    let mut value = article("SUBSCRIPTION");
+    value.as_object_mut().unwrap().remove("externalReference");
+    value["price"].as_object_mut().unwrap().remove("currency");
+    assert!(serde_json::from_value::<Article>(value).is_err());
+
+    let mut value = article("PRODUCT");
+    value.as_object_mut().unwrap().remove("externalReference");
+    value["price"].as_object_mut().unwrap().remove("currency");
+    let article: Article = serde_json::from_value(value).unwrap();
+    assert_eq!(article._type, Some(Type::Product));
+}
@@ -0,0 +1,54 @@
+use lexoffice::model::contacts::{Company, Customer, Roles};
+use lexoffice::model::Contact;
+use lexoffice::testing::FakeServer;
+use lexoffice::{ApiKey, Client, RetryPolicy};
+use reqwest::StatusCode;
+
+fn client(server: &FakeServer) -> Client {
+    Client::builder()
+        .api_key(ApiKey::from("fake-api-key"))
+        .base_url(server.base_url())
+        .retry_policy(RetryPolicy::default())
+        .build()
+}
+
+#[tokio::test]
+async fn throttled_get_is_retried_once() {
+    let server = FakeServer::start();
+    server.fail_next("contacts", StatusCode::TOO_MANY_REQUESTS);
+
+    let page = client(&server).request::<Contact>().page(0).await.unwrap();
+    assert!(page.content.is_empty());
+    assert_eq!(
+        server.requests(),
+        vec!["GET /v1/contacts?page=0", "GET /v1/contacts?page=0"]
+    );
+}
+
+#[tokio::test]
+async fn throttled_post_is_not_retried() {
+    let server = FakeServer::start();
+    server.fail_next("contacts", StatusCode::TOO_MANY_REQUESTS);
+
+    let contact = Contact::builder()
+        .roles(
+            Roles::builder()
+                .customer(Customer::builder().build())
+                .build(),
+        )
+        .company(
+            Company::builder()
+                .name("Bike & Ride GmbH".to_string())
+                .build(),
+        )
+        .build();
+    let result = client(&server).request::<Contact>().save(contact).await;
+    match result {
+        Err(lexoffice::Error::LexOffice(e)) => {
+            assert_eq!(e.status(), StatusCode::TOO_MANY_REQUESTS)
+        }
+        x => panic!("expected a throttling error, got {:?}", x),
+    }
+    assert_eq!(server.requests(), vec!["POST /v1/contacts"]);
+    assert!(server.objects("contacts").is_empty());
+}
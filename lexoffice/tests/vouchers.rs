@@ -0,0 +1,113 @@
+use lexoffice::model::{PostingCategory, Voucher};
+use lexoffice::testing::FakeServer;
+use lexoffice::types::Amount;
+use lexoffice::Error;
+use reqwest::StatusCode;
+use serde_json::json;
+use std::path::PathBuf;
+
+fn voucher() -> Voucher {
+    let category: PostingCategory = serde_json::from_value(json!({
+        "id": "8f8664a8-fd86-11e1-a21f-0800200c9a66",
+        "name": "Bürobedarf",
+        "type": "outgo"
+    }))
+    .unwrap();
+    Voucher::purchase_invoice(
+        "RE-2021-0815",
+        chrono::NaiveDate::from_ymd_opt(2021, 4, 1).unwrap(),
+        &category,
+        Amount::new(11900, 2),
+        19.0,
+    )
+    .unwrap()
+}
+
+/// Writes `bytes` to a new file named `receipt.<extension>` in a temporary
+/// directory.
+fn receipt(extension: &str, bytes: &[u8]) -> PathBuf {
+    let dir = std::env::temp_dir().join(uuid::Uuid::new_v4().to_string());
+    std::fs::create_dir(&dir).unwrap();
+    let path = dir.join(format!("receipt.{}", extension));
+    std::fs::write(&path, bytes).unwrap();
+    path
+}
+
+#[tokio::test]
+async fn receipts_are_attached_to_saved_vouchers() {
+    let server = FakeServer::start();
+    let path = receipt("pdf", b"%PDF-1.4\n");
+
+    let result = server
+        .client()
+        .request::<Voucher>()
+        .save_receipt(voucher(), &path)
+        .await
+        .unwrap();
+
+    let vouchers = server.objects("vouchers");
+    assert_eq!(vouchers.len(), 1);
+    assert_eq!(vouchers[0]["id"], json!(result.id));
+    assert_eq!(vouchers[0]["files"].as_array().unwrap().len(), 1);
+    assert_eq!(
+        server.requests(),
+        vec![
+            "POST /v1/vouchers".to_string(),
+            format!("POST /v1/vouchers/{}/files", result.id),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn missing_receipts_dont_save_the_voucher() {
+    let server = FakeServer::start();
+    let path = receipt("pdf", b"%PDF-1.4\n").with_file_name("missing.pdf");
+
+    let result = server
+        .client()
+        .request::<Voucher>()
+        .save_receipt(voucher(), &path)
+        .await;
+
+    assert!(matches!(result, Err(Error::Io(_))));
+    assert!(server.objects("vouchers").is_empty());
+    assert!(server.requests().is_empty());
+}
+
+#[tokio::test]
+async fn failed_attachments_return_the_saved_voucher_id() {
+    let server = FakeServer::start();
+    let client = server.client();
+    // LexOffice only accepts PDF, PNG and JPEG receipts
+    let path = receipt("txt", b"not a receipt");
+
+    let result = client
+        .request::<Voucher>()
+        .save_receipt(voucher(), &path)
+        .await;
+
+    let err = match result {
+        Err(Error::ReceiptNotAttached(x)) => x,
+        x => panic!("expected ReceiptNotAttached, got {:?}", x),
+    };
+    match err.error() {
+        Error::LexOffice(x) => {
+            assert_eq!(x.status(), StatusCode::NOT_ACCEPTABLE)
+        }
+        x => panic!("expected a LexOffice error, got {:?}", x),
+    }
+    let vouchers = server.objects("vouchers");
+    assert_eq!(vouchers.len(), 1);
+    assert_eq!(vouchers[0]["id"], json!(err.voucher_id()));
+    assert!(vouchers[0]["files"].is_null());
+
+    // the receipt can be attached to the saved voucher later on
+    let path = receipt("pdf", b"%PDF-1.4\n");
+    client
+        .request::<Voucher>()
+        .attach_path(err.voucher_id(), &path)
+        .await
+        .unwrap();
+    let vouchers = server.objects("vouchers");
+    assert_eq!(vouchers[0]["files"].as_array().unwrap().len(), 1);
+}
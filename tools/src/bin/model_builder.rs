@@ -45,6 +45,43 @@ fn create_mod_rs(modules: &[ModelModule]) -> TokenStream {
     }
 }
 
+const TS_PAGE: &str = "export interface Page<T> {
+    content: T[];
+    first: boolean;
+    last: boolean;
+    totalPages: number;
+    totalElements: number;
+    numberOfElements: number;
+    size: number;
+    number: number;
+    sort: unknown;
+}
+";
+
+/// Creates the rust module of `lexoffice-js` that ships the TypeScript
+/// definitions of the model in the declarations generated by wasm-bindgen.
+fn create_model_ts(modules: &[ModelModule]) -> String {
+    let namespaces = modules
+        .iter()
+        .map(|x| x.codegen_ts())
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!(
+        r###"//! TypeScript definitions of the model. This file was semi-automaticly
+//! generated from The official lexoffice documentation
+//!
+//! See <https://developers.lexoffice.io/docs/> for more information
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_MODEL: &'static str = r##"
+{}
+{}"##;
+"###,
+        TS_PAGE, namespaces
+    )
+}
+
 fn main() -> Result<()> {
     let content_selector = Selector::parse("div.content > h1").unwrap();
 
@@ -91,6 +128,10 @@ fn main() -> Result<()> {
     path.push("mod.rs");
     write_token_stream(&path, create_mod_rs(&modules))?;
 
+    let ts_path =
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../lexoffice-js/src/model.rs");
+    fs::write(ts_path, create_model_ts(&modules))?;
+
     for module in modules {
         let mut path = dir.clone();
         path.push(format!("{}.rs", module.type_name()));
@@ -1,4 +1,6 @@
 use crate::model_builder::utils::mk_doc;
+use crate::model_builder::utils::mk_md;
+use crate::model_builder::utils::ts_doc;
 use proc_macro2::TokenStream;
 use quote::format_ident;
 use quote::quote;
@@ -44,11 +46,45 @@ impl ModelEnum {
 
     pub fn parse_description(&mut self, description: &ElementRef) {
         let strong_selector = Selector::parse("strong").unwrap();
+        let em_selector = Selector::parse("em").unwrap();
 
         self.variants = description
             .select(&strong_selector)
             .map(|x| (x.text().collect::<String>(), None))
             .collect();
+        // Some enums, e.g. in the payments endpoint, list their values in
+        // italics instead.
+        if self.variants.is_empty() {
+            self.variants = description
+                .select(&em_selector)
+                .map(|x| (x.text().collect::<String>(), None))
+                .collect();
+        }
+    }
+
+    /// Generates a union of string literals. Unlike the rust enum it
+    /// doesn't allow unknown values.
+    pub fn codegen_ts(&self) -> String {
+        let variants = self
+            .variants
+            .keys()
+            .filter(|x| {
+                !string_morph::to_pascal_case(x)
+                    .contains("WillSoonAllowAnyValue")
+            })
+            .map(|x| format!("\n        | {:?}", x))
+            .collect::<String>();
+        let variants = if variants.is_empty() {
+            " string".to_string()
+        } else {
+            variants
+        };
+        format!(
+            "{}    export type {} ={};\n",
+            ts_doc(&mk_md(&self.doc.clone().unwrap_or_default()), "    "),
+            self.type_name(),
+            variants,
+        )
     }
 
     pub fn codegen(&self) -> Option<TokenStream> {
@@ -69,12 +105,22 @@ impl ModelEnum {
         });
         let type_name = format_ident!("{}", self.type_name());
         let doc = mk_doc(&self.doc.clone().unwrap_or_default());
+        // Known variants are (de)serialized by the code derived with
+        // `remote = "Self"`, unknown values are kept in `Unknown`.
+        let serialize_known = if self.variants.is_empty() {
+            quote! {}
+        } else {
+            quote! { _ => Self::serialize(self, serializer), }
+        };
         Some(quote! {
             #doc
             #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-            #[serde(deny_unknown_fields)]
+            #[serde(remote = "Self")]
             pub enum #type_name {
-                #( #variants ),*
+                #( #variants, )*
+                #[doc = "A value that is not known to this crate"]
+                #[serde(skip)]
+                Unknown(String),
             }
 
             impl std::str::FromStr for #type_name {
@@ -84,6 +130,31 @@ impl ModelEnum {
                     serde_plain::from_str::<Self>(s)
                 }
             }
+
+            impl Serialize for #type_name {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: serde::Serializer,
+                {
+                    match self {
+                        Self::Unknown(x) => serializer.serialize_str(x),
+                        #serialize_known
+                    }
+                }
+            }
+
+            impl<'de> Deserialize<'de> for #type_name {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: serde::Deserializer<'de>,
+                {
+                    crate::serde::lenient_enum::deserialize(
+                        deserializer,
+                        Self::deserialize,
+                        Self::Unknown,
+                    )
+                }
+            }
         })
     }
 }
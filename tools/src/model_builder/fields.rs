@@ -1,5 +1,7 @@
 use crate::model_builder::enums::ModelEnum;
 use crate::model_builder::utils::mk_doc;
+use crate::model_builder::utils::mk_md;
+use crate::model_builder::utils::ts_doc;
 use proc_macro2::TokenStream;
 use quote::format_ident;
 use quote::quote;
@@ -24,6 +26,7 @@ pub enum ModelType {
     Uuid,
     Integer,
     Number,
+    Amount,
     String,
     Bool,
     Currency,
@@ -58,8 +61,8 @@ impl ModelField {
         }
     }
 
-    pub fn type_name(&self) -> String {
-        let model_type = match &self.model_type {
+    fn base_type_name(&self) -> String {
+        match &self.model_type {
             ModelType::Object(x) => {
                 if x.starts_with('#') {
                     string_morph::to_pascal_case(&self.name)
@@ -70,6 +73,7 @@ impl ModelField {
             ModelType::Uuid => "uuid::Uuid".to_string(),
             ModelType::Integer => "i64".to_string(),
             ModelType::Number => "f64".to_string(),
+            ModelType::Amount => "crate::types::Amount".to_string(),
             ModelType::String => "String".to_string(),
             ModelType::Bool => "bool".to_string(),
             ModelType::CountryCode => "crate::types::CountryCode".to_string(),
@@ -79,13 +83,33 @@ impl ModelField {
             ModelType::Enum(_) => {
                 string_morph::to_pascal_case(self.name.as_str())
             }
-        };
+        }
+    }
+
+    pub fn type_name(&self) -> String {
+        let model_type = self.base_type_name();
         if self.is_list {
             format!("Vec<{}>", model_type)
         } else {
             model_type
         }
     }
+    pub fn ts_type_name(&self) -> String {
+        let model_type = match &self.model_type {
+            ModelType::Object(_) | ModelType::Enum(_) => self.base_type_name(),
+            ModelType::Integer | ModelType::Number | ModelType::Amount => {
+                "number".to_string()
+            }
+            ModelType::Bool => "boolean".to_string(),
+            _ => "string".to_string(),
+        };
+        if self.is_list {
+            format!("{}[]", model_type)
+        } else {
+            model_type
+        }
+    }
+
     pub fn is_reversable_ident(&self) -> bool {
         if self.name == "type" {
             true
@@ -169,6 +193,7 @@ impl ModelField {
             "datetime" => return ModelType::DateTime,
             "uuid" => return ModelType::Uuid,
             "integer" => return ModelType::Integer,
+            "number" if Self::is_money(name) => return ModelType::Amount,
             "number" => return ModelType::Number,
             "string" => return ModelType::String,
             "boolean" => return ModelType::Bool,
@@ -203,6 +228,14 @@ impl ModelField {
         }
     }
 
+    /// Numbers that contain monetary values are stored as
+    /// `crate::types::Amount` to avoid rounding errors.
+    fn is_money(name: &str) -> bool {
+        name.to_ascii_lowercase().ends_with("amount")
+            || name.ends_with("Price")
+            || name == "totalDiscountAbsolute"
+    }
+
     fn find_access_type(name: &str, description: &ElementRef) -> AccessType {
         match name {
             "id" => return AccessType::ReadOnly,
@@ -225,12 +258,8 @@ impl ModelField {
         }
     }
 
-    pub fn codegen(&self) -> TokenStream {
-        let mut annotations = Vec::<TokenStream>::new();
-        let name = format_ident!("{}", self.ident());
-        let property_type = self.type_name();
-        let property_type = TokenStream::from_str(&property_type).unwrap();
-        let access_type = if self.access_type == AccessType::Unsure {
+    fn resolved_access_type(&self) -> &AccessType {
+        if self.access_type == AccessType::Unsure {
             if self.assume_mandatory {
                 &AccessType::Mandatory
             } else {
@@ -238,12 +267,22 @@ impl ModelField {
             }
         } else {
             &self.access_type
-        };
+        }
+    }
+
+    pub fn codegen(&self) -> TokenStream {
+        let mut annotations = Vec::<TokenStream>::new();
+        let name = format_ident!("{}", self.ident());
+        let property_type = self.type_name();
+        let property_type = TokenStream::from_str(&property_type).unwrap();
+        let access_type = self.resolved_access_type();
 
         if matches!(self.model_type, ModelType::Date) {
             match access_type {
-                AccessType::Optional | AccessType::Unsure => annotations.push(
-                    quote!( #[serde(with = "crate::serde::optional_date")]),
+                AccessType::Optional
+                | AccessType::Unsure
+                | AccessType::ReadOnly => annotations.push(
+                    quote!( #[serde(default, with = "crate::serde::optional_date")]),
                 ),
                 _ => annotations
                     .push(quote!( #[serde(with = "crate::serde::date")])),
@@ -252,6 +291,8 @@ impl ModelField {
         let property_type = match access_type {
             AccessType::Mandatory => {
                 if name == "version" {
+                    // list endpoints like recurring-templates omit the version
+                    annotations.push(quote! {#[serde(default)] });
                     annotations
                         .push(quote! {#[builder(default, setter(skip))] });
                 } else {
@@ -284,6 +325,28 @@ impl ModelField {
             pub #name: #property_type
         }
     }
+    /// Generates the property of a TypeScript interface. Read-only fields
+    /// are optional when sending objects and `null` when lexoffice didn't
+    /// set them.
+    pub fn codegen_ts(&self) -> String {
+        let (prefix, suffix, nullable) = match self.resolved_access_type() {
+            // see the `#[serde(default)]` in `codegen()`
+            AccessType::Mandatory if self.name == "version" => ("", "?", ""),
+            AccessType::Mandatory => ("", "", ""),
+            AccessType::ReadOnly => ("readonly ", "?", " | null"),
+            AccessType::Optional | AccessType::Unsure => ("", "?", ""),
+        };
+        format!(
+            "{}        {}{}{}: {}{};\n",
+            ts_doc(&mk_md(&self.doc), "        "),
+            prefix,
+            self.name,
+            suffix,
+            self.ts_type_name(),
+            nullable,
+        )
+    }
+
     pub fn codegen_enum(&self) -> TokenStream {
         let mut annotations = Vec::<TokenStream>::new();
         let name = self.ident();
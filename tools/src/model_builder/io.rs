@@ -1,3 +1,4 @@
+use crate::model_builder::overrides;
 use crate::model_builder::result::*;
 use proc_macro2::TokenStream;
 use std::fs;
@@ -10,7 +11,7 @@ const CACHE_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/index.html");
 
 pub fn load_docs() -> Result<String> {
     println!("{:?}", CACHE_FILE);
-    Ok(fs::read_to_string(CACHE_FILE)?)
+    overrides::apply(&fs::read_to_string(CACHE_FILE)?)
 }
 
 fn quickfmt(token_stream: TokenStream) -> String {
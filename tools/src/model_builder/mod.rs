@@ -2,6 +2,7 @@ pub mod enums;
 pub mod fields;
 pub mod io;
 pub mod modules;
+pub mod overrides;
 pub mod result;
 pub mod structs;
 pub mod utils;
@@ -1,5 +1,6 @@
 use crate::model_builder::enums::ModelEnum;
 use crate::model_builder::structs::ModelStruct;
+use crate::model_builder::utils::ts_doc;
 use inflector::string::singularize::to_singular;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -178,4 +179,39 @@ impl ModelModule {
             #( #types )*
         }
     }
+
+    /// Generates a TypeScript namespace containing the types of this
+    /// module.
+    pub fn codegen_ts(&self) -> String {
+        let doc = html2md::parse_html(&self.doc).trim().to_string();
+        let doc = ts_doc(&doc, "");
+
+        if self.type_name() == "files" {
+            return format!(
+                "{}export namespace files {{\n    export interface File {{}}\n}}\n",
+                doc
+            );
+        }
+
+        let extra_use = if self.id == "down-payment-invoices-endpoint" {
+            "    export type ShippingConditions = invoices.ShippingConditions;\n"
+        } else {
+            ""
+        };
+        let items = self
+            .collect_enums()
+            .iter()
+            .map(|x| x.to_owned())
+            .chain(self.extra_enums.iter())
+            .map(|x| x.codegen_ts())
+            .chain(self.structs.iter().map(|x| x.codegen_ts()))
+            .collect::<Vec<_>>();
+        format!(
+            "{}export namespace {} {{\n{}{}}}\n",
+            doc,
+            self.type_name(),
+            extra_use,
+            items.join("\n"),
+        )
+    }
 }
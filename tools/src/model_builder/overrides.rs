@@ -0,0 +1,77 @@
+//! Local corrections of the official documentation.
+//!
+//! `tools/index.html` is a verbatim copy of <https://developers.lexoffice.io/docs/>
+//! that is compared against upstream daily, so it must not be edited by
+//! hand. Fields that are missing or wrong upstream are patched in here
+//! before the models are generated.
+use crate::model_builder::result::*;
+
+enum Patch {
+    /// Inserts the html right after the anchor.
+    After,
+    /// Inserts the html right before the anchor.
+    Before,
+    /// Replaces the anchor with the html.
+    Replace,
+}
+
+struct Override {
+    anchor: &'static str,
+    patch: Patch,
+    html: &'static str,
+}
+
+const OVERRIDES: &[Override] = &[
+    // The payments endpoint returns the date of the last payment and the
+    // single payment items, but they are not documented.
+    Override {
+        anchor: "<td>Contains one of the following voucher states: <em>open</em>, <em>paid</em>, <em>paidoff</em>, <em>voided</em>, <em>transferred</em>, <em>sepadebit</em></td>\n</tr>\n",
+        patch: Patch::After,
+        html: include_str!("../../overrides/payments.html"),
+    },
+    // The articles endpoint is not part of the reference documentation yet.
+    Override {
+        anchor: "<h1 id='contacts-endpoint'>",
+        patch: Patch::Before,
+        html: include_str!("../../overrides/articles.html"),
+    },
+    // The payment discount conditions are a single object, not a list.
+    Override {
+        anchor: "paymentDiscountConditions</a> <br><code>list</code></td>\n<td style=\"text-align: left\">The payment discount conditions for the payment condition.</td>",
+        patch: Patch::Replace,
+        html: "paymentDiscountConditions</a> <br><code>object</code></td>\n<td style=\"text-align: left\">The payment discount conditions for the payment condition.</td>",
+    },
+    // Recurring templates carry the dates of their last and next execution,
+    // but they are not documented.
+    Override {
+        anchor: "<td style=\"text-align: left\">The execution interval defined as <strong>WEEKLY</strong>, <strong>BIWEEKLY</strong>, <strong>MONTHLY</strong>, <strong>QUARTERLY</strong>, <strong>BIANNUALLY</strong>, <strong>ANNUALLY</strong>.</td>\n</tr>\n",
+        patch: Patch::After,
+        html: include_str!("../../overrides/recurring_templates.html"),
+    },
+];
+
+/// Applies all overrides to the documentation. Fails if an anchor can't be
+/// found exactly once, which means that upstream changed and the override
+/// needs to be revisited.
+pub fn apply(html: &str) -> Result<String> {
+    let mut html = html.to_string();
+    for o in OVERRIDES {
+        let mut matches = html.match_indices(o.anchor);
+        let (start, _) = match (matches.next(), matches.next()) {
+            (Some(m), None) => m,
+            (None, _) => {
+                return Err(format!("anchor not found: {:?}", o.anchor).into())
+            }
+            (Some(_), Some(_)) => {
+                return Err(format!("anchor not unique: {:?}", o.anchor).into())
+            }
+        };
+        let end = start + o.anchor.len();
+        match o.patch {
+            Patch::After => html.insert_str(end, o.html),
+            Patch::Before => html.insert_str(start, o.html),
+            Patch::Replace => html.replace_range(start..end, o.html),
+        }
+    }
+    Ok(html)
+}
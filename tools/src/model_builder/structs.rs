@@ -4,6 +4,8 @@ use crate::model_builder::fields::ModelField;
 use crate::model_builder::fields::ModelType;
 use crate::model_builder::modules::ModelModule;
 use crate::model_builder::utils::mk_doc;
+use crate::model_builder::utils::mk_md;
+use crate::model_builder::utils::ts_doc;
 use crate::model_builder::utils::StringUtils;
 use proc_macro2::TokenStream;
 use quote::format_ident;
@@ -222,14 +224,34 @@ impl ModelStruct {
             #doc
             #[derive(Debug, Clone, PartialEq, TypedBuilder, Serialize, Deserialize)]
             #[builder(doc)]
-            #[serde(deny_unknown_fields, rename_all = "camelCase")]
+            #[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+            #[serde(rename_all = "camelCase")]
             pub struct #name {
-                #( #fields ),*
+                #( #fields, )*
+                #[doc = "Fields sent by LexOffice that are not known to this crate. They are kept when the object is sent back to LexOffice. Always empty with the `strict` feature, which rejects unknown fields instead."]
+                #[cfg_attr(not(feature = "strict"), serde(flatten))]
+                #[cfg_attr(feature = "strict", serde(skip))]
+                #[builder(default, setter(skip))]
+                pub extra: serde_json::Map<String, serde_json::Value>,
             }
 
             #implementation
         }
     }
+    pub fn codegen_ts(&self) -> String {
+        let fields = self
+            .fields
+            .iter()
+            .map(|x| x.codegen_ts())
+            .collect::<String>();
+        format!(
+            "{}    export interface {} {{\n{}    }}\n",
+            ts_doc(&mk_md(&self.doc), "    "),
+            self.type_name(),
+            fields,
+        )
+    }
+
     pub fn codegen_roles(&self) -> TokenStream {
         let name = format_ident!("{}", self.type_name());
         let fields = self.fields.iter().map(|x| x.codegen_enum());
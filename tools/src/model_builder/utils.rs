@@ -151,18 +151,36 @@ impl StringUtils for &str {
     }
 }
 
-pub fn mk_doc(html: &str) -> TokenStream {
+pub fn mk_md(html: &str) -> String {
     let mut tag_handlers: HashMap<String, Box<dyn TagHandlerFactory>> =
         HashMap::new();
     tag_handlers.insert("a".to_string(), Box::new(AnchorHandlerFactory {}));
     tag_handlers.insert("pre".to_string(), Box::new(CodeHandlerFactory {}));
 
-    let md = html2md::parse_html_custom(html, &tag_handlers)
+    html2md::parse_html_custom(html, &tag_handlers)
         .trim()
-        .to_string();
+        .to_string()
+}
+
+pub fn mk_doc(html: &str) -> TokenStream {
+    let md = mk_md(html);
     if md.is_empty() {
         quote! {}
     } else {
         quote! { #[doc = #md] }
     }
 }
+
+/// Formats markdown as JSDoc comment, every line prefixed with `indent`.
+pub fn ts_doc(md: &str, indent: &str) -> String {
+    if md.is_empty() {
+        return String::new();
+    }
+    let mut doc = format!("{}/**\n", indent);
+    for line in md.replace("*/", "*\\/").lines() {
+        doc.push_str(format!("{} * {}", indent, line).trim_end());
+        doc.push('\n');
+    }
+    doc.push_str(&format!("{} */\n", indent));
+    doc
+}